@@ -1,9 +1,16 @@
+use crate::calculate_tiered_fee;
+use crate::config;
 use crate::errors::SavingsError;
 use crate::flexi;
-use crate::storage_types::{AutoSave, DataKey};
+use crate::goal;
+use crate::lock;
+use crate::storage_types::{AutoSave, AutoSaveTarget, ConfigKey, DataKey};
 use crate::ttl;
 use crate::users;
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+
+/// Default upper bound on the number of schedules processed per batch call.
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = 50;
 
 /// Creates a new AutoSave schedule for recurring Flexi deposits
 ///
@@ -23,6 +30,41 @@ pub fn create_autosave(
     amount: i128,
     interval_seconds: u64,
     start_time: u64,
+) -> Result<u64, SavingsError> {
+    create_targeted_autosave(
+        env,
+        user,
+        amount,
+        interval_seconds,
+        start_time,
+        AutoSaveTarget::Flexi,
+    )
+}
+
+/// Creates a new AutoSave schedule that deposits into the given target plan
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The user creating the schedule
+/// * `amount` - The amount to deposit on each execution (must be > 0)
+/// * `interval_seconds` - How often the schedule runs in seconds (must be > 0)
+/// * `start_time` - Unix timestamp for the first execution
+/// * `target` - The plan each execution deposits into
+///
+/// # Returns
+/// * `Ok(u64)` - The unique schedule ID
+/// * `Err(SavingsError)` - If validation fails
+///
+/// # Errors
+/// * `PlanNotFound` - If a Goal or Lock target does not exist
+/// * `Unauthorized` - If a Goal or Lock target is not owned by `user`
+pub fn create_targeted_autosave(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    interval_seconds: u64,
+    start_time: u64,
+    target: AutoSaveTarget,
 ) -> Result<u64, SavingsError> {
     user.require_auth();
 
@@ -41,6 +83,24 @@ pub fn create_autosave(
         return Err(SavingsError::UserNotFound);
     }
 
+    // Ensure the target plan exists and belongs to the user
+    let target_owner = match &target {
+        AutoSaveTarget::Flexi => None,
+        AutoSaveTarget::Goal(goal_id) => Some(
+            goal::get_goal_save(env, *goal_id)
+                .ok_or(SavingsError::PlanNotFound)?
+                .owner,
+        ),
+        AutoSaveTarget::LockTopUp(lock_id) => Some(
+            lock::get_lock_save(env, *lock_id)
+                .ok_or(SavingsError::PlanNotFound)?
+                .owner,
+        ),
+    };
+    if target_owner.is_some_and(|owner| owner != user) {
+        return Err(SavingsError::Unauthorized);
+    }
+
     // Generate unique schedule ID
     let schedule_id = get_next_schedule_id(env);
 
@@ -52,6 +112,9 @@ pub fn create_autosave(
         interval_seconds,
         next_execution_time: start_time,
         is_active: true,
+        target,
+        start_time,
+        executions_done: 0,
     };
 
     // Store the schedule
@@ -74,16 +137,21 @@ pub fn create_autosave(
 
 /// Executes an AutoSave schedule if it's due
 ///
+/// Safe for keepers to retry: the due time is derived from the number of
+/// executions already made, so a second call within the same interval
+/// deposits nothing.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `schedule_id` - The ID of the schedule to execute
 ///
 /// # Returns
-/// * `Ok(())` - If execution succeeds
-/// * `Err(SavingsError)` - If the schedule is not found, inactive, or not yet due
-pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError> {
+/// * `Ok(true)` - If a deposit was made
+/// * `Ok(false)` - If the schedule is not yet due
+/// * `Err(SavingsError)` - If the schedule is not found or inactive, or the deposit fails
+pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<bool, SavingsError> {
     // Fetch the schedule
-    let mut schedule: AutoSave = env
+    let schedule: AutoSave = env
         .storage()
         .persistent()
         .get(&DataKey::AutoSave(schedule_id))
@@ -94,27 +162,20 @@ pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError>
         return Err(SavingsError::InvalidPlanConfig);
     }
 
-    // Ensure current time >= next_execution_time
-    let current_time = env.ledger().timestamp();
-    if current_time < schedule.next_execution_time {
-        return Err(SavingsError::InvalidTimestamp);
+    // Nothing to do until the next interval is due
+    if env.ledger().timestamp() < next_due(&schedule) {
+        return Ok(false);
     }
 
-    // Perform Flexi deposit
-    flexi::flexi_deposit(env.clone(), schedule.user.clone(), schedule.amount)?;
+    // Deposit into the schedule's target plan
+    deposit_to_target(env, &schedule)?;
 
-    // Update next execution time
-    schedule.next_execution_time += schedule.interval_seconds;
-
-    // Save updated schedule
-    env.storage()
-        .persistent()
-        .set(&DataKey::AutoSave(schedule_id), &schedule);
+    record_execution(env, schedule);
 
     // Extend TTL on execution (active schedule gets full extension)
     ttl::extend_autosave_ttl(env, schedule_id);
 
-    Ok(())
+    Ok(true)
 }
 
 /// Batch-executes multiple AutoSave schedules that are due.
@@ -133,12 +194,20 @@ pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError>
 /// - `true`  — the schedule was due and executed successfully
 /// - `false` — the schedule was skipped (not found, inactive, not yet due, or deposit failed)
 ///
+/// # Errors
+/// * `InvalidAmount` - If `schedule_ids` is longer than the configured max batch size
+///
 /// # Guarantees
+/// - Oversized batches are rejected up front; nothing is executed.
 /// - One failed or skipped schedule does **not** revert the entire batch.
-/// - Only schedules whose `next_execution_time <= current_ledger_timestamp` are executed.
-/// - For each executed schedule, a Flexi deposit is performed and `next_execution_time` is
-///   advanced by `interval_seconds`.
-pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
+/// - Only schedules whose next due time is `<= current_ledger_timestamp` are executed.
+/// - For each executed schedule, a deposit into its target plan is performed and
+///   its execution count is advanced by one interval.
+pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Result<Vec<bool>, SavingsError> {
+    if schedule_ids.len() > get_max_batch_size(env) {
+        return Err(SavingsError::InvalidAmount);
+    }
+
     let current_time = env.ledger().timestamp();
     let mut results = Vec::new(env);
 
@@ -166,31 +235,95 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
         }
 
         // Skip schedules that are not yet due
-        if current_time < schedule.next_execution_time {
+        if current_time < next_due(&schedule) {
             results.push_back(false);
             continue;
         }
 
-        // Attempt the Flexi deposit; if it fails, mark as false and continue
-        let deposit_result =
-            flexi::flexi_deposit(env.clone(), schedule.user.clone(), schedule.amount);
-
-        if deposit_result.is_err() {
+        // Attempt the deposit; if it fails, mark as false and continue
+        if deposit_to_target(env, &schedule).is_err() {
             results.push_back(false);
             continue;
         }
 
-        // Update next execution time and persist
-        let mut updated_schedule = schedule.clone();
-        updated_schedule.next_execution_time += updated_schedule.interval_seconds;
-        env.storage()
-            .persistent()
-            .set(&DataKey::AutoSave(schedule_id), &updated_schedule);
+        record_execution(env, schedule);
 
         results.push_back(true);
     }
 
-    results
+    Ok(results)
+}
+
+/// Time the schedule's next deposit is due.
+fn next_due(schedule: &AutoSave) -> u64 {
+    schedule.start_time.saturating_add(
+        schedule
+            .interval_seconds
+            .saturating_mul(schedule.executions_done),
+    )
+}
+
+/// Counts one completed deposit and persists the advanced schedule.
+fn record_execution(env: &Env, mut schedule: AutoSave) {
+    schedule.executions_done += 1;
+    schedule.next_execution_time = next_due(&schedule);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoSave(schedule.id), &schedule);
+}
+
+/// Performs one scheduled deposit into the schedule's target plan.
+fn deposit_to_target(env: &Env, schedule: &AutoSave) -> Result<(), SavingsError> {
+    match schedule.target {
+        AutoSaveTarget::Flexi => {
+            flexi::flexi_deposit(env.clone(), schedule.user.clone(), schedule.amount)
+        }
+        AutoSaveTarget::Goal(goal_id) => {
+            goal::deposit_to_goal_save(env, schedule.user.clone(), goal_id, schedule.amount)
+        }
+        AutoSaveTarget::LockTopUp(lock_id) => {
+            lock::top_up_lock_save(env, schedule.user.clone(), lock_id, schedule.amount)
+        }
+    }
+}
+
+/// Sets the maximum number of schedules accepted by `execute_due_autosaves`.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `max_batch_size` - The new limit (must be > 0)
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::InvalidAmount` - If `max_batch_size` is 0
+pub fn set_max_batch_size(
+    env: &Env,
+    admin: Address,
+    max_batch_size: u32,
+) -> Result<(), SavingsError> {
+    config::require_admin(env, &admin)?;
+
+    if max_batch_size == 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MaxAutoSaveBatchSize, &max_batch_size);
+
+    env.events()
+        .publish((symbol_short!("set_batch"),), max_batch_size);
+
+    Ok(())
+}
+
+/// Returns the maximum batch size, falling back to `DEFAULT_MAX_BATCH_SIZE`.
+pub fn get_max_batch_size(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxAutoSaveBatchSize)
+        .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
 }
 
 /// Cancels an AutoSave schedule
@@ -229,6 +362,44 @@ pub fn cancel_autosave(env: &Env, user: Address, schedule_id: u64) -> Result<(),
     Ok(())
 }
 
+/// Previews what the next execution of a schedule would deposit.
+///
+/// Flexi and Goal deposits pay the tiered protocol fee, which is deducted
+/// from the schedule amount. Lock top-ups are fee-free.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `schedule_id` - The ID of the schedule to preview
+///
+/// # Returns
+/// `(gross, net)` — the scheduled amount and the amount that would land after fees.
+///
+/// # Errors
+/// * `PlanNotFound` - If the schedule does not exist
+pub fn preview_autosave_execution(
+    env: &Env,
+    schedule_id: u64,
+) -> Result<(i128, i128), SavingsError> {
+    let schedule: AutoSave = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AutoSave(schedule_id))
+        .ok_or(SavingsError::PlanNotFound)?;
+
+    let fee_amount = match schedule.target {
+        AutoSaveTarget::Flexi | AutoSaveTarget::Goal(_) => {
+            calculate_tiered_fee(env, schedule.amount)?
+        }
+        AutoSaveTarget::LockTopUp(_) => 0,
+    };
+    let net_amount = schedule
+        .amount
+        .checked_sub(fee_amount)
+        .ok_or(SavingsError::Underflow)?;
+
+    Ok((schedule.amount, net_amount))
+}
+
 /// Gets an AutoSave schedule by ID
 pub fn get_autosave(env: &Env, schedule_id: u64) -> Option<AutoSave> {
     let schedule = env
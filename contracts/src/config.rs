@@ -1,10 +1,23 @@
 use crate::errors::SavingsError;
-use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use crate::storage_types::{ConfigKey, DataKey};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
 /// Maximum fee in basis points (100% = 10000 bps)
 const MAX_FEE_BPS: u32 = 10_000;
 
+/// Operation scope codes for `pause_op` / `unpause_op`
+pub const PAUSE_OP_DEPOSIT: u32 = 1;
+pub const PAUSE_OP_WITHDRAW: u32 = 2;
+pub const PAUSE_OP_GOVERNANCE: u32 = 3;
+pub const PAUSE_OP_STRATEGY: u32 = 4;
+
+/// Fee kind codes for `estimate_fee`
+pub const FEE_KIND_PROTOCOL: u32 = 0;
+/// Early break of a Goal Save
+pub const FEE_KIND_EARLY_BREAK: u32 = 1;
+pub const FEE_KIND_LOCK_BREAK: u32 = 2;
+pub const FEE_KIND_GROUP_BREAK: u32 = 3;
+
 /// Global configuration for the Nestera protocol.
 ///
 /// This struct is assembled from individual storage keys rather than
@@ -24,7 +37,7 @@ pub struct Config {
 ///
 /// # Errors
 /// * `SavingsError::Unauthorized` - If the caller is not the admin
-fn require_admin(env: &Env, caller: &Address) -> Result<(), SavingsError> {
+pub(crate) fn require_admin(env: &Env, caller: &Address) -> Result<(), SavingsError> {
     let stored_admin: Address = env
         .storage()
         .instance()
@@ -37,6 +50,75 @@ fn require_admin(env: &Env, caller: &Address) -> Result<(), SavingsError> {
     Ok(())
 }
 
+// ========== Admin Transfer ==========
+
+/// Proposes `new_admin` as the next admin. The current admin keeps full
+/// control until the proposed address calls `accept_admin`.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If `current_admin` is not the admin
+pub fn propose_admin(
+    env: &Env,
+    current_admin: Address,
+    new_admin: Address,
+) -> Result<(), SavingsError> {
+    require_admin(env, &current_admin)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingAdmin, &new_admin);
+
+    env.events()
+        .publish((symbol_short!("adm_prop"),), new_admin);
+
+    Ok(())
+}
+
+/// Completes a pending admin transfer. `new_admin` must be the proposed
+/// address and must authorize the call.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If no transfer is pending or `new_admin`
+///   is not the proposed address
+pub fn accept_admin(env: &Env, new_admin: Address) -> Result<(), SavingsError> {
+    let pending: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::PendingAdmin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if pending != new_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    new_admin.require_auth();
+
+    env.storage().instance().set(&DataKey::Admin, &new_admin);
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+
+    env.events()
+        .publish((symbol_short!("set_admin"),), new_admin);
+
+    Ok(())
+}
+
+/// Withdraws a pending admin proposal (admin only).
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If `admin` is not the admin
+pub fn cancel_admin_transfer(env: &Env, admin: Address) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+
+    env.events().publish((symbol_short!("adm_canc"),), admin);
+
+    Ok(())
+}
+
+/// Returns the address awaiting acceptance as admin, if any.
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
 // ========== Config Functions ==========
 
 /// Initializes the global protocol configuration.
@@ -73,6 +155,16 @@ pub fn initialize_config(
     // Verify admin
     require_admin(env, &admin)?;
 
+    store_initial_config(env, treasury, protocol_fee_bps)
+}
+
+/// Validates and stores the initial treasury and protocol fee. Callers are
+/// responsible for the admin and re-initialization checks.
+pub(crate) fn store_initial_config(
+    env: &Env,
+    treasury: Address,
+    protocol_fee_bps: u32,
+) -> Result<(), SavingsError> {
     // Validate fee bounds
     if protocol_fee_bps > MAX_FEE_BPS {
         return Err(SavingsError::InvalidFeeBps);
@@ -123,11 +215,7 @@ pub fn get_config(env: &Env) -> Result<Config, SavingsError> {
         .get(&DataKey::ProtocolFeeBps)
         .unwrap_or(0);
 
-    let paused: bool = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Paused)
-        .unwrap_or(false);
+    let paused = is_paused(env);
 
     Ok(Config {
         admin,
@@ -148,15 +236,18 @@ pub fn get_config(env: &Env) -> Result<Config, SavingsError> {
 /// * `SavingsError::Unauthorized` - If caller is not the admin
 pub fn set_treasury(env: &Env, admin: Address, new_treasury: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    store_treasury(env, new_treasury);
+    Ok(())
+}
 
+/// Writes the treasury address without an admin check (shared with governance).
+pub(crate) fn store_treasury(env: &Env, new_treasury: Address) {
     env.storage()
         .instance()
         .set(&DataKey::Treasury, &new_treasury);
 
     env.events()
         .publish((symbol_short!("set_trs"),), new_treasury);
-
-    Ok(())
 }
 
 /// Updates the protocol fee in basis points.
@@ -171,7 +262,14 @@ pub fn set_treasury(env: &Env, admin: Address, new_treasury: Address) -> Result<
 /// * `SavingsError::InvalidFeeBps` - If fee exceeds 10000 bps
 pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    store_protocol_fee(env, new_fee_bps)
+}
 
+/// Validates and writes the protocol fee without an admin check (shared with governance).
+///
+/// # Errors
+/// * `SavingsError::InvalidFeeBps` - If fee exceeds 10000 bps
+pub(crate) fn store_protocol_fee(env: &Env, new_fee_bps: u32) -> Result<(), SavingsError> {
     if new_fee_bps > MAX_FEE_BPS {
         return Err(SavingsError::InvalidFeeBps);
     }
@@ -186,6 +284,410 @@ pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(
     Ok(())
 }
 
+/// Sets the deposit fee tiers used by `calculate_tiered_fee`.
+///
+/// Each tier is a `(threshold, bps)` pair; a deposit pays the bps of the
+/// highest threshold that is `<=` the deposit amount. Passing an empty vector
+/// clears the tiers so the flat platform fee applies again.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `tiers` - `(threshold, bps)` pairs, strictly ascending by threshold
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::InvalidAmount` - If a threshold is negative or thresholds are not strictly ascending
+/// * `SavingsError::InvalidFeeBps` - If any tier's bps exceeds 10000
+pub fn set_fee_tiers(
+    env: &Env,
+    admin: Address,
+    tiers: Vec<(i128, u32)>,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    let mut previous: Option<i128> = None;
+    for (threshold, bps) in tiers.iter() {
+        if threshold < 0 {
+            return Err(SavingsError::InvalidAmount);
+        }
+        if let Some(prev) = previous {
+            if threshold <= prev {
+                return Err(SavingsError::InvalidAmount);
+            }
+        }
+        if bps > MAX_FEE_BPS {
+            return Err(SavingsError::InvalidFeeBps);
+        }
+        previous = Some(threshold);
+    }
+
+    env.storage().instance().set(&ConfigKey::FeeTiers, &tiers);
+
+    env.events()
+        .publish((symbol_short!("set_tier"),), tiers.len());
+
+    Ok(())
+}
+
+/// Returns the configured deposit fee tiers (empty if none).
+pub fn get_fee_tiers(env: &Env) -> Vec<(i128, u32)> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FeeTiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Sets the maximum absolute fee charged on a single transaction.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `max_fee` - The fee cap; 0 removes the cap
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::InvalidAmount` - If `max_fee` is negative
+pub fn set_max_fee(env: &Env, admin: Address, max_fee: i128) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    if max_fee < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MaxFeeAbsolute, &max_fee);
+
+    env.events().publish((symbol_short!("set_mxfee"),), max_fee);
+
+    Ok(())
+}
+
+/// Returns the per-transaction fee cap (0 = uncapped).
+pub fn get_max_fee(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxFeeAbsolute)
+        .unwrap_or(0)
+}
+
+/// Sets the most a single user may withdraw per day.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `limit` - The daily limit; 0 removes the limit
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::InvalidAmount` - If `limit` is negative
+pub fn set_daily_withdraw_limit(
+    env: &Env,
+    admin: Address,
+    limit: i128,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    if limit < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::DailyWithdrawLimit, &limit);
+
+    env.events().publish((symbol_short!("set_wdlim"),), limit);
+
+    Ok(())
+}
+
+/// Returns the per-user daily withdrawal limit (0 = no limit).
+pub fn get_daily_withdraw_limit(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::DailyWithdrawLimit)
+        .unwrap_or(0)
+}
+
+/// Counts `amount` against the user's withdrawals for the current day.
+///
+/// Days are fixed 86,400-second buckets of the ledger timestamp, so the
+/// allowance resets at each bucket boundary.
+///
+/// # Errors
+/// * `SavingsError::AmountExceedsLimit` - If the day's total would exceed the limit
+pub(crate) fn consume_daily_withdrawal(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    let limit = get_daily_withdraw_limit(env);
+    if limit == 0 {
+        return Ok(());
+    }
+
+    let day = env.ledger().timestamp() / 86_400;
+    let key = DataKey::DailyWithdrawn(user.clone());
+    let withdrawn = match env.storage().persistent().get::<DataKey, (u64, i128)>(&key) {
+        Some((last_day, withdrawn)) if last_day == day => withdrawn,
+        _ => 0,
+    };
+    let withdrawn = withdrawn
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    if withdrawn > limit {
+        return Err(SavingsError::AmountExceedsLimit);
+    }
+
+    env.storage().persistent().set(&key, &(day, withdrawn));
+    Ok(())
+}
+
+/// Sets the early-break fee for Goal Saves, overriding the shared `EarlyBreakFeeBps`.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin (or governance is inactive)
+/// * `SavingsError::InvalidFeeBps` - If `bps` exceeds 10000
+pub fn set_goal_break_fee_bps(env: &Env, caller: Address, bps: u32) -> Result<(), SavingsError> {
+    set_break_fee_bps(env, caller, ConfigKey::GoalBreakFeeBps, bps)
+}
+
+/// Sets the early-break fee for Lock Saves, overriding the shared `EarlyBreakFeeBps`.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin (or governance is inactive)
+/// * `SavingsError::InvalidFeeBps` - If `bps` exceeds 10000
+pub fn set_lock_break_fee_bps(env: &Env, caller: Address, bps: u32) -> Result<(), SavingsError> {
+    set_break_fee_bps(env, caller, ConfigKey::LockBreakFeeBps, bps)
+}
+
+/// Sets the fee for leaving a Group Save early, overriding the shared `EarlyBreakFeeBps`.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin (or governance is inactive)
+/// * `SavingsError::InvalidFeeBps` - If `bps` exceeds 10000
+pub fn set_group_break_fee_bps(env: &Env, caller: Address, bps: u32) -> Result<(), SavingsError> {
+    set_break_fee_bps(env, caller, ConfigKey::GroupBreakFeeBps, bps)
+}
+
+fn set_break_fee_bps(
+    env: &Env,
+    caller: Address,
+    key: ConfigKey,
+    bps: u32,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    crate::governance::validate_admin_or_governance(env, &caller)?;
+    if bps > 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+
+    env.storage().instance().set(&key, &bps);
+    env.events().publish((symbol_short!("set_brk"), key), bps);
+    Ok(())
+}
+
+/// Returns the early-break fee for the plan type stored under `key`.
+///
+/// Falls back to the shared `EarlyBreakFeeBps` when the plan type has no fee
+/// of its own, and is 0 in emergency mode.
+pub(crate) fn get_break_fee_bps(env: &Env, key: ConfigKey) -> u32 {
+    if is_emergency_mode(env) {
+        return 0;
+    }
+    env.storage()
+        .instance()
+        .get(&key)
+        .or_else(|| env.storage().instance().get(&DataKey::EarlyBreakFeeBps))
+        .unwrap_or(0)
+}
+
+/// Credits an early-break fee to the fee recipient, if one is set.
+///
+/// # Errors
+/// * `SavingsError::Overflow` - If the recipient's accrued fees would overflow
+pub(crate) fn accrue_break_fee(
+    env: &Env,
+    plan_id: u64,
+    fee_amount: i128,
+) -> Result<(), SavingsError> {
+    if fee_amount <= 0 {
+        return Ok(());
+    }
+    let Some(fee_recipient) = env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::FeeRecipient)
+    else {
+        return Ok(());
+    };
+
+    let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
+    let current_fee_balance = env
+        .storage()
+        .persistent()
+        .get::<DataKey, i128>(&fee_key)
+        .unwrap_or(0i128);
+    let new_fee_balance = current_fee_balance
+        .checked_add(fee_amount)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&fee_key, &new_fee_balance);
+    crate::stats::record_fee(env, fee_amount)?;
+
+    // Extend TTL on fee storage
+    crate::ttl::extend_config_ttl(env, &fee_key);
+
+    env.events().publish(
+        (symbol_short!("brk_fee"), fee_recipient, plan_id),
+        fee_amount,
+    );
+    Ok(())
+}
+
+/// Estimates the fee charged on `amount` without changing any state.
+///
+/// `FEE_KIND_PROTOCOL` applies the deposit fee tiers and the per-transaction
+/// cap exactly as deposits do. `FEE_KIND_EARLY_BREAK` (goals),
+/// `FEE_KIND_LOCK_BREAK` and `FEE_KIND_GROUP_BREAK` apply that plan type's
+/// break fee bps and the same cap, waived in emergency mode. All round down.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If `amount` is negative
+/// * `SavingsError::InvalidPlanConfig` - If `fee_kind` is not a known kind
+pub fn estimate_fee(env: &Env, amount: i128, fee_kind: u32) -> Result<i128, SavingsError> {
+    if amount < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let break_fee_key = match fee_kind {
+        FEE_KIND_PROTOCOL => return crate::calculate_tiered_fee(env, amount),
+        FEE_KIND_EARLY_BREAK => ConfigKey::GoalBreakFeeBps,
+        FEE_KIND_LOCK_BREAK => ConfigKey::LockBreakFeeBps,
+        FEE_KIND_GROUP_BREAK => ConfigKey::GroupBreakFeeBps,
+        _ => return Err(SavingsError::InvalidPlanConfig),
+    };
+    crate::calculate_capped_fee(env, amount, get_break_fee_bps(env, break_fee_key))
+}
+
+/// Sets the maximum number of open plans (savings plans, locks and goals) a
+/// single user may hold.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `max_plans` - The limit; 0 removes it
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+pub fn set_max_plans_per_user(
+    env: &Env,
+    admin: Address,
+    max_plans: u32,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MaxPlansPerUser, &max_plans);
+
+    env.events()
+        .publish((symbol_short!("set_mxpln"),), max_plans);
+
+    Ok(())
+}
+
+/// Returns the per-user open plan limit (0 = unlimited).
+pub fn get_max_plans_per_user(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::MaxPlansPerUser)
+        .unwrap_or(0)
+}
+
+/// Sets the grace period added to a signed payload's expiry to absorb
+/// clock skew between the signer and the ledger.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `grace_secs` - Seconds of grace; 0 enforces the exact expiry
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+pub fn set_signature_grace_secs(
+    env: &Env,
+    admin: Address,
+    grace_secs: u64,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::SignatureGraceSecs, &grace_secs);
+
+    env.events()
+        .publish((symbol_short!("set_grace"),), grace_secs);
+
+    Ok(())
+}
+
+/// Returns the signature expiry grace period in seconds (0 = none).
+pub fn get_signature_grace_secs(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::SignatureGraceSecs)
+        .unwrap_or(0)
+}
+
+/// Withdraws accrued protocol fees for a fee recipient.
+///
+/// Decrements the recipient's `FeeAccrued` balance; the recipient's own
+/// savings are never touched.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `caller` - The fee recipient or the admin
+/// * `recipient` - Whose accrued fees to withdraw
+/// * `amount` - Amount to withdraw (must be > 0)
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is neither the recipient nor the admin
+/// * `SavingsError::InvalidAmount` - If `amount` <= 0
+/// * `SavingsError::InsufficientBalance` - If `amount` exceeds the accrued fees
+pub fn withdraw_protocol_fees(
+    env: &Env,
+    caller: Address,
+    recipient: Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    if caller == recipient {
+        caller.require_auth();
+    } else {
+        require_admin(env, &caller)?;
+    }
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let fee_key = DataKey::FeeAccrued(recipient.clone());
+    let balance: i128 = env.storage().persistent().get(&fee_key).unwrap_or(0);
+    if amount > balance {
+        return Err(SavingsError::InsufficientBalance);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&fee_key, &(balance - amount));
+    crate::stats::record_fee_withdrawal(env, amount)?;
+
+    env.events()
+        .publish((symbol_short!("fee_wd"), recipient), amount);
+
+    Ok(())
+}
+
 /// Pauses the contract, blocking all state-changing operations.
 ///
 /// # Arguments
@@ -198,12 +700,54 @@ pub fn pause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
 
     env.storage().persistent().set(&DataKey::Paused, &true);
+    clear_auto_unpause(env);
 
     env.events().publish((symbol_short!("pause"),), admin);
 
     Ok(())
 }
 
+/// Pauses the contract and schedules it to unpause itself after `duration` seconds.
+///
+/// Once `now >= auto_unpause_at` the pause is treated as lifted without any
+/// further admin action. A `duration` of 0 behaves like `pause_contract`
+/// (manual unpause only).
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The admin calling this function
+/// * `duration` - Seconds until the pause lifts automatically (0 = never)
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::Overflow` - If `now + duration` overflows
+pub fn pause_contract_with_timeout(
+    env: &Env,
+    admin: Address,
+    duration: u64,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    let auto_unpause_at = if duration == 0 {
+        0
+    } else {
+        env.ledger()
+            .timestamp()
+            .checked_add(duration)
+            .ok_or(SavingsError::Overflow)?
+    };
+
+    env.storage().persistent().set(&DataKey::Paused, &true);
+    env.storage()
+        .persistent()
+        .set(&ConfigKey::AutoUnpauseAt, &auto_unpause_at);
+
+    env.events()
+        .publish((symbol_short!("pause"),), (admin, auto_unpause_at));
+
+    Ok(())
+}
+
 /// Unpauses the contract, restoring all state-changing operations.
 ///
 /// # Arguments
@@ -216,6 +760,7 @@ pub fn unpause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
 
     env.storage().persistent().set(&DataKey::Paused, &false);
+    clear_auto_unpause(env);
 
     env.events().publish((symbol_short!("unpause"),), admin);
 
@@ -230,14 +775,160 @@ pub fn unpause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
 /// # Errors
 /// * `SavingsError::ContractPaused` - If the contract is paused
 pub fn require_not_paused(env: &Env) -> Result<(), SavingsError> {
-    let is_paused: bool = env
+    if is_paused(env) {
+        Err(SavingsError::ContractPaused)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns whether the contract is currently paused.
+///
+/// A timed pause whose `AutoUnpauseAt` timestamp has been reached is
+/// reported as unpaused.
+pub fn is_paused(env: &Env) -> bool {
+    let paused: bool = env
         .storage()
         .persistent()
         .get(&DataKey::Paused)
         .unwrap_or(false);
-    if is_paused {
+    if !paused {
+        return false;
+    }
+
+    let auto_unpause_at = get_auto_unpause_at(env);
+    auto_unpause_at == 0 || env.ledger().timestamp() < auto_unpause_at
+}
+
+/// Returns whether withdrawals are blocked by the global or withdraw-scoped pause.
+pub(crate) fn is_withdraw_paused(env: &Env) -> bool {
+    is_paused(env) || is_op_paused(env, PAUSE_OP_WITHDRAW)
+}
+
+/// Returns the timestamp at which the current pause lifts itself (0 = manual only).
+pub fn get_auto_unpause_at(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::AutoUnpauseAt)
+        .unwrap_or(0)
+}
+
+/// Halts a single class of operations without pausing the whole contract.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `caller` - The admin (or any caller once governance is active)
+/// * `op_code` - One of the `PAUSE_OP_*` codes
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin and governance is inactive
+/// * `SavingsError::InvalidPauseOp` - If `op_code` is not a known operation scope
+pub fn pause_op(env: &Env, caller: Address, op_code: u32) -> Result<(), SavingsError> {
+    set_op_paused(env, caller, op_code, true)
+}
+
+/// Lifts a pause previously set with `pause_op`.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin and governance is inactive
+/// * `SavingsError::InvalidPauseOp` - If `op_code` is not a known operation scope
+pub fn unpause_op(env: &Env, caller: Address, op_code: u32) -> Result<(), SavingsError> {
+    set_op_paused(env, caller, op_code, false)
+}
+
+fn set_op_paused(
+    env: &Env,
+    caller: Address,
+    op_code: u32,
+    paused: bool,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    crate::governance::validate_admin_or_governance(env, &caller)?;
+    if !(PAUSE_OP_DEPOSIT..=PAUSE_OP_STRATEGY).contains(&op_code) {
+        return Err(SavingsError::InvalidPauseOp);
+    }
+
+    let key = ConfigKey::PausedOps(op_code);
+    if paused {
+        env.storage().persistent().set(&key, &true);
+        env.events()
+            .publish((symbol_short!("pause_op"), caller), op_code);
+    } else {
+        env.storage().persistent().remove(&key);
+        env.events()
+            .publish((symbol_short!("unpaus_op"), caller), op_code);
+    }
+
+    Ok(())
+}
+
+/// Returns whether the given operation scope is paused (ignores the global flag).
+pub fn is_op_paused(env: &Env, op_code: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::PausedOps(op_code))
+        .unwrap_or(false)
+}
+
+/// Fails with `ContractPaused` if the given operation scope is paused.
+///
+/// Deposits are also treated as paused while emergency mode is on.
+pub(crate) fn require_op_not_paused(env: &Env, op_code: u32) -> Result<(), SavingsError> {
+    if is_op_paused(env, op_code) || (op_code == PAUSE_OP_DEPOSIT && is_emergency_mode(env)) {
         Err(SavingsError::ContractPaused)
     } else {
         Ok(())
     }
 }
+
+/// Turns on emergency mode: deposits are blocked and withdrawal and
+/// early-break fees are waived so users can exit freely.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `caller` - The admin (or any caller once governance is active)
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin and governance is inactive
+pub fn enable_emergency_mode(env: &Env, caller: Address) -> Result<(), SavingsError> {
+    set_emergency_mode(env, caller, true)
+}
+
+/// Turns emergency mode off, restoring deposits and normal fees.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin and governance is inactive
+pub fn disable_emergency_mode(env: &Env, caller: Address) -> Result<(), SavingsError> {
+    set_emergency_mode(env, caller, false)
+}
+
+fn set_emergency_mode(env: &Env, caller: Address, enabled: bool) -> Result<(), SavingsError> {
+    caller.require_auth();
+    crate::governance::validate_admin_or_governance(env, &caller)?;
+
+    if enabled {
+        env.storage()
+            .persistent()
+            .set(&ConfigKey::EmergencyMode, &true);
+    } else {
+        env.storage().persistent().remove(&ConfigKey::EmergencyMode);
+    }
+    env.events()
+        .publish((symbol_short!("emergency"), caller), enabled);
+
+    Ok(())
+}
+
+/// Returns whether emergency mode is on.
+pub fn is_emergency_mode(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::EmergencyMode)
+        .unwrap_or(false)
+}
+
+/// Clears any scheduled automatic unpause. Called whenever the pause flag is
+/// set or cleared manually so a stale timeout cannot lift a later pause.
+pub(crate) fn clear_auto_unpause(env: &Env) {
+    env.storage().persistent().remove(&ConfigKey::AutoUnpauseAt);
+}
@@ -1,5 +1,10 @@
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, InvokeError};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, InvokeError,
+};
 
+use crate::config::{PAUSE_OP_DEPOSIT, PAUSE_OP_GOVERNANCE, PAUSE_OP_WITHDRAW};
+use crate::rewards::storage_types::RewardsConfig;
 use crate::{NesteraContract, NesteraContractClient, SavingsError};
 
 // ========== Test Helpers ==========
@@ -150,6 +155,111 @@ fn test_get_config_reflects_updates() {
     assert_eq!(config.protocol_fee_bps, 500);
 }
 
+// ========== Admin transfer Tests ==========
+
+#[test]
+fn test_propose_then_accept_admin_transfers_control() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    client.initialize_config(&new_admin, &treasury, &100);
+    assert_eq!(client.get_config().admin, new_admin);
+    assert_savings_error(
+        client.try_set_protocol_fee(&admin, &200).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_accept_admin_from_non_pending_address_fails() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+    let intruder = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client.try_accept_admin(&intruder).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+
+    client.propose_admin(&admin, &new_admin);
+    assert_savings_error(
+        client.try_accept_admin(&intruder).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+    assert_eq!(client.get_config().admin, admin);
+}
+
+#[test]
+fn test_old_admin_retains_control_until_acceptance() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.propose_admin(&admin, &new_admin);
+
+    client.initialize_config(&admin, &treasury, &100);
+    client.set_protocol_fee(&admin, &250);
+    assert_eq!(client.get_config().admin, admin);
+    assert_savings_error(
+        client.try_set_protocol_fee(&new_admin, &300).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
+#[test]
+fn test_cancel_admin_transfer_clears_pending() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.propose_admin(&admin, &new_admin);
+    client.cancel_admin_transfer(&admin);
+
+    assert_eq!(client.get_pending_admin(), None);
+    assert_savings_error(
+        client.try_accept_admin(&new_admin).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+    assert_eq!(client.get_config().admin, admin);
+}
+
+#[test]
+fn test_set_admin_only_proposes() {
+    let (env, client, admin) = setup();
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.set_admin(&admin, &new_admin);
+
+    assert_eq!(client.get_config().admin, admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_config().admin, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_non_admin_cannot_propose_admin() {
+    let (env, client, _admin) = setup();
+    let attacker = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client.try_propose_admin(&attacker, &attacker).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
 // ========== set_treasury Tests ==========
 
 #[test]
@@ -259,6 +369,313 @@ fn test_non_admin_cannot_set_protocol_fee() {
     );
 }
 
+// ========== Fee tier Tests ==========
+
+/// Flat 5% fee, 3% from 1_000 and 1% from 10_000
+fn setup_fee_tiers() -> (Env, NesteraContractClient<'static>, Address) {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+    client.set_fee_recipient(&Address::generate(&env));
+    client.set_protocol_fee_bps(&500);
+    client.set_fee_tiers(
+        &admin,
+        &vec![&env, (1_000i128, 300u32), (10_000i128, 100u32)],
+    );
+    (env, client, admin)
+}
+
+#[test]
+fn test_fee_tier_below_lowest_uses_flat_fee() {
+    let (env, client, _admin) = setup_fee_tiers();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+
+    client.deposit_flexi(&user, &999);
+    // 5% of 999 = 49
+    assert_eq!(client.get_flexi_balance(&user), 950);
+}
+
+#[test]
+fn test_fee_tier_on_boundary_uses_that_tier() {
+    let (env, client, _admin) = setup_fee_tiers();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+
+    client.deposit_flexi(&user, &1_000);
+    // 3% of 1_000 = 30
+    assert_eq!(client.get_flexi_balance(&user), 970);
+
+    client.deposit_flexi(&user, &10_000);
+    // 1% of 10_000 = 100
+    assert_eq!(client.get_flexi_balance(&user), 970 + 9_900);
+}
+
+#[test]
+fn test_fee_tier_above_top_uses_top_tier() {
+    let (env, client, _admin) = setup_fee_tiers();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+
+    client.deposit_flexi(&user, &50_000);
+    // 1% of 50_000 = 500
+    assert_eq!(client.get_flexi_balance(&user), 49_500);
+}
+
+#[test]
+fn test_fee_tiers_apply_to_goal_deposits() {
+    let (env, client, _admin) = setup_fee_tiers();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+
+    let goal_id = client.create_goal_save(
+        &user,
+        &soroban_sdk::Symbol::new(&env, "car"),
+        &100_000,
+        &2_000,
+    );
+    // 3% of 2_000 = 60
+    assert_eq!(client.get_goal_save_detail(&goal_id).current_amount, 1_940);
+}
+
+#[test]
+fn test_set_fee_tiers_rejects_non_ascending_thresholds() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+
+    assert_savings_error(
+        client
+            .try_set_fee_tiers(
+                &admin,
+                &vec![&env, (5_000i128, 300u32), (5_000i128, 100u32)],
+            )
+            .unwrap_err(),
+        SavingsError::InvalidAmount,
+    );
+    assert_savings_error(
+        client
+            .try_set_fee_tiers(
+                &admin,
+                &vec![&env, (5_000i128, 300u32), (1_000i128, 100u32)],
+            )
+            .unwrap_err(),
+        SavingsError::InvalidAmount,
+    );
+}
+
+#[test]
+fn test_set_fee_tiers_rejects_bps_above_max() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+
+    assert_savings_error(
+        client
+            .try_set_fee_tiers(&admin, &vec![&env, (1_000i128, 10_001u32)])
+            .unwrap_err(),
+        SavingsError::InvalidFeeBps,
+    );
+}
+
+#[test]
+fn test_non_admin_cannot_set_fee_tiers() {
+    let (env, client, _admin) = setup();
+    let non_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client
+            .try_set_fee_tiers(&non_admin, &vec![&env, (1_000i128, 300u32)])
+            .unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
+// ========== Fee cap Tests ==========
+
+#[test]
+fn test_fee_above_cap_is_clamped() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_fee_recipient(&treasury);
+    client.set_protocol_fee_bps(&100); // 1%
+    client.set_max_fee(&admin, &500);
+
+    // 1% of 100_000 = 1_000, capped at 500
+    client.deposit_flexi(&user, &100_000);
+    assert_eq!(client.get_flexi_balance(&user), 99_500);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 500);
+}
+
+#[test]
+fn test_fee_below_cap_is_unaffected() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_fee_recipient(&treasury);
+    client.set_protocol_fee_bps(&100); // 1%
+    client.set_max_fee(&admin, &500);
+
+    // 1% of 20_000 = 200, under the cap
+    client.deposit_flexi(&user, &20_000);
+    assert_eq!(client.get_flexi_balance(&user), 19_800);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 200);
+}
+
+#[test]
+fn test_zero_cap_leaves_fees_uncapped() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_fee_recipient(&Address::generate(&env));
+    client.set_protocol_fee_bps(&100);
+    client.set_max_fee(&admin, &0);
+    assert_eq!(client.get_max_fee(), 0);
+
+    client.deposit_flexi(&user, &100_000);
+    assert_eq!(client.get_flexi_balance(&user), 99_000);
+}
+
+#[test]
+fn test_set_negative_max_fee_rejected() {
+    let (env, client, admin) = setup();
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client.try_set_max_fee(&admin, &-1).unwrap_err(),
+        SavingsError::InvalidAmount,
+    );
+}
+
+#[test]
+fn test_non_admin_cannot_set_max_fee() {
+    let (env, client, _admin) = setup();
+    let non_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client.try_set_max_fee(&non_admin, &100).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
+// ========== Max plans per user Tests ==========
+
+#[test]
+fn test_max_plans_per_user_blocks_new_plans_at_limit() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_max_plans_per_user(&admin, &3);
+    assert_eq!(client.get_max_plans_per_user(), 3);
+
+    client.create_savings_plan(&user, &crate::PlanType::Flexi, &100);
+    let lock_id = client.create_lock_save(&user, &100, &10, &false);
+    client.create_goal_save(&user, &soroban_sdk::symbol_short!("trip"), &1_000, &100);
+
+    assert_savings_error(
+        client
+            .try_create_savings_plan(&user, &crate::PlanType::Flexi, &100)
+            .unwrap_err(),
+        SavingsError::MaxPlansExceeded,
+    );
+    assert_eq!(
+        client.try_create_lock_save(&user, &100, &10, &false),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            SavingsError::MaxPlansExceeded as u32
+        )))
+    );
+
+    // Withdrawing a plan frees its slot
+    env.ledger().with_mut(|li| li.timestamp += 10);
+    client.withdraw_lock_save(&user, &lock_id);
+    assert!(client
+        .try_create_lock_save(&user, &100, &10, &false)
+        .is_ok());
+}
+
+#[test]
+fn test_max_plans_per_user_counts_groups_and_merged_locks() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&user);
+    let group_id = client.create_group_save(
+        &creator,
+        &soroban_sdk::String::from_str(&env, "Group"),
+        &soroban_sdk::String::from_str(&env, "Desc"),
+        &soroban_sdk::String::from_str(&env, "savings"),
+        &10_000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000,
+    );
+
+    client.set_max_plans_per_user(&admin, &2);
+    let lock_a = client.create_lock_save(&user, &100, &10, &false);
+    let lock_b = client.create_lock_save(&user, &100, &10, &false);
+    assert_savings_error(
+        client.try_join_group_save(&user, &group_id).unwrap_err(),
+        SavingsError::MaxPlansExceeded,
+    );
+
+    // Merging two locks leaves one open plan
+    client.merge_lock_saves(&user, &lock_a, &lock_b);
+    client.join_group_save(&user, &group_id);
+
+    // Leaving the group frees its slot again
+    client.break_group_save(&user, &group_id);
+    assert!(client
+        .try_create_goal_save(&user, &soroban_sdk::symbol_short!("trip"), &1_000, &100)
+        .is_ok());
+}
+
+#[test]
+fn test_zero_max_plans_per_user_is_unlimited() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_max_plans_per_user(&admin, &1);
+    client.create_savings_plan(&user, &crate::PlanType::Flexi, &100);
+    client.set_max_plans_per_user(&admin, &0);
+
+    for _ in 0..5 {
+        assert!(client
+            .try_create_savings_plan(&user, &crate::PlanType::Flexi, &100)
+            .is_ok());
+    }
+}
+
+#[test]
+fn test_non_admin_cannot_set_max_plans_per_user() {
+    let (env, client, _admin) = setup();
+    let non_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client
+            .try_set_max_plans_per_user(&non_admin, &5)
+            .unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
 // ========== pause / unpause Tests ==========
 
 #[test]
@@ -312,6 +729,250 @@ fn test_non_admin_cannot_unpause() {
     );
 }
 
+// ========== Operation-scoped pause Tests ==========
+
+#[test]
+fn test_pausing_deposits_still_allows_withdrawals() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.deposit_flexi(&user, &1_000);
+
+    client.pause_op(&admin, &PAUSE_OP_DEPOSIT);
+    assert!(client.is_op_paused(&PAUSE_OP_DEPOSIT));
+    assert!(!client.is_op_paused(&PAUSE_OP_WITHDRAW));
+    assert!(!client.is_paused());
+
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+    assert!(client.try_withdraw_flexi(&user, &400).is_ok());
+    assert_eq!(client.get_flexi_balance(&user), 600);
+
+    client.unpause_op(&admin, &PAUSE_OP_DEPOSIT);
+    assert!(!client.is_op_paused(&PAUSE_OP_DEPOSIT));
+    assert!(client.try_deposit_flexi(&user, &100).is_ok());
+}
+
+#[test]
+fn test_pausing_deposits_blocks_lock_split() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    let lock_id = client.create_lock_save(&user, &1_000, &(30 * 86_400), &false);
+
+    client.pause_op(&admin, &PAUSE_OP_DEPOSIT);
+    assert_savings_error(
+        client
+            .try_split_lock_save(&user, &lock_id, &400)
+            .unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+    assert_eq!(client.get_user_lock_saves(&user).len(), 1);
+
+    client.unpause_op(&admin, &PAUSE_OP_DEPOSIT);
+    assert!(client.try_split_lock_save(&user, &lock_id, &400).is_ok());
+}
+
+#[test]
+fn test_pausing_deposits_blocks_lock_merge() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    let lock_a = client.create_lock_save(&user, &1_000, &(30 * 86_400), &false);
+    let lock_b = client.create_lock_save(&user, &500, &(30 * 86_400), &false);
+
+    client.pause_op(&admin, &PAUSE_OP_DEPOSIT);
+    assert_savings_error(
+        client
+            .try_merge_lock_saves(&user, &lock_a, &lock_b)
+            .unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+    assert_eq!(client.get_user_lock_saves(&user).len(), 2);
+
+    client.unpause_op(&admin, &PAUSE_OP_DEPOSIT);
+    assert!(client.try_merge_lock_saves(&user, &lock_a, &lock_b).is_ok());
+}
+
+#[test]
+fn test_pausing_withdrawals_still_allows_deposits() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.pause_op(&admin, &PAUSE_OP_WITHDRAW);
+
+    assert!(client.try_deposit_flexi(&user, &1_000).is_ok());
+    assert_savings_error(
+        client.try_withdraw_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+}
+
+#[test]
+fn test_pause_op_rejects_non_admin_and_unknown_op() {
+    let (env, client, admin) = setup();
+    let non_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client
+            .try_pause_op(&non_admin, &PAUSE_OP_GOVERNANCE)
+            .unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+    assert_savings_error(
+        client.try_pause_op(&admin, &0).unwrap_err(),
+        SavingsError::InvalidPauseOp,
+    );
+    assert_savings_error(
+        client.try_unpause_op(&admin, &99).unwrap_err(),
+        SavingsError::InvalidPauseOp,
+    );
+}
+
+// ========== Emergency mode Tests ==========
+
+#[test]
+fn test_emergency_mode_waives_withdraw_fee_and_blocks_deposits() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    assert!(client.try_set_fee_recipient(&treasury).is_ok());
+    client.deposit_flexi(&user, &1_000);
+    assert!(client.try_set_protocol_fee_bps(&500).is_ok());
+
+    client.enable_emergency_mode(&admin);
+    assert!(client.is_emergency_mode());
+
+    client.withdraw_flexi(&user, &1_000);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 0);
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+    assert_savings_error(
+        client
+            .try_create_savings_plan(&user, &crate::PlanType::Flexi, &100)
+            .unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+
+    client.disable_emergency_mode(&admin);
+    assert!(!client.is_emergency_mode());
+    assert!(client.try_deposit_flexi(&user, &100).is_ok());
+}
+
+#[test]
+fn test_non_admin_cannot_toggle_emergency_mode() {
+    let (env, client, admin) = setup();
+    let non_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client.try_enable_emergency_mode(&non_admin).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+    client.enable_emergency_mode(&admin);
+    assert_savings_error(
+        client.try_disable_emergency_mode(&non_admin).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+    assert!(client.is_emergency_mode());
+}
+
+// ========== Timed pause Tests ==========
+
+#[test]
+fn test_timed_pause_blocks_until_timeout_then_lifts() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    client.pause_contract_with_timeout(&admin, &3_600);
+    assert_eq!(client.get_auto_unpause_at(), 4_600);
+    assert!(client.is_paused());
+
+    env.ledger().with_mut(|li| li.timestamp = 4_599);
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    assert!(!client.is_paused());
+    assert!(!client.get_config().paused);
+    assert!(client.try_deposit_flexi(&user, &100).is_ok());
+}
+
+#[test]
+fn test_timed_pause_zero_duration_requires_manual_unpause() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.pause_contract_with_timeout(&admin, &0);
+    assert_eq!(client.get_auto_unpause_at(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 10_000_000);
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+
+    client.unpause_contract(&admin);
+    assert!(client.try_deposit_flexi(&user, &100).is_ok());
+}
+
+#[test]
+fn test_manual_pause_clears_expired_timeout() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.pause_contract_with_timeout(&admin, &60);
+    env.ledger().with_mut(|li| li.timestamp += 120);
+
+    // A later manual pause must not be lifted by the stale timeout
+    client.pause_contract(&admin);
+    assert_eq!(client.get_auto_unpause_at(), 0);
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+}
+
+#[test]
+fn test_non_admin_cannot_pause_with_timeout() {
+    let (env, client, _admin) = setup();
+    let non_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client
+            .try_pause_contract_with_timeout(&non_admin, &60)
+            .unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+}
+
 // ========== Pause Blocks State-Changing Operations ==========
 
 #[test]
@@ -443,3 +1104,105 @@ fn test_full_config_lifecycle() {
     client.unpause_contract(&admin);
     assert!(!client.get_config().paused);
 }
+
+// ========== bootstrap Tests ==========
+
+fn bootstrap_rewards_config(points_per_token: u32) -> RewardsConfig {
+    RewardsConfig {
+        points_per_token,
+        streak_bonus_bps: 0,
+        long_lock_bonus_bps: 0,
+        goal_completion_bonus: 0,
+        enabled: true,
+        min_deposit_for_rewards: 0,
+        action_cooldown_seconds: 0,
+        max_daily_points: 1_000_000,
+        max_streak_multiplier: 10_000,
+        streak_window_secs: 0,
+        streak_threshold: 0,
+        maturity_bonus_bps: 0,
+    }
+}
+
+#[test]
+fn test_bootstrap_initializes_all_subsystems() {
+    let env = Env::default();
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+    env.mock_all_auths();
+    client.bootstrap(
+        &admin,
+        &admin_pk,
+        &treasury,
+        &100,
+        &bootstrap_rewards_config(10),
+    );
+
+    assert!(client.is_initialized());
+    let config = client.get_config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.treasury, treasury);
+    assert_eq!(config.protocol_fee_bps, 100);
+    assert_eq!(client.get_rewards_config().points_per_token, 10);
+}
+
+#[test]
+fn test_bootstrap_twice_fails_without_partial_changes() {
+    let env = Env::default();
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+    env.mock_all_auths();
+    client.bootstrap(
+        &admin,
+        &admin_pk,
+        &treasury,
+        &100,
+        &bootstrap_rewards_config(10),
+    );
+
+    let other_treasury = Address::generate(&env);
+    let result = client.try_bootstrap(
+        &admin,
+        &admin_pk,
+        &other_treasury,
+        &200,
+        &bootstrap_rewards_config(20),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::ContractError::AlreadyInitialized as u32
+        )))
+    );
+
+    let config = client.get_config();
+    assert_eq!(config.treasury, treasury);
+    assert_eq!(config.protocol_fee_bps, 100);
+    assert_eq!(client.get_rewards_config().points_per_token, 10);
+}
+
+#[test]
+fn test_rewards_config_or_default_before_and_after_init() {
+    let (_env, client, _admin) = setup();
+
+    assert_eq!(
+        client.try_get_rewards_config(),
+        Err(Ok(SavingsError::InternalError))
+    );
+    let default = client.get_rewards_config_or_default();
+    assert!(!default.enabled);
+    assert_eq!(default.points_per_token, 0);
+    assert_eq!(default.max_daily_points, 0);
+
+    let config = bootstrap_rewards_config(10);
+    client.initialize_rewards_config(&config);
+    assert_eq!(client.get_rewards_config_or_default(), config);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::contracterror;
+use soroban_sdk::{contracterror, log, Env, Symbol};
 
 /// Global error enum for the Nestera Savings Protocol
 ///
@@ -6,7 +6,8 @@ use soroban_sdk::contracterror;
 /// the savings contract modules. Each error is assigned a unique code
 /// and provides a descriptive name for debugging and error handling.
 ///
-/// Error codes range from 1-99 and are mapped to u32 for Soroban compatibility.
+/// Error codes are grouped by category in ranges of ten, with later additions
+/// numbered from 100 upward, and are mapped to u32 for Soroban compatibility.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -85,12 +86,14 @@ pub enum SavingsError {
 
     /// Returned when an amount exceeds the configured maximum limit.
     ///
-    /// This may apply to single transactions or cumulative amounts.
+    /// This may apply to single transactions or cumulative amounts, such as
+    /// the daily withdrawal limit.
     AmountExceedsLimit = 42,
 
     /// Returned when an amount is below the required minimum threshold.
     ///
-    /// Some operations may require minimum amounts for efficiency or viability.
+    /// Some operations may require minimum amounts for efficiency or viability,
+    /// and strategy withdrawals return it when they yield less than `min_out`.
     AmountBelowMinimum = 43,
 
     // ========== Timestamp and Time-Related Errors (50-59) ==========
@@ -106,6 +109,7 @@ pub enum SavingsError {
     ///
     /// This occurs when:
     /// - Withdrawing from a locked plan before maturity
+    /// - Rejoining a group before the join cooldown has passed
     /// - Accessing time-gated features prematurely
     TooEarly = 51,
 
@@ -228,6 +232,59 @@ pub enum SavingsError {
 
     /// Returned when attempting to register a strategy that already exists.
     StrategyAlreadyRegistered = 96,
+
+    /// Returned when a cross-contract call to a yield strategy fails.
+    StrategyCallFailed = 97,
+
+    /// Returned when a strategy-interacting function is re-entered while a
+    /// previous call is still in progress.
+    Reentrancy = 98,
+
+    /// Returned when a proposal is created with an empty title or category.
+    InvalidProposal = 99,
+
+    /// Returned when an operation-scoped pause uses an unknown op code.
+    InvalidPauseOp = 100,
+
+    /// Returned when a Flexi withdrawal is attempted within the cooldown
+    /// window after the user's last deposit.
+    WithdrawLocked = 101,
+
+    /// Returned when `upgrade` is given a version no newer than the current
+    /// one, or `migrate` is called again for an already-migrated version.
+    AlreadyMigrated = 102,
+
+    /// Returned when an action proposal duplicates the action of one that has
+    /// not yet been executed, defeated or canceled.
+    DuplicateProposal = 103,
+
+    /// Returned when routing would push a strategy's total principal above
+    /// its configured allocation cap.
+    StrategyCapExceeded = 104,
+
+    /// Returned when closing a group that still has members and has not
+    /// yet ended.
+    GroupNotEmpty = 105,
+
+    /// Returned when deregistering a strategy that still holds routed
+    /// principal.
+    StrategyHasFunds = 106,
+
+    /// Returned when a group contribution does not match the group's
+    /// fixed amount, falls below its flexible minimum, or would take the
+    /// group past its target.
+    InvalidContribution = 107,
+}
+
+/// Logs the operation that overflowed and returns `SavingsError::Overflow`,
+/// so overflow sites can be told apart in diagnostics.
+///
+/// A contract event would be discarded along with the reverted invocation, so
+/// this writes a diagnostic log instead, which is kept for failed calls (and
+/// compiled out of release builds).
+pub(crate) fn overflow(env: &Env, operation: &str) -> SavingsError {
+    log!(env, "overflow", Symbol::new(env, operation));
+    SavingsError::Overflow
 }
 
 #[cfg(test)]
@@ -276,6 +333,19 @@ mod tests {
             SavingsError::StrategyNotFound as u32,
             SavingsError::StrategyAlreadyRegistered as u32,
             SavingsError::StrategyDisabled as u32,
+            SavingsError::StrategyCallFailed as u32,
+            SavingsError::AlreadyWithdrawn as u32,
+            SavingsError::LockNotFound as u32,
+            SavingsError::Reentrancy as u32,
+            SavingsError::InvalidProposal as u32,
+            SavingsError::InvalidPauseOp as u32,
+            SavingsError::WithdrawLocked as u32,
+            SavingsError::AlreadyMigrated as u32,
+            SavingsError::DuplicateProposal as u32,
+            SavingsError::StrategyCapExceeded as u32,
+            SavingsError::GroupNotEmpty as u32,
+            SavingsError::StrategyHasFunds as u32,
+            SavingsError::InvalidContribution as u32,
         ];
 
         let mut sorted = errors.clone();
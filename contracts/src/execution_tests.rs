@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod execution_tests {
-    use crate::governance::ProposalAction;
+    use crate::governance::{ProposalAction, VotingConfig};
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
         Address, BytesN, Env, String,
@@ -28,6 +28,9 @@ mod execution_tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
         client.initialize_rewards_config(&config);
 
@@ -48,7 +51,13 @@ mod execution_tests {
 
         let action = ProposalAction::SetFlexiRate(500);
         let proposal_id = client
-            .try_create_action_proposal(&creator, &description, &action)
+            .try_create_action_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &description,
+                &action,
+            )
             .unwrap()
             .unwrap();
 
@@ -69,6 +78,80 @@ mod execution_tests {
         (env, client, admin, proposal_id)
     }
 
+    fn create_flexi_rate_proposal(
+        env: &Env,
+        client: &NesteraContractClient<'static>,
+        creator: &Address,
+        rate: i128,
+    ) -> Result<u64, SavingsError> {
+        client
+            .try_create_action_proposal(
+                creator,
+                &String::from_str(env, "Rate"),
+                &String::from_str(env, "general"),
+                &String::from_str(env, "Change flexi rate"),
+                &ProposalAction::SetFlexiRate(rate),
+            )
+            .map(|id| id.unwrap())
+            .map_err(|e| e.unwrap())
+    }
+
+    #[test]
+    fn test_duplicate_action_proposal_rejected_while_active() {
+        let (env, client, _admin, first_id) = setup_with_voted_proposal();
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        // setup_with_voted_proposal already opened SetFlexiRate(500)
+        assert_eq!(
+            create_flexi_rate_proposal(&env, &client, &creator, 500),
+            Err(SavingsError::DuplicateProposal)
+        );
+
+        // A different action is fine
+        let other_id = create_flexi_rate_proposal(&env, &client, &creator, 600).unwrap();
+        assert_ne!(other_id, first_id);
+
+        // A passed proposal holds the action through the queue until executed
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        assert_eq!(
+            create_flexi_rate_proposal(&env, &client, &creator, 500),
+            Err(SavingsError::DuplicateProposal)
+        );
+        client.queue_proposal(&first_id);
+        assert_eq!(
+            create_flexi_rate_proposal(&env, &client, &creator, 500),
+            Err(SavingsError::DuplicateProposal)
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += 86400 + 1);
+        client.execute_proposal(&first_id);
+        assert!(create_flexi_rate_proposal(&env, &client, &creator, 500).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_action_freed_by_defeat_or_cancel() {
+        let (env, client, admin) = setup_contract();
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        // Canceled by its creator
+        let canceled = create_flexi_rate_proposal(&env, &client, &creator, 700).unwrap();
+        env.as_contract(&client.address, || {
+            crate::governance::cancel_proposal(&env, canceled, creator.clone()).unwrap();
+        });
+        let defeated = create_flexi_rate_proposal(&env, &client, &creator, 700).unwrap();
+
+        // Defeated once voting ends without a majority
+        client.vote(&defeated, &2, &creator);
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        assert!(create_flexi_rate_proposal(&env, &client, &creator, 700).is_ok());
+    }
+
     #[test]
     fn test_queue_proposal_success() {
         let (env, client, _admin, proposal_id) = setup_with_voted_proposal();
@@ -110,7 +193,13 @@ mod execution_tests {
 
         let action = ProposalAction::SetFlexiRate(500);
         let proposal_id = client
-            .try_create_action_proposal(&creator, &description, &action)
+            .try_create_action_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &description,
+                &action,
+            )
             .unwrap()
             .unwrap();
 
@@ -244,7 +333,13 @@ mod execution_tests {
 
         let action = ProposalAction::SetFlexiRate(750);
         let proposal_id = client
-            .try_create_action_proposal(&creator, &description, &action)
+            .try_create_action_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &description,
+                &action,
+            )
             .unwrap()
             .unwrap();
 
@@ -296,7 +391,13 @@ mod execution_tests {
 
         let action = ProposalAction::PauseContract;
         let proposal_id = client
-            .try_create_action_proposal(&creator, &description, &action)
+            .try_create_action_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &description,
+                &action,
+            )
             .unwrap()
             .unwrap();
 
@@ -317,4 +418,203 @@ mod execution_tests {
 
         assert!(client.is_paused());
     }
+
+    fn pass_action_proposal(
+        env: &Env,
+        client: &NesteraContractClient<'static>,
+        admin: &Address,
+        action: ProposalAction,
+    ) -> u64 {
+        let proposal_id = queue_action_proposal(env, client, admin, action);
+        client.execute_proposal(&proposal_id);
+        proposal_id
+    }
+
+    /// Creates, votes through and queues an action proposal, then waits out the timelock.
+    fn queue_action_proposal(
+        env: &Env,
+        client: &NesteraContractClient<'static>,
+        admin: &Address,
+        action: ProposalAction,
+    ) -> u64 {
+        client.init_voting_config(admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(env);
+        let description = String::from_str(env, "Strategy proposal");
+
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        let proposal_id = client
+            .try_create_action_proposal(
+                &creator,
+                &String::from_str(env, "Title"),
+                &String::from_str(env, "general"),
+                &description,
+                &action,
+            )
+            .unwrap()
+            .unwrap();
+
+        let voter = Address::generate(env);
+        client.initialize_user(&voter);
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &5000);
+        client.vote(&proposal_id, &1, &voter);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 604800 + 1;
+        });
+        client.queue_proposal(&proposal_id);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 86400 + 1;
+        });
+
+        proposal_id
+    }
+
+    #[test]
+    fn test_execute_register_strategy_action() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        let strategy = Address::generate(&env);
+        let action = ProposalAction::RegisterStrategy(strategy.clone(), 3);
+        pass_action_proposal(&env, &client, &admin, action);
+
+        let info = client.get_strategy(&strategy);
+        assert_eq!(info.address, strategy);
+        assert_eq!(info.risk_level, 3);
+        assert!(info.enabled);
+
+        let all = client.get_all_strategies();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all.get(0).unwrap(), strategy);
+    }
+
+    #[test]
+    fn test_execute_deregister_strategy_action() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        let strategy = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.register_strategy(&admin, &strategy, &1);
+        client.register_strategy(&admin, &other, &2);
+
+        let action = ProposalAction::DeregisterStrategy(strategy.clone());
+        pass_action_proposal(&env, &client, &admin, action);
+
+        assert!(client.try_get_strategy(&strategy).is_err());
+        let all = client.get_all_strategies();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all.get(0).unwrap(), other);
+    }
+
+    #[test]
+    fn test_execute_set_voting_config_action() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        let new_config = VotingConfig {
+            quorum: 2_500,
+            voting_period: 3 * 86400,
+            timelock_duration: 43200,
+            proposal_threshold: 50,
+            max_voting_power: 20_000,
+        };
+        let action = ProposalAction::SetVotingConfig(new_config.clone());
+        pass_action_proposal(&env, &client, &admin, action);
+
+        assert_eq!(client.get_voting_config(), new_config);
+    }
+
+    #[test]
+    fn test_execute_set_voting_config_rejects_invalid_quorum() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        let action = ProposalAction::SetVotingConfig(VotingConfig {
+            quorum: 10_001,
+            voting_period: 86400,
+            timelock_duration: 86400,
+            proposal_threshold: 100,
+            max_voting_power: 10_000,
+        });
+        let proposal_id = queue_action_proposal(&env, &client, &admin, action);
+
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(client.get_voting_config().quorum, 5000);
+    }
+
+    #[test]
+    fn test_execute_set_voting_config_rejects_zero_voting_period() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        let action = ProposalAction::SetVotingConfig(VotingConfig {
+            quorum: 5000,
+            voting_period: 0,
+            timelock_duration: 86400,
+            proposal_threshold: 100,
+            max_voting_power: 10_000,
+        });
+        let proposal_id = queue_action_proposal(&env, &client, &admin, action);
+
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(client.get_voting_config().voting_period, 604800);
+    }
+
+    #[test]
+    fn test_execute_set_protocol_fee_action() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        let treasury = Address::generate(&env);
+        client.initialize_config(&admin, &treasury, &100);
+
+        pass_action_proposal(&env, &client, &admin, ProposalAction::SetProtocolFee(250));
+
+        assert_eq!(client.get_config().protocol_fee_bps, 250);
+    }
+
+    #[test]
+    fn test_execute_set_protocol_fee_rejects_invalid_bps() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        let treasury = Address::generate(&env);
+        client.initialize_config(&admin, &treasury, &100);
+
+        let proposal_id = queue_action_proposal(
+            &env,
+            &client,
+            &admin,
+            ProposalAction::SetProtocolFee(10_001),
+        );
+
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(SavingsError::InvalidFeeBps))
+        );
+        assert_eq!(client.get_config().protocol_fee_bps, 100);
+    }
+
+    #[test]
+    fn test_execute_set_treasury_action() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        let treasury = Address::generate(&env);
+        client.initialize_config(&admin, &treasury, &100);
+
+        let new_treasury = Address::generate(&env);
+        let action = ProposalAction::SetTreasury(new_treasury.clone());
+        pass_action_proposal(&env, &client, &admin, action);
+
+        assert_eq!(client.get_config().treasury, new_treasury);
+    }
 }
@@ -1,11 +1,16 @@
 // New/Correct
-use crate::calculate_fee;
+use crate::calculate_capped_fee;
+use crate::calculate_tiered_fee;
+use crate::config;
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::history;
 use crate::invariants;
 use crate::rewards;
-use crate::storage_types::{DataKey, User};
+use crate::stats;
+use crate::storage_types::{ConfigKey, DataKey, User};
 use crate::ttl;
+use crate::users;
 use soroban_sdk::{symbol_short, Address, Env};
 
 /// Handles depositing funds into the Flexi Save pool.
@@ -20,14 +25,11 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
         return Err(SavingsError::InvalidAmount);
     }
 
-    // 3. Calculate protocol fee
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PlatformFee)
-        .unwrap_or(0);
+    // Register the user inline if auto-init is on
+    users::ensure_user(&env, &user)?;
 
-    let fee_amount = calculate_fee(amount, fee_bps)?;
+    // 3. Calculate protocol fee (tiered by deposit size)
+    let fee_amount = calculate_tiered_fee(&env, amount)?;
     let net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -42,6 +44,10 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
     env.storage()
         .persistent()
         .set(&flexi_key, &new_flexi_balance);
+    env.storage().persistent().set(
+        &DataKey::LastFlexiDeposit(user.clone()),
+        &env.ledger().timestamp(),
+    );
 
     // 5. Sync with the main User struct (Total Balance)
     let user_key = DataKey::User(user.clone());
@@ -54,6 +60,8 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
     } else {
         return Err(SavingsError::UserNotFound);
     }
+    stats::record_deposit(&env, net_amount)?;
+    history::record(&env, &user, symbol_short!("flexi_dep"), amount, 0);
 
     // Extend TTL on user interaction
     ttl::extend_user_ttl(&env, &user);
@@ -68,7 +76,7 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
             .instance()
             .get::<DataKey, Address>(&DataKey::FeeRecipient)
         {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
             let current_fee_balance = env
                 .storage()
                 .persistent()
@@ -78,11 +86,17 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
+            stats::record_fee(&env, fee_amount)?;
             env.events()
                 .publish((symbol_short!("dep_fee"), fee_recipient), fee_amount);
         }
     }
 
+    env.events().publish(
+        (symbol_short!("flexi_dep"), user),
+        (amount, new_flexi_balance),
+    );
+
     Ok(())
 }
 
@@ -98,20 +112,38 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
         return Err(SavingsError::InvalidAmount);
     }
 
+    // Enforce the optional cooldown since the user's last deposit
+    let cooldown = get_flexi_withdraw_cooldown(&env);
+    if cooldown > 0 {
+        if let Some(last_deposit) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::LastFlexiDeposit(user.clone()))
+        {
+            if env.ledger().timestamp() < last_deposit.saturating_add(cooldown) {
+                return Err(SavingsError::WithdrawLocked);
+            }
+        }
+    }
+
     // 1. Fetch the balance first
     let current_balance = get_flexi_balance(&env, user.clone()).unwrap_or(0);
 
     // 2. Now the variable 'current_balance' exists in this scope
     invariants::assert_sufficient_balance(current_balance, amount)?;
+    config::consume_daily_withdrawal(&env, &user, amount)?;
 
-    // 3. Calculate protocol fee
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PlatformFee)
-        .unwrap_or(0);
+    // 3. Calculate protocol fee (waived in emergency mode)
+    let fee_bps: u32 = if config::is_emergency_mode(&env) {
+        0
+    } else {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformFee)
+            .unwrap_or(0)
+    };
 
-    let fee_amount = calculate_fee(amount, fee_bps)?;
+    let fee_amount = calculate_capped_fee(&env, amount, fee_bps)?;
     let _net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -140,6 +172,8 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
             .ok_or(SavingsError::Underflow)?;
         env.storage().persistent().set(&user_key, &user_data);
     }
+    stats::record_withdrawal(&env, amount);
+    history::record(&env, &user, symbol_short!("flexi_wd"), amount, 0);
 
     // Extend TTL on user interaction
     ttl::extend_user_ttl(&env, &user);
@@ -151,7 +185,7 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
             .instance()
             .get::<DataKey, Address>(&DataKey::FeeRecipient)
         {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
             let current_fee_balance = env
                 .storage()
                 .persistent()
@@ -161,11 +195,17 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
+            stats::record_fee(&env, fee_amount)?;
             env.events()
                 .publish((symbol_short!("wth_fee"), fee_recipient), fee_amount);
         }
     }
 
+    env.events().publish(
+        (symbol_short!("flexi_wd"), user),
+        (amount, new_flexi_balance),
+    );
+
     Ok(())
 }
 /// Returns the user's Flexi Save balance.
@@ -189,6 +229,101 @@ pub fn get_flexi_balance(env: &Env, user: Address) -> Result<i128, SavingsError>
     Ok(balance)
 }
 
+/// Sets how long (in seconds) Flexi withdrawals stay locked after a deposit.
+///
+/// A cooldown of 0 disables the check.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+pub fn set_flexi_withdraw_cooldown(
+    env: &Env,
+    admin: Address,
+    seconds: u64,
+) -> Result<(), SavingsError> {
+    config::require_admin(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::FlexiWithdrawCooldown, &seconds);
+    env.events()
+        .publish((symbol_short!("flx_cool"), admin), seconds);
+
+    Ok(())
+}
+
+/// Returns the Flexi withdrawal cooldown in seconds (0 = none).
+pub fn get_flexi_withdraw_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::FlexiWithdrawCooldown)
+        .unwrap_or(0)
+}
+
+/// Credits `amount` directly to a user's Flexi balance, without fees or
+/// `total_balance` bookkeeping. Callers keep the user totals in sync.
+///
+/// # Errors
+/// * `Overflow` - If the new balance would overflow
+pub(crate) fn credit_flexi_balance(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let current: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    let updated = current.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&flexi_key, &updated);
+    Ok(())
+}
+
+/// Credits a verified mint into the user's Flexi balance, creating the
+/// user record first if needed. No fee is charged on minted amounts.
+///
+/// # Errors
+/// * `InvalidAmount` - If `amount` is not positive
+/// * `Overflow` - If a balance would overflow
+pub(crate) fn credit_minted_flexi(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if !users::user_exists(env, user) {
+        users::create_user_record(env, user.clone());
+    }
+
+    credit_flexi_balance(env, user, amount)?;
+
+    let user_key = DataKey::User(user.clone());
+    let mut user_data: User = env
+        .storage()
+        .persistent()
+        .get(&user_key)
+        .ok_or(SavingsError::UserNotFound)?;
+    user_data.total_balance = user_data
+        .total_balance
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&user_key, &user_data);
+    stats::record_deposit(env, amount)?;
+    history::record(env, user, symbol_short!("flexi_dep"), amount, 0);
+    ttl::extend_user_ttl(env, user);
+
+    let new_flexi_balance: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FlexiBalance(user.clone()))
+        .unwrap_or(0);
+    env.events().publish(
+        (symbol_short!("flexi_dep"), user.clone()),
+        (amount, new_flexi_balance),
+    );
+
+    Ok(new_flexi_balance)
+}
+
 /// Returns true if the user has a non-zero Flexi Save balance.
 /// This function does not mutate storage.
 pub fn has_flexi_balance(env: &Env, user: Address) -> bool {
@@ -203,8 +338,12 @@ pub fn has_flexi_balance(env: &Env, user: Address) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::{NesteraContract, NesteraContractClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env};
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events, Ledger},
+        Address, Env, IntoVal,
+    };
 
     fn setup_admin_env() -> (Env, NesteraContractClient<'static>, Address) {
         let env = Env::default();
@@ -219,6 +358,139 @@ mod tests {
         (env, client, admin)
     }
 
+    #[test]
+    fn test_flexi_withdraw_blocked_during_cooldown() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.set_flexi_withdraw_cooldown(&admin, &3_600);
+        assert_eq!(client.get_flexi_withdraw_cooldown(), 3_600);
+
+        client.deposit_flexi(&user, &1_000);
+        assert_eq!(
+            client.try_withdraw_flexi(&user, &500),
+            Err(Ok(SavingsError::WithdrawLocked))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += 3_599);
+        assert_eq!(
+            client.try_withdraw_flexi(&user, &500),
+            Err(Ok(SavingsError::WithdrawLocked))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        assert!(client.try_withdraw_flexi(&user, &500).is_ok());
+        assert_eq!(client.get_flexi_balance(&user), 500);
+    }
+
+    #[test]
+    fn test_auto_init_registers_user_on_first_deposit() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.set_auto_init_users(&admin, &true);
+        assert!(client.get_auto_init_users());
+
+        client.deposit_flexi(&user, &1_000);
+        assert!(client.user_exists(&user));
+        assert_eq!(client.get_flexi_balance(&user), 1_000);
+        assert_eq!(client.get_user(&user).total_balance, 1_000);
+    }
+
+    #[test]
+    fn test_deposit_requires_initialized_user_without_auto_init() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        assert!(!client.get_auto_init_users());
+        assert_eq!(
+            client.try_deposit_flexi(&user, &1_000),
+            Err(Ok(SavingsError::UserNotFound))
+        );
+        assert!(!client.user_exists(&user));
+    }
+
+    #[test]
+    fn test_flexi_withdraw_without_cooldown_is_immediate() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert_eq!(client.get_flexi_withdraw_cooldown(), 0);
+
+        client.deposit_flexi(&user, &1_000);
+        assert!(client.try_withdraw_flexi(&user, &1_000).is_ok());
+    }
+
+    #[test]
+    fn test_non_admin_cannot_set_flexi_withdraw_cooldown() {
+        let (env, client, _admin) = setup_admin_env();
+        let non_admin = Address::generate(&env);
+
+        env.mock_all_auths();
+        assert_eq!(
+            client.try_set_flexi_withdraw_cooldown(&non_admin, &60),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_flexi_deposit_emits_event_with_new_balance() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &1_000);
+        client.deposit_flexi(&user, &250);
+
+        let events = env.events().all();
+        let event = events
+            .iter()
+            .rev()
+            .find(|e| {
+                e.0 == client.address
+                    && e.1 == (symbol_short!("flexi_dep"), user.clone()).into_val(&env)
+            })
+            .expect("flexi_dep event not emitted");
+        let (amount, new_balance): (i128, i128) = event.2.into_val(&env);
+
+        assert_eq!(amount, 250);
+        assert_eq!(new_balance, 1_250);
+        assert_eq!(new_balance, client.get_flexi_balance(&user));
+    }
+
+    #[test]
+    fn test_flexi_withdraw_emits_event_with_new_balance() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &1_000);
+        client.withdraw_flexi(&user, &400);
+
+        let events = env.events().all();
+        let event = events
+            .iter()
+            .rev()
+            .find(|e| {
+                e.0 == client.address
+                    && e.1 == (symbol_short!("flexi_wd"), user.clone()).into_val(&env)
+            })
+            .expect("flexi_wd event not emitted");
+        let (amount, new_balance): (i128, i128) = event.2.into_val(&env);
+
+        assert_eq!(amount, 400);
+        assert_eq!(new_balance, 600);
+        assert_eq!(new_balance, client.get_flexi_balance(&user));
+    }
+
     #[test]
     fn test_flexi_deposit_with_protocol_fee() {
         let (env, client, _admin) = setup_admin_env();
@@ -328,4 +600,85 @@ mod tests {
         assert_eq!(client.get_flexi_balance(&user), 50);
         assert_eq!(client.get_protocol_fee_balance(&treasury), 0);
     }
+
+    #[test]
+    fn test_fee_recipient_who_is_user_has_distinct_balances() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.initialize_user(&treasury);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_protocol_fee_bps(&500).is_ok()); // 5%
+
+        // Treasury saves as a regular user: 2,000 - 100 fee to itself
+        client.deposit_flexi(&treasury, &2_000);
+        // Another user deposits: 10,000 - 500 fee
+        client.deposit_flexi(&user, &10_000);
+
+        assert_eq!(client.get_flexi_balance(&treasury), 1_900);
+        assert_eq!(client.get_user(&treasury).total_balance, 1_900);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 600);
+
+        // Withdrawing savings leaves accrued fees untouched, apart from the new fee
+        client.withdraw_flexi(&treasury, &1_000);
+        assert_eq!(client.get_flexi_balance(&treasury), 900);
+        assert_eq!(client.get_user(&treasury).total_balance, 900);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 650);
+    }
+
+    #[test]
+    fn test_daily_withdraw_limit_resets_next_day() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &5_000);
+        client.set_daily_withdraw_limit(&admin, &1_000);
+        assert_eq!(client.get_daily_withdraw_limit(), 1_000);
+
+        client.withdraw_flexi(&user, &600);
+        client.withdraw_flexi(&user, &400);
+        assert_eq!(
+            client.try_withdraw_flexi(&user, &1),
+            Err(Ok(SavingsError::AmountExceedsLimit))
+        );
+        assert_eq!(client.get_flexi_balance(&user), 4_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        client.withdraw_flexi(&user, &1_000);
+        assert_eq!(client.get_flexi_balance(&user), 3_000);
+
+        client.set_daily_withdraw_limit(&admin, &0);
+        client.withdraw_flexi(&user, &3_000);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_daily_withdraw_limit_spans_flexi_and_plans() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &1_000);
+        let goal_id = client.create_goal_save(&user, &symbol_short!("car"), &5_000, &800);
+        client.set_daily_withdraw_limit(&admin, &1_000);
+
+        client.withdraw_flexi(&user, &500);
+        assert_eq!(
+            client.try_break_goal_save(&user, &goal_id),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::AmountExceedsLimit as u32
+            )))
+        );
+
+        assert_eq!(
+            client.try_set_daily_withdraw_limit(&user, &0),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
 }
@@ -1,10 +1,19 @@
 use soroban_sdk::{symbol_short, Address, Env, Vec};
 
-use crate::calculate_fee;
+use crate::calculate_capped_fee;
+use crate::calculate_tiered_fee;
+use crate::config;
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::history;
+use crate::lock::{
+    WITHDRAW_ALREADY_WITHDRAWN, WITHDRAW_NOT_MATURED, WITHDRAW_NOT_OWNER, WITHDRAW_OK,
+    WITHDRAW_PAUSED,
+};
+use crate::rates;
 use crate::rewards::storage;
-use crate::storage_types::{DataKey, GoalSave, User};
+use crate::stats;
+use crate::storage_types::{ConfigKey, DataKey, GoalSave, User, WithdrawalReceipt};
 use crate::ttl;
 use crate::users;
 
@@ -26,18 +35,11 @@ pub fn create_goal_save(
         return Err(SavingsError::InvalidAmount);
     }
 
-    if !users::user_exists(env, &user) {
-        return Err(SavingsError::UserNotFound);
-    }
-
-    // Calculate protocol fee on initial deposit
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PlatformFee)
-        .unwrap_or(0);
+    users::ensure_user(env, &user)?;
+    users::ensure_plan_capacity(env, &user)?;
 
-    let fee_amount = calculate_fee(initial_deposit, fee_bps)?;
+    // Calculate protocol fee on initial deposit (tiered by deposit size)
+    let fee_amount = calculate_tiered_fee(env, initial_deposit)?;
     let net_initial_deposit = initial_deposit
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -51,18 +53,29 @@ pub fn create_goal_save(
         goal_name: goal_name.clone(),
         target_amount,
         current_amount: net_initial_deposit,
-        interest_rate: 500,
+        interest_rate: rates::resolve_goal_rate(env)?,
         start_time: current_time,
         is_completed: net_initial_deposit >= target_amount,
         is_withdrawn: false,
+        contribution_interval: 0,
+        contribution_amount: 0,
+        next_contribution_time: 0,
     };
 
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_deposit(env, net_initial_deposit)?;
+    history::record(
+        env,
+        &user,
+        symbol_short!("goal_dep"),
+        net_initial_deposit,
+        goal_id,
+    );
 
     if goal_save.is_completed {
-        storage::award_goal_completion_bonus(env, user.clone())?;
+        storage::award_goal_completion_bonus(env, user.clone(), target_amount)?;
     }
 
     // Transfer fee to treasury if fee > 0
@@ -72,7 +85,7 @@ pub fn create_goal_save(
             .instance()
             .get::<DataKey, Address>(&DataKey::FeeRecipient)
         {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
             let current_fee_balance = env
                 .storage()
                 .persistent()
@@ -82,6 +95,7 @@ pub fn create_goal_save(
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
+            stats::record_fee(env, fee_amount)?;
             env.events().publish(
                 (symbol_short!("gdep_fee"), fee_recipient, goal_id),
                 fee_amount,
@@ -90,6 +104,7 @@ pub fn create_goal_save(
     }
 
     add_goal_to_user(env, &user, goal_id);
+    users::record_plan_opened(env, &user)?;
     increment_next_goal_id(env);
 
     // Award deposit points
@@ -125,14 +140,8 @@ pub fn deposit_to_goal_save(
         return Err(SavingsError::PlanCompleted);
     }
 
-    // Calculate protocol fee
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PlatformFee)
-        .unwrap_or(0);
-
-    let fee_amount = calculate_fee(amount, fee_bps)?;
+    // Calculate protocol fee (tiered by deposit size)
+    let fee_amount = calculate_tiered_fee(env, amount)?;
     let net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -150,9 +159,11 @@ pub fn deposit_to_goal_save(
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_deposit(env, net_amount)?;
+    history::record(env, &user, symbol_short!("goal_dep"), net_amount, goal_id);
 
     if !was_completed && goal_save.is_completed {
-        storage::award_goal_completion_bonus(env, user.clone())?;
+        storage::award_goal_completion_bonus(env, user.clone(), goal_save.target_amount)?;
     }
 
     // Extend TTL on deposit
@@ -166,7 +177,7 @@ pub fn deposit_to_goal_save(
             .instance()
             .get::<DataKey, Address>(&DataKey::FeeRecipient)
         {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
             let current_fee_balance = env
                 .storage()
                 .persistent()
@@ -176,6 +187,7 @@ pub fn deposit_to_goal_save(
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
+            stats::record_fee(env, fee_amount)?;
             env.events().publish(
                 (symbol_short!("gdep_fee"), fee_recipient, goal_id),
                 fee_amount,
@@ -193,7 +205,7 @@ pub fn withdraw_completed_goal_save(
     env: &Env,
     user: Address,
     goal_id: u64,
-) -> Result<i128, SavingsError> {
+) -> Result<WithdrawalReceipt, SavingsError> {
     ensure_not_paused(env)?;
     user.require_auth();
 
@@ -215,16 +227,32 @@ pub fn withdraw_completed_goal_save(
         return Err(SavingsError::PlanCompleted);
     }
 
-    // Calculate protocol fee on withdrawal
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PlatformFee)
-        .unwrap_or(0);
+    // Calculate protocol fee on withdrawal (waived in emergency mode)
+    let fee_bps: u32 = if config::is_emergency_mode(env) {
+        0
+    } else {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformFee)
+            .unwrap_or(0)
+    };
 
-    let fee_amount = calculate_fee(goal_save.current_amount, fee_bps)?;
-    let net_amount = goal_save
+    // Accrue interest on the saved amount since the goal started
+    let interest = rates::calculate_plan_interest(
+        goal_save.current_amount,
+        goal_save.interest_rate,
+        env.ledger()
+            .timestamp()
+            .saturating_sub(goal_save.start_time),
+    );
+    let gross_amount = goal_save
         .current_amount
+        .checked_add(interest)
+        .ok_or(SavingsError::Overflow)?;
+    config::consume_daily_withdrawal(env, &user, gross_amount)?;
+
+    let fee_amount = calculate_capped_fee(env, gross_amount, fee_bps)?;
+    let net_amount = gross_amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
 
@@ -233,6 +261,9 @@ pub fn withdraw_completed_goal_save(
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    users::record_plan_closed(env, &user);
+    stats::record_withdrawal(env, goal_save.current_amount);
+    history::record(env, &user, symbol_short!("goal_wd"), net_amount, goal_id);
 
     let user_key = DataKey::User(user.clone());
     if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
@@ -254,7 +285,7 @@ pub fn withdraw_completed_goal_save(
             .instance()
             .get::<DataKey, Address>(&DataKey::FeeRecipient)
         {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
             let current_fee_balance = env
                 .storage()
                 .persistent()
@@ -264,6 +295,7 @@ pub fn withdraw_completed_goal_save(
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
+            stats::record_fee(env, fee_amount)?;
             env.events().publish(
                 (symbol_short!("gwth_fee"), fee_recipient, goal_id),
                 fee_amount,
@@ -271,10 +303,34 @@ pub fn withdraw_completed_goal_save(
         }
     }
 
-    Ok(net_amount)
+    Ok(WithdrawalReceipt {
+        gross: gross_amount,
+        fee: fee_amount,
+        net: net_amount,
+        plan_id: goal_id,
+        plan_kind: symbol_short!("goal"),
+    })
 }
 
-pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, SavingsError> {
+/// Withdraws part of the amount saved above a completed goal's target.
+///
+/// The goal stays completed and keeps `target_amount` reserved; only the
+/// surplus (`current_amount - target_amount`) can be pulled out. The protocol
+/// fee is charged on the withdrawn amount.
+///
+/// # Errors
+/// * `UserNotFound` - If the user does not exist
+/// * `PlanNotFound` - If the goal does not exist
+/// * `Unauthorized` - If `user` does not own the goal
+/// * `TooEarly` - If the goal has not been completed
+/// * `PlanCompleted` - If the goal has already been withdrawn
+/// * `InvalidAmount` - If `amount` is not positive or exceeds the surplus
+pub fn withdraw_goal_surplus(
+    env: &Env,
+    user: Address,
+    goal_id: u64,
+    amount: i128,
+) -> Result<WithdrawalReceipt, SavingsError> {
     ensure_not_paused(env)?;
     user.require_auth();
 
@@ -288,44 +344,42 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
         return Err(SavingsError::Unauthorized);
     }
 
-    if goal_save.is_completed {
-        return Err(SavingsError::PlanCompleted);
+    if !goal_save.is_completed {
+        return Err(SavingsError::TooEarly);
     }
 
     if goal_save.is_withdrawn {
         return Err(SavingsError::PlanCompleted);
     }
 
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::EarlyBreakFeeBps)
-        .unwrap_or(0);
-
-    if fee_bps > 10_000 {
+    let surplus = goal_save
+        .current_amount
+        .checked_sub(goal_save.target_amount)
+        .ok_or(SavingsError::Underflow)?;
+    if amount <= 0 || amount > surplus {
         return Err(SavingsError::InvalidAmount);
     }
+    config::consume_daily_withdrawal(env, &user, amount)?;
 
-    let fee_amount = if fee_bps == 0 {
+    let fee_bps: u32 = if config::is_emergency_mode(env) {
         0
     } else {
-        goal_save
-            .current_amount
-            .checked_mul(fee_bps as i128)
-            .ok_or(SavingsError::Overflow)?
-            / 10_000
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformFee)
+            .unwrap_or(0)
     };
-
-    let net_amount = goal_save
-        .current_amount
+    let fee_amount = calculate_capped_fee(env, amount, fee_bps)?;
+    let net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
 
-    goal_save.is_withdrawn = true;
-
+    goal_save.current_amount -= amount;
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_withdrawal(env, amount);
+    history::record(env, &user, symbol_short!("goal_wd"), net_amount, goal_id);
 
     let user_key = DataKey::User(user.clone());
     if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
@@ -336,13 +390,16 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
         env.storage().persistent().set(&user_key, &user_data);
     }
 
+    ttl::extend_goal_ttl(env, goal_id);
+    ttl::extend_user_ttl(env, &user);
+
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
             .storage()
             .instance()
             .get::<DataKey, Address>(&DataKey::FeeRecipient)
         {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let fee_key = DataKey::FeeAccrued(fee_recipient.clone());
             let current_fee_balance = env
                 .storage()
                 .persistent()
@@ -352,17 +409,89 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
-
-            // Extend TTL on fee storage
-            ttl::extend_config_ttl(env, &fee_key);
-
+            stats::record_fee(env, fee_amount)?;
             env.events().publish(
-                (symbol_short!("brk_fee"), fee_recipient, goal_id),
+                (symbol_short!("gwth_fee"), fee_recipient, goal_id),
                 fee_amount,
             );
         }
     }
 
+    env.events().publish(
+        (symbol_short!("goal_surp"), user, goal_id),
+        (amount, fee_amount),
+    );
+
+    Ok(WithdrawalReceipt {
+        gross: amount,
+        fee: fee_amount,
+        net: net_amount,
+        plan_id: goal_id,
+        plan_kind: symbol_short!("goal"),
+    })
+}
+
+pub fn break_goal_save(
+    env: &Env,
+    user: Address,
+    goal_id: u64,
+) -> Result<WithdrawalReceipt, SavingsError> {
+    ensure_not_paused(env)?;
+    user.require_auth();
+
+    if !users::user_exists(env, &user) {
+        return Err(SavingsError::UserNotFound);
+    }
+
+    let mut goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if goal_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    if goal_save.is_completed {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    if goal_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    // Early-break fee is waived in emergency mode
+    let fee_bps = config::get_break_fee_bps(env, ConfigKey::GoalBreakFeeBps);
+
+    if fee_bps > 10_000 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    config::consume_daily_withdrawal(env, &user, goal_save.current_amount)?;
+
+    let fee_amount = calculate_capped_fee(env, goal_save.current_amount, fee_bps)?;
+
+    let net_amount = goal_save
+        .current_amount
+        .checked_sub(fee_amount)
+        .ok_or(SavingsError::Underflow)?;
+
+    goal_save.is_withdrawn = true;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(goal_id), &goal_save);
+    users::record_plan_closed(env, &user);
+    stats::record_withdrawal(env, goal_save.current_amount);
+    history::record(env, &user, symbol_short!("goal_wd"), net_amount, goal_id);
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(net_amount)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+
+    config::accrue_break_fee(env, goal_id, fee_amount)?;
+
     env.events().publish(
         (symbol_short!("goal_brk"), user.clone(), goal_id),
         net_amount,
@@ -374,7 +503,341 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
     ttl::extend_goal_ttl(env, goal_id);
     ttl::extend_user_ttl(env, &user);
 
-    Ok(net_amount)
+    Ok(WithdrawalReceipt {
+        gross: goal_save.current_amount,
+        fee: fee_amount,
+        net: net_amount,
+        plan_id: goal_id,
+        plan_kind: symbol_short!("goal"),
+    })
+}
+
+/// Shortest allowed gap between a goal's start and its deadline (30 days), so
+/// a deadline can't be used to sweep a goal early without the break fee
+const MIN_GOAL_DEADLINE_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Sets the deadline after which an unfinished goal may be swept into Flexi.
+///
+/// The deadline can only be set once.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The goal owner
+/// * `goal_id` - The goal to set the deadline on
+/// * `deadline` - Unix timestamp; must be in the future and at least
+///   `MIN_GOAL_DEADLINE_SECS` after the goal started
+///
+/// # Errors
+/// * `PlanNotFound` - If the goal does not exist
+/// * `Unauthorized` - If `user` does not own the goal
+/// * `PlanCompleted` - If the goal has already been withdrawn
+/// * `PlanLocked` - If a deadline has already been set
+/// * `InvalidTimestamp` - If `deadline` is not in the future or falls short
+///   of the minimum term
+pub fn set_goal_deadline(
+    env: &Env,
+    user: Address,
+    goal_id: u64,
+    deadline: u64,
+) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    let goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+    if goal_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if goal_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    let key = DataKey::GoalDeadline(goal_id);
+    if env.storage().persistent().has(&key) {
+        return Err(SavingsError::PlanLocked);
+    }
+    let earliest = goal_save.start_time.saturating_add(MIN_GOAL_DEADLINE_SECS);
+    if deadline <= env.ledger().timestamp() || deadline < earliest {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+
+    env.storage().persistent().set(&key, &deadline);
+    ttl::extend_config_ttl(env, &key);
+
+    env.events()
+        .publish((symbol_short!("goal_ddl"), user, goal_id), deadline);
+
+    Ok(())
+}
+
+/// Returns the goal's deadline, if one has been set.
+pub fn get_goal_deadline(env: &Env, goal_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GoalDeadline(goal_id))
+}
+
+/// Sets a recurring contribution that moves `amount` from the owner's Flexi
+/// balance into the goal every `interval` seconds. An `interval` of 0 clears
+/// the schedule.
+///
+/// The first contribution is due one interval from now.
+///
+/// # Errors
+/// * `PlanNotFound` - If the goal does not exist
+/// * `Unauthorized` - If `user` does not own the goal
+/// * `PlanCompleted` - If the goal is already completed or withdrawn
+/// * `InvalidAmount` - If a schedule is set with `amount <= 0`
+pub fn set_goal_contribution_schedule(
+    env: &Env,
+    user: Address,
+    goal_id: u64,
+    interval: u64,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    let mut goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+    if goal_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if goal_save.is_completed || goal_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    if interval > 0 && amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    goal_save.contribution_interval = interval;
+    goal_save.contribution_amount = if interval > 0 { amount } else { 0 };
+    goal_save.next_contribution_time = if interval > 0 {
+        env.ledger().timestamp().saturating_add(interval)
+    } else {
+        0
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(goal_id), &goal_save);
+    ttl::extend_goal_ttl(env, goal_id);
+
+    env.events().publish(
+        (symbol_short!("goal_sch"), user, goal_id),
+        (interval, goal_save.contribution_amount),
+    );
+
+    Ok(())
+}
+
+/// Makes a goal's scheduled contribution from the owner's Flexi balance if
+/// it is due. Meant to be called by a keeper.
+///
+/// Safe to retry: the next due time advances by one interval per
+/// contribution, so a second call within the same interval moves nothing.
+/// The transfer stays inside the contract, so no deposit fee is charged.
+///
+/// # Returns
+/// * `Ok(true)` - If the contribution was made
+/// * `Ok(false)` - If it is not yet due, the goal is already completed, or
+///   the Flexi balance is insufficient
+///
+/// # Errors
+/// * `PlanNotFound` - If the goal does not exist
+/// * `InvalidPlanConfig` - If the goal has no contribution schedule
+pub fn execute_goal_contribution(env: &Env, goal_id: u64) -> Result<bool, SavingsError> {
+    ensure_not_paused(env)?;
+
+    let mut goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+    if goal_save.contribution_interval == 0 {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+    if goal_save.is_completed || goal_save.is_withdrawn {
+        return Ok(false);
+    }
+    if env.ledger().timestamp() < goal_save.next_contribution_time {
+        return Ok(false);
+    }
+
+    let owner = goal_save.owner.clone();
+    let amount = goal_save.contribution_amount;
+    let flexi_key = DataKey::FlexiBalance(owner.clone());
+    let flexi_balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    if flexi_balance < amount {
+        return Ok(false);
+    }
+    env.storage()
+        .persistent()
+        .set(&flexi_key, &(flexi_balance - amount));
+
+    // Goal balances are not part of `total_balance` until withdrawn
+    let user_key = DataKey::User(owner.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_sub(amount)
+            .ok_or(SavingsError::Underflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+
+    goal_save.current_amount = goal_save
+        .current_amount
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    goal_save.next_contribution_time = goal_save
+        .next_contribution_time
+        .saturating_add(goal_save.contribution_interval);
+    let completed_now = goal_save.current_amount >= goal_save.target_amount;
+    goal_save.is_completed = completed_now;
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(goal_id), &goal_save);
+    history::record(env, &owner, symbol_short!("goal_dep"), amount, goal_id);
+
+    if completed_now {
+        storage::award_goal_completion_bonus(env, owner.clone(), goal_save.target_amount)?;
+    }
+
+    ttl::extend_goal_ttl(env, goal_id);
+    ttl::extend_user_ttl(env, &owner);
+
+    env.events()
+        .publish((symbol_short!("goal_auto"), owner, goal_id), amount);
+
+    Ok(true)
+}
+
+/// Moves `amount` between two of the user's open goals.
+///
+/// The funds stay in the contract, so no fee is charged. If the destination
+/// reaches its target it is completed and the completion bonus is awarded.
+///
+/// # Errors
+/// * `InvalidAmount` - If `amount` <= 0
+/// * `InvalidPlanConfig` - If `from_goal` and `to_goal` are the same goal
+/// * `PlanNotFound` - If either goal does not exist
+/// * `Unauthorized` - If `user` does not own both goals
+/// * `PlanCompleted` - If either goal is already completed or withdrawn
+/// * `InsufficientBalance` - If `amount` exceeds the source goal's balance
+pub fn transfer_between_goals(
+    env: &Env,
+    user: Address,
+    from_goal: u64,
+    to_goal: u64,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    ensure_not_paused(env)?;
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if from_goal == to_goal {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    let mut source = get_goal_save(env, from_goal).ok_or(SavingsError::PlanNotFound)?;
+    let mut dest = get_goal_save(env, to_goal).ok_or(SavingsError::PlanNotFound)?;
+    if source.owner != user || dest.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if source.is_completed || source.is_withdrawn || dest.is_completed || dest.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    if amount > source.current_amount {
+        return Err(SavingsError::InsufficientBalance);
+    }
+
+    source.current_amount -= amount;
+    dest.current_amount = dest
+        .current_amount
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    let completed_now = dest.current_amount >= dest.target_amount;
+    dest.is_completed = completed_now;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(from_goal), &source);
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(to_goal), &dest);
+
+    if completed_now {
+        storage::award_goal_completion_bonus(env, user.clone(), dest.target_amount)?;
+    }
+
+    ttl::extend_goal_ttl(env, from_goal);
+    ttl::extend_goal_ttl(env, to_goal);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events().publish(
+        (symbol_short!("goal_xfer"), user, from_goal),
+        (to_goal, amount),
+    );
+
+    Ok(())
+}
+
+/// Moves an overdue, unwithdrawn and unmet goal's balance into the owner's
+/// Flexi balance. Completed goals are paid out through `withdraw_completed_goal_save`.
+///
+/// Unlike `break_goal_save`, this is only allowed once the goal's deadline has
+/// passed and no early-break fee is charged.
+///
+/// # Returns
+/// The amount credited to the Flexi balance
+///
+/// # Errors
+/// * `PlanNotFound` - If the goal does not exist
+/// * `Unauthorized` - If `user` does not own the goal
+/// * `PlanCompleted` - If the goal has already been withdrawn or has reached its target
+/// * `TooEarly` - If no deadline is set or it has not passed yet
+pub fn sweep_overdue_goal(env: &Env, user: Address, goal_id: u64) -> Result<i128, SavingsError> {
+    ensure_not_paused(env)?;
+    user.require_auth();
+
+    let mut goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+    if goal_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if goal_save.is_withdrawn || goal_save.is_completed {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    let deadline = get_goal_deadline(env, goal_id).ok_or(SavingsError::TooEarly)?;
+    if env.ledger().timestamp() < deadline {
+        return Err(SavingsError::TooEarly);
+    }
+
+    let amount = goal_save.current_amount;
+    goal_save.is_withdrawn = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(goal_id), &goal_save);
+    users::record_plan_closed(env, &user);
+
+    crate::flexi::credit_flexi_balance(env, &user, amount)?;
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    stats::record_withdrawal(env, amount);
+    stats::record_deposit(env, amount)?;
+    history::record(env, &user, symbol_short!("goal_wd"), amount, goal_id);
+
+    remove_goal_from_user(env, &user, goal_id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::GoalDeadline(goal_id));
+
+    ttl::extend_goal_ttl(env, goal_id);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events()
+        .publish((symbol_short!("goal_swp"), user, goal_id), amount);
+
+    Ok(amount)
 }
 
 pub fn get_goal_save(env: &Env, goal_id: u64) -> Option<GoalSave> {
@@ -386,6 +849,31 @@ pub fn get_goal_save(env: &Env, goal_id: u64) -> Option<GoalSave> {
     goal_save
 }
 
+/// Reports whether `withdraw_completed_goal_save` would succeed for `user`,
+/// without changing any state. An incomplete goal is reported as not matured.
+///
+/// # Returns
+/// `(true, WITHDRAW_OK)` or `(false, reason)` using the `lock::WITHDRAW_*` codes
+pub fn can_withdraw_goal(env: &Env, user: &Address, goal_id: u64) -> (bool, u32) {
+    let reason = if config::is_withdraw_paused(env) {
+        WITHDRAW_PAUSED
+    } else {
+        match get_goal_save(env, goal_id) {
+            Some(goal_save) if goal_save.owner == *user => {
+                if goal_save.is_withdrawn {
+                    WITHDRAW_ALREADY_WITHDRAWN
+                } else if !goal_save.is_completed {
+                    WITHDRAW_NOT_MATURED
+                } else {
+                    WITHDRAW_OK
+                }
+            }
+            _ => WITHDRAW_NOT_OWNER,
+        }
+    };
+    (reason == WITHDRAW_OK, reason)
+}
+
 pub fn get_user_goal_saves(env: &Env, user: &Address) -> Vec<u64> {
     let list_key = DataKey::UserGoalSaves(user.clone());
     let goals = env
@@ -451,9 +939,10 @@ fn remove_goal_from_user(env: &Env, user: &Address, goal_id: u64) {
 #[cfg(test)]
 mod tests {
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient};
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
     use soroban_sdk::{
-        testutils::{Address as _, Events},
+        symbol_short,
+        testutils::{Address as _, Events, Ledger},
         Address, BytesN, Env, IntoVal, Symbol,
     };
 
@@ -499,6 +988,9 @@ mod tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
         assert!(client.try_initialize_rewards_config(&config).is_ok());
     }
@@ -612,6 +1104,43 @@ mod tests {
         assert!(goal_save.is_completed);
     }
 
+    #[test]
+    fn test_completed_goal_withdrawal_pays_accrued_interest() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_goal_rate(&admin, &800).is_ok()); // 8%
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &10_000);
+        assert_eq!(client.get_goal_save_detail(&goal_id).interest_rate, 800);
+
+        // One 365-day year at 8% on 10_000
+        env.ledger()
+            .with_mut(|li| li.timestamp += 365 * 24 * 60 * 60);
+
+        let receipt = client.withdraw_completed_goal_save(&user, &goal_id);
+        assert_eq!(receipt.gross, 10_800);
+        assert_eq!(receipt.net, 10_800);
+        assert!(receipt.net > 10_000);
+    }
+
+    #[test]
+    fn test_goal_rate_defaults_when_unconfigured() {
+        let (env, client) = setup_test_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "bike"), &5_000, &100);
+        assert_eq!(
+            client.get_goal_save_detail(&goal_id).interest_rate,
+            crate::rates::DEFAULT_PLAN_RATE
+        );
+    }
+
     #[test]
     fn test_withdraw_completed_goal_save_success() {
         let (env, client) = setup_test_env();
@@ -629,7 +1158,7 @@ mod tests {
         let goal_save = client.get_goal_save_detail(&goal_id);
         assert!(goal_save.is_completed);
 
-        let amount = client.withdraw_completed_goal_save(&user, &goal_id);
+        let amount = client.withdraw_completed_goal_save(&user, &goal_id).net;
         assert_eq!(amount, 1000);
 
         let goal_save_after = client.get_goal_save_detail(&goal_id);
@@ -704,7 +1233,7 @@ mod tests {
         let initial = 2000i128;
 
         let goal_id = client.create_goal_save(&user, &goal_name, &target, &initial);
-        let net_amount = client.break_goal_save(&user, &goal_id);
+        let net_amount = client.break_goal_save(&user, &goal_id).net;
         assert_eq!(net_amount, initial);
 
         let goal_save = client.get_goal_save_detail(&goal_id);
@@ -747,12 +1276,162 @@ mod tests {
         let initial = 2_000i128;
 
         let goal_id = client.create_goal_save(&user, &goal_name, &target, &initial);
-        let net_amount = client.break_goal_save(&user, &goal_id);
+        let net_amount = client.break_goal_save(&user, &goal_id).net;
 
         assert_eq!(net_amount, 1_900);
         assert_eq!(client.get_protocol_fee_balance(&treasury), 100);
     }
 
+    #[test]
+    fn test_break_goal_save_waives_fee_in_emergency_mode() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&500).is_ok()); // 5%
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "exit"), &10_000, &2_000);
+        client.enable_emergency_mode(&admin);
+
+        let receipt = client.break_goal_save(&user, &goal_id);
+        assert_eq!(receipt.fee, 0);
+        assert_eq!(receipt.net, 2_000);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 0);
+
+        assert_eq!(
+            client.try_deposit_flexi(&user, &100),
+            Err(Ok(SavingsError::ContractPaused))
+        );
+    }
+
+    #[test]
+    fn test_sweep_overdue_goal_moves_funds_to_flexi_without_fee() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&500).is_ok()); // 5%
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "trip"), &10_000, &2_000);
+        let deadline = env.ledger().timestamp() + 30 * 24 * 60 * 60;
+        client.set_goal_deadline(&user, &goal_id, &deadline);
+        assert_eq!(client.get_goal_deadline(&goal_id), Some(deadline));
+
+        assert_eq!(
+            client.try_sweep_overdue_goal(&user, &goal_id),
+            Err(Ok(SavingsError::TooEarly))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp = deadline);
+        assert_eq!(client.sweep_overdue_goal(&user, &goal_id), 2_000);
+        assert_eq!(client.get_flexi_balance(&user), 2_000);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 0);
+        assert!(client.get_goal_save_detail(&goal_id).is_withdrawn);
+
+        assert_eq!(
+            client.try_sweep_overdue_goal(&user, &goal_id),
+            Err(Ok(SavingsError::PlanCompleted))
+        );
+    }
+
+    #[test]
+    fn test_sweep_rejects_completed_goal() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &5_000, &1_000);
+        let deadline = env.ledger().timestamp() + 30 * 24 * 60 * 60;
+        client.set_goal_deadline(&user, &goal_id, &deadline);
+        client.deposit_to_goal_save(&user, &goal_id, &4_000);
+        assert!(client.get_goal_save_detail(&goal_id).is_completed);
+
+        env.ledger().with_mut(|li| li.timestamp = deadline);
+        assert_eq!(
+            client.try_sweep_overdue_goal(&user, &goal_id),
+            Err(Ok(SavingsError::PlanCompleted))
+        );
+        assert!(!client.get_goal_save_detail(&goal_id).is_withdrawn);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_sweep_requires_deadline_and_owner() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.initialize_user(&other);
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "tv"), &10_000, &1_000);
+        assert_eq!(
+            client.try_sweep_overdue_goal(&user, &goal_id),
+            Err(Ok(SavingsError::TooEarly))
+        );
+        assert_eq!(
+            client.try_set_goal_deadline(&user, &goal_id, &env.ledger().timestamp()),
+            Err(Ok(SavingsError::InvalidTimestamp))
+        );
+        assert_eq!(
+            client.try_set_goal_deadline(&other, &goal_id, &(env.ledger().timestamp() + 10)),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_scheduled_goal_contributions_pull_from_flexi_when_due() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &1_000);
+
+        let week = 7 * 24 * 60 * 60;
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "bike"), &10_000, &100);
+        client.set_goal_contribution_schedule(&user, &goal_id, &week, &250);
+
+        // Nothing is due before the first interval has passed
+        assert!(!client.execute_goal_contribution(&goal_id));
+
+        env.ledger().with_mut(|li| li.timestamp += 2 * week);
+        assert!(client.execute_goal_contribution(&goal_id));
+        assert!(client.execute_goal_contribution(&goal_id));
+        // Both due contributions are made; a retry in the same interval is a no-op
+        assert!(!client.execute_goal_contribution(&goal_id));
+
+        assert_eq!(client.get_goal_save_detail(&goal_id).current_amount, 600);
+        assert_eq!(client.get_flexi_balance(&user), 500);
+    }
+
+    #[test]
+    fn test_scheduled_goal_contribution_skips_when_flexi_is_short() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &100);
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "bike"), &10_000, &100);
+        assert_eq!(
+            client.try_execute_goal_contribution(&goal_id),
+            Err(Ok(SavingsError::InvalidPlanConfig))
+        );
+        client.set_goal_contribution_schedule(&user, &goal_id, &3_600, &250);
+
+        env.ledger().with_mut(|li| li.timestamp += 3_600);
+        assert!(!client.execute_goal_contribution(&goal_id));
+        assert_eq!(client.get_goal_save_detail(&goal_id).current_amount, 100);
+        assert_eq!(client.get_flexi_balance(&user), 100);
+    }
+
     #[test]
     fn test_break_goal_save_fee_rounds_down() {
         let (env, client, _admin) = setup_admin_env();
@@ -769,13 +1448,42 @@ mod tests {
         let initial = 3_333i128;
 
         let goal_id = client.create_goal_save(&user, &goal_name, &target, &initial);
-        let net_amount = client.break_goal_save(&user, &goal_id);
+        let net_amount = client.break_goal_save(&user, &goal_id).net;
 
         // fee = floor(3333 * 125 / 10000) = 41
         assert_eq!(net_amount, 3_292);
         assert_eq!(client.get_protocol_fee_balance(&treasury), 41);
     }
 
+    #[test]
+    fn test_estimate_fee_matches_goal_deposit_and_break() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_protocol_fee_bps(&300).is_ok()); // 3%
+        assert!(client.try_set_early_break_fee_bps(&125).is_ok()); // 1.25%
+
+        let deposit_estimate = client.estimate_fee(&3_333, &crate::config::FEE_KIND_PROTOCOL);
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "est"), &10_000, &3_333);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), deposit_estimate);
+        assert_eq!(deposit_estimate, 99);
+
+        let balance = client.get_goal_save_detail(&goal_id).current_amount;
+        let break_estimate = client.estimate_fee(&balance, &crate::config::FEE_KIND_EARLY_BREAK);
+        let receipt = client.break_goal_save(&user, &goal_id);
+        assert_eq!(receipt.fee, break_estimate);
+        assert_eq!(break_estimate, 40);
+
+        assert_eq!(
+            client.try_estimate_fee(&1_000, &7),
+            Err(Ok(SavingsError::InvalidPlanConfig))
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #1)")]
     fn test_break_unauthorized_fails() {
@@ -900,13 +1608,53 @@ mod tests {
         assert!(goal_save.is_completed);
         assert_eq!(client.get_protocol_fee_balance(&treasury), 125);
 
-        let amount = client.withdraw_completed_goal_save(&user, &goal_id);
+        let amount = client.withdraw_completed_goal_save(&user, &goal_id).net;
         // Withdrawal: 4,875 - 121 = 4,754 (fee rounded down)
         assert_eq!(amount, 4_754);
         // Total fees: 125 + 121 = 246
         assert_eq!(client.get_protocol_fee_balance(&treasury), 246);
     }
 
+    #[test]
+    fn test_withdraw_protocol_fees_after_goal_breaks() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&1_000).is_ok()); // 10%
+
+        let first = client.create_goal_save(&user, &Symbol::new(&env, "one"), &10_000, &2_000);
+        let second = client.create_goal_save(&user, &Symbol::new(&env, "two"), &10_000, &3_000);
+        client.break_goal_save(&user, &first);
+        client.break_goal_save(&user, &second);
+        // Break fees: 200 + 300
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 500);
+
+        client.withdraw_protocol_fees(&treasury, &treasury, &150);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 350);
+
+        let result = client.try_withdraw_protocol_fees(&treasury, &treasury, &351);
+        assert_eq!(result, Err(Ok(SavingsError::InsufficientBalance)));
+
+        // Admin can sweep the remainder on the recipient's behalf
+        assert_eq!(client.withdraw_all_protocol_fees(&admin, &treasury), 350);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 0);
+    }
+
+    #[test]
+    fn test_withdraw_protocol_fees_unauthorized_caller() {
+        let (env, client, _admin) = setup_admin_env();
+        let treasury = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        env.mock_all_auths();
+        let result = client.try_withdraw_protocol_fees(&stranger, &treasury, &1);
+        assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+    }
+
     #[test]
     fn test_goal_zero_protocol_fee() {
         let (env, client) = setup_test_env();
@@ -924,10 +1672,56 @@ mod tests {
         assert_eq!(goal_save.current_amount, 5_000);
         assert!(goal_save.is_completed);
 
-        let amount = client.withdraw_completed_goal_save(&user, &goal_id);
+        let amount = client.withdraw_completed_goal_save(&user, &goal_id).net;
         assert_eq!(amount, 5_000);
     }
 
+    #[test]
+    fn test_goal_withdraw_receipt_breakdown() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+
+        // Create at 0% so the goal holds exactly 4,000, then charge 2.5% on exit
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "bike"), &4_000, &4_000);
+        assert!(client.try_set_protocol_fee_bps(&250).is_ok());
+
+        let receipt = client.withdraw_completed_goal_save(&user, &goal_id);
+        assert_eq!(receipt.gross, 4_000);
+        assert_eq!(receipt.fee, 100);
+        assert_eq!(receipt.net, 3_900);
+        assert_eq!(receipt.gross, receipt.fee + receipt.net);
+        assert_eq!(receipt.plan_id, goal_id);
+        assert_eq!(receipt.plan_kind, symbol_short!("goal"));
+        assert_eq!(client.get_protocol_fee_balance(&treasury), receipt.fee);
+    }
+
+    #[test]
+    fn test_break_goal_receipt_gross_equals_fee_plus_net() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&125).is_ok()); // 1.25%
+
+        for initial in [1i128, 79, 3_333, 9_999] {
+            let goal_id =
+                client.create_goal_save(&user, &Symbol::new(&env, "brk"), &10_000, &initial);
+            let receipt = client.break_goal_save(&user, &goal_id);
+            assert_eq!(receipt.gross, initial);
+            assert_eq!(receipt.fee, initial * 125 / 10_000);
+            assert_eq!(receipt.gross, receipt.fee + receipt.net);
+            assert_eq!(receipt.plan_id, goal_id);
+        }
+    }
+
     #[test]
     fn test_goal_fee_calculation_correctness() {
         let (env, client, _admin) = setup_admin_env();
@@ -1036,6 +1830,48 @@ mod tests {
         assert_eq!(rewards.total_points, 50250);
     }
 
+    #[test]
+    fn test_goal_completion_bonus_scales_with_target_when_bps_set() {
+        let (env, client) = setup_test_env();
+        setup_rewards(&client, &env);
+        let admin = client.get_config().admin;
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.set_goal_completion_bonus_bps(&admin, &500);
+        assert_eq!(client.get_goal_completion_bonus_bps(), 500);
+
+        let goal_name = Symbol::new(&env, "scaled");
+        client.create_goal_save(&user, &goal_name, &10_000, &10_000);
+
+        let rewards = client.get_user_rewards(&user);
+        // Base points: 10000 * 10 = 100000
+        // Scaled bonus: 10000 * 5% = 500 (replaces the flat 250)
+        assert_eq!(rewards.total_points, 100_500);
+    }
+
+    #[test]
+    fn test_goal_completion_bonus_flat_when_bps_disabled() {
+        let (env, client) = setup_test_env();
+        setup_rewards(&client, &env);
+        let admin = client.get_config().admin;
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.set_goal_completion_bonus_bps(&admin, &500);
+        client.set_goal_completion_bonus_bps(&admin, &0);
+
+        let goal_name = Symbol::new(&env, "flat");
+        client.create_goal_save(&user, &goal_name, &10_000, &10_000);
+
+        let rewards = client.get_user_rewards(&user);
+        // Base points: 10000 * 10 = 100000
+        // Flat bonus: 250
+        assert_eq!(rewards.total_points, 100_250);
+    }
+
     #[test]
     fn test_goal_completion_bonus_not_awarded_when_rewards_disabled() {
         let (env, client) = setup_test_env();
@@ -1069,4 +1905,217 @@ mod tests {
         // Base points: 2000 * 10 = 20000
         assert_eq!(rewards.total_points, 20000);
     }
+
+    #[test]
+    fn test_can_withdraw_goal_reports_each_reason() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("car"), &5_000, &1_000);
+        assert_eq!(client.can_withdraw_goal(&user, &goal_id), (false, 3));
+        assert_eq!(client.can_withdraw_goal(&other, &goal_id), (false, 1));
+
+        client.deposit_to_goal_save(&user, &goal_id, &4_000);
+        assert_eq!(client.can_withdraw_goal(&user, &goal_id), (true, 0));
+
+        client.pause(&admin);
+        assert_eq!(client.can_withdraw_goal(&user, &goal_id), (false, 4));
+        client.unpause(&admin);
+
+        client.withdraw_completed_goal_save(&user, &goal_id);
+        assert_eq!(client.can_withdraw_goal(&user, &goal_id), (false, 2));
+    }
+
+    #[test]
+    fn test_goal_deadline_enforces_minimum_term_and_is_set_once() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("trip"), &5_000, &1_000);
+        let now = env.ledger().timestamp();
+        let min_term = 30 * 24 * 60 * 60;
+
+        assert_eq!(
+            client.try_set_goal_deadline(&user, &goal_id, &(now + 1)),
+            Err(Ok(SavingsError::InvalidTimestamp))
+        );
+        client.set_goal_deadline(&user, &goal_id, &(now + min_term));
+        assert_eq!(
+            client.try_set_goal_deadline(&user, &goal_id, &(now + 2 * min_term)),
+            Err(Ok(SavingsError::PlanLocked))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp = now + min_term);
+        assert_eq!(client.sweep_overdue_goal(&user, &goal_id), 1_000);
+    }
+
+    #[test]
+    fn test_withdraw_goal_surplus_keeps_target_reserved() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("trip"), &5_000, &1_000);
+        client.deposit_to_goal_save(&user, &goal_id, &6_000);
+
+        let receipt = client.withdraw_goal_surplus(&user, &goal_id, &1_500);
+        assert_eq!(receipt.gross, 1_500);
+
+        let goal = client.get_goal_save_detail(&goal_id);
+        assert_eq!(goal.current_amount, 5_500);
+        assert!(goal.current_amount >= goal.target_amount);
+        assert!(goal.is_completed && !goal.is_withdrawn);
+
+        assert_eq!(
+            client.try_withdraw_goal_surplus(&user, &goal_id, &501),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::InvalidAmount as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn test_transfer_between_goals_moves_funds_and_completes_destination() {
+        let (env, client) = setup_test_env();
+        setup_rewards(&client, &env);
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        let from_goal = client.create_goal_save(&user, &symbol_short!("car"), &10_000, &3_000);
+        let to_goal = client.create_goal_save(&user, &symbol_short!("trip"), &2_000, &1_500);
+        let points_before = client.get_user_rewards(&user).total_points;
+
+        client.transfer_between_goals(&user, &from_goal, &to_goal, &200);
+        assert_eq!(
+            client.get_goal_save_detail(&from_goal).current_amount,
+            2_800
+        );
+        assert_eq!(client.get_goal_save_detail(&to_goal).current_amount, 1_700);
+        assert_eq!(client.get_user_rewards(&user).total_points, points_before);
+
+        client.transfer_between_goals(&user, &from_goal, &to_goal, &300);
+        let dest = client.get_goal_save_detail(&to_goal);
+        assert_eq!(dest.current_amount, 2_000);
+        assert!(dest.is_completed);
+        // Completion bonus only, no deposit points for the internal move
+        assert_eq!(
+            client.get_user_rewards(&user).total_points,
+            points_before + 250
+        );
+    }
+
+    #[test]
+    fn test_transfer_between_goals_rejects_over_transfer() {
+        let (env, client) = setup_test_env();
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.initialize_user(&other);
+
+        let from_goal = client.create_goal_save(&user, &symbol_short!("car"), &10_000, &1_000);
+        let to_goal = client.create_goal_save(&user, &symbol_short!("trip"), &10_000, &500);
+        let others_goal = client.create_goal_save(&other, &symbol_short!("boat"), &10_000, &500);
+
+        assert_eq!(
+            client.try_transfer_between_goals(&user, &from_goal, &to_goal, &1_001),
+            Err(Ok(SavingsError::InsufficientBalance))
+        );
+        assert_eq!(
+            client.try_transfer_between_goals(&user, &from_goal, &others_goal, &100),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_transfer_between_goals(&user, &from_goal, &from_goal, &100),
+            Err(Ok(SavingsError::InvalidPlanConfig))
+        );
+        assert_eq!(
+            client.get_goal_save_detail(&from_goal).current_amount,
+            1_000
+        );
+        assert_eq!(client.get_goal_save_detail(&to_goal).current_amount, 500);
+    }
+
+    #[test]
+    fn test_goal_and_lock_breaks_use_their_own_fee_bps() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&500).is_ok()); // 5% shared
+        client.set_goal_break_fee_bps(&admin, &300); // 3%
+        client.set_lock_break_fee_bps(&admin, &800); // 8%
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("car"), &10_000, &2_000);
+        let goal_receipt = client.break_goal_save(&user, &goal_id);
+        assert_eq!(goal_receipt.fee, 60);
+        assert_eq!(goal_receipt.net, 1_940);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400, &false);
+        let lock_receipt = client.break_lock_save(&user, &lock_id);
+        assert_eq!(lock_receipt.fee, 80);
+        assert_eq!(lock_receipt.net, 920);
+        assert_eq!(lock_receipt.plan_kind, symbol_short!("lock"));
+
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 140);
+        assert_eq!(
+            client.try_set_lock_break_fee_bps(&user, &0),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_goal_break_fee_bps(&admin, &10_001),
+            Err(Ok(SavingsError::InvalidFeeBps))
+        );
+    }
+
+    #[test]
+    fn test_break_fees_respect_max_fee_and_match_estimates() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        client.set_goal_break_fee_bps(&admin, &300); // 3%
+        client.set_lock_break_fee_bps(&admin, &800); // 8%
+        client.set_max_fee(&admin, &50);
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("car"), &10_000, &2_000);
+        let goal_estimate = client.estimate_fee(&2_000, &crate::config::FEE_KIND_EARLY_BREAK);
+        assert_eq!(goal_estimate, 50);
+        assert_eq!(client.break_goal_save(&user, &goal_id).fee, goal_estimate);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400, &false);
+        let lock_estimate = client.estimate_fee(&1_000, &crate::config::FEE_KIND_LOCK_BREAK);
+        assert_eq!(lock_estimate, 50);
+        assert_eq!(client.break_lock_save(&user, &lock_id).fee, lock_estimate);
+
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 100);
+    }
+
+    #[test]
+    fn test_break_lock_save_rejects_matured_lock() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+        env.ledger().with_mut(|li| li.timestamp += 100);
+
+        assert_eq!(
+            client.try_break_lock_save(&user, &lock_id),
+            Err(Ok(SavingsError::TooLate))
+        );
+    }
 }
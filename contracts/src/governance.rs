@@ -34,17 +34,89 @@ pub fn get_proposal_votes(env: &Env, proposal_id: u64) -> (u128, u128, u128) {
         (0, 0, 0)
     }
 }
+
+/// Voting is open and no votes have been cast yet
+pub const PROPOSAL_STATUS_ACTIVE: u32 = 0;
+/// More `for` than `against` votes; queueable once voting ends
+pub const PROPOSAL_STATUS_PASSING: u32 = 1;
+/// Voting is open and `for` votes do not exceed `against` votes
+pub const PROPOSAL_STATUS_FAILING: u32 = 2;
+/// Queued and waiting out the timelock
+pub const PROPOSAL_STATUS_QUEUED: u32 = 3;
+/// Executed (or canceled, which also sets `executed`)
+pub const PROPOSAL_STATUS_EXECUTED: u32 = 4;
+/// Voting ended without more `for` than `against` votes
+pub const PROPOSAL_STATUS_DEFEATED: u32 = 5;
+
+/// Returns the live status code of a regular or action proposal.
+///
+/// Passing mirrors the `queue_proposal` rule: strictly more `for` than
+/// `against` votes.
+///
+/// # Errors
+/// * `PlanNotFound` - If no proposal exists with this ID
+pub fn get_proposal_status(env: &Env, proposal_id: u64) -> Result<u32, SavingsError> {
+    let (end_time, for_votes, against_votes, abstain_votes, queued_time, executed) =
+        if let Some(p) = get_proposal(env, proposal_id) {
+            (
+                p.end_time,
+                p.for_votes,
+                p.against_votes,
+                p.abstain_votes,
+                p.queued_time,
+                p.executed,
+            )
+        } else if let Some(p) = get_action_proposal(env, proposal_id) {
+            (
+                p.end_time,
+                p.for_votes,
+                p.against_votes,
+                p.abstain_votes,
+                p.queued_time,
+                p.executed,
+            )
+        } else {
+            return Err(SavingsError::PlanNotFound);
+        };
+
+    if executed {
+        return Ok(PROPOSAL_STATUS_EXECUTED);
+    }
+    if queued_time > 0 {
+        return Ok(PROPOSAL_STATUS_QUEUED);
+    }
+
+    let passing = for_votes > against_votes;
+    if env.ledger().timestamp() > end_time {
+        return Ok(if passing {
+            PROPOSAL_STATUS_PASSING
+        } else {
+            PROPOSAL_STATUS_DEFEATED
+        });
+    }
+
+    if for_votes == 0 && against_votes == 0 && abstain_votes == 0 {
+        Ok(PROPOSAL_STATUS_ACTIVE)
+    } else if passing {
+        Ok(PROPOSAL_STATUS_PASSING)
+    } else {
+        Ok(PROPOSAL_STATUS_FAILING)
+    }
+}
 use crate::errors::SavingsError;
 use crate::governance_events::*;
 use crate::rewards::storage::get_user_rewards;
+use crate::rewards::storage_types::UserRewards;
 use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ActionProposal {
     pub id: u64,
     pub creator: Address,
+    pub title: String,
+    pub category: String,
     pub description: String,
     pub start_time: u64,
     pub end_time: u64,
@@ -61,6 +133,8 @@ pub struct ActionProposal {
 pub struct Proposal {
     pub id: u64,
     pub creator: Address,
+    pub title: String,
+    pub category: String,
     pub description: String,
     pub start_time: u64,
     pub end_time: u64,
@@ -91,6 +165,27 @@ pub enum GovernanceKey {
     AllProposals,
     GovernanceActive,
     VoterRecord(u64, Address),
+    /// Number of proposals a user has voted on
+    UserVoteCount(Address),
+    /// Number of proposals a user has created
+    UserProposalCount(Address),
+    /// Latest action proposal for a given action hash (sha256 of its XDR)
+    ActiveAction(BytesN<32>),
+    /// Seconds of inactivity after which voting power halves (0 = no decay)
+    VotingPowerHalflife,
+    /// Voting power counted for a voter's vote on a proposal
+    SnapshotPower(u64, Address),
+    /// History of the rewards fields a user's voting power derives from
+    PowerCheckpoints(Address),
+}
+
+/// The inputs to a user's voting power as of `timestamp`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PowerCheckpoint {
+    pub timestamp: u64,
+    pub lifetime_deposited: i128,
+    pub last_action_timestamp: u64,
 }
 
 #[contracttype]
@@ -102,29 +197,204 @@ pub enum ProposalAction {
     SetLockRate(u64, i128),
     PauseContract,
     UnpauseContract,
+    RegisterStrategy(Address, u32),
+    DeregisterStrategy(Address),
+    SetVotingConfig(VotingConfig),
+    SetProtocolFee(u32),
+    SetTreasury(Address),
 }
 
-/// Calculates voting power for a user based on their lifetime deposited funds
+/// Calculates voting power for a user based on their lifetime deposited funds,
+/// decayed by the time since their last action when a halflife is configured
 pub fn get_voting_power(env: &Env, user: &Address) -> u128 {
     let rewards = get_user_rewards(env, user.clone());
-    rewards.lifetime_deposited.max(0) as u128
+    power_at(
+        env,
+        rewards.lifetime_deposited,
+        rewards.last_action_timestamp,
+        env.ledger().timestamp(),
+    )
+}
+
+/// Returns a user's voting power as of `timestamp`, from the latest
+/// checkpoint written at or before it. Users with no checkpoints have not
+/// changed their rewards since checkpoints were introduced, so their live
+/// rewards apply.
+pub fn get_voting_power_at(env: &Env, user: &Address, timestamp: u64) -> u128 {
+    let checkpoints: Option<Vec<PowerCheckpoint>> = env
+        .storage()
+        .persistent()
+        .get(&GovernanceKey::PowerCheckpoints(user.clone()));
+    let Some(checkpoints) = checkpoints else {
+        let rewards = get_user_rewards(env, user.clone());
+        return power_at(
+            env,
+            rewards.lifetime_deposited,
+            rewards.last_action_timestamp,
+            timestamp,
+        );
+    };
+
+    for checkpoint in checkpoints.iter().rev() {
+        if checkpoint.timestamp <= timestamp {
+            return power_at(
+                env,
+                checkpoint.lifetime_deposited,
+                checkpoint.last_action_timestamp,
+                timestamp,
+            );
+        }
+    }
+    0
+}
+
+/// Records the voting power inputs of `rewards`, which is about to replace
+/// `previous` in storage. Changes within one timestamp share a checkpoint.
+pub(crate) fn checkpoint_voting_power(
+    env: &Env,
+    user: &Address,
+    previous: Option<UserRewards>,
+    rewards: &UserRewards,
+) {
+    let key = GovernanceKey::PowerCheckpoints(user.clone());
+    let mut checkpoints: Vec<PowerCheckpoint> = match env.storage().persistent().get(&key) {
+        Some(checkpoints) => checkpoints,
+        None => {
+            let mut checkpoints = Vec::new(env);
+            // Seed with the state written before checkpoints existed
+            if let Some(previous) = previous {
+                checkpoints.push_back(PowerCheckpoint {
+                    timestamp: previous.last_action_timestamp,
+                    lifetime_deposited: previous.lifetime_deposited,
+                    last_action_timestamp: previous.last_action_timestamp,
+                });
+            }
+            checkpoints
+        }
+    };
+
+    if let Some(last) = checkpoints.last() {
+        if last.lifetime_deposited == rewards.lifetime_deposited
+            && last.last_action_timestamp == rewards.last_action_timestamp
+        {
+            return;
+        }
+    }
+
+    let now = env.ledger().timestamp();
+    let checkpoint = PowerCheckpoint {
+        timestamp: now,
+        lifetime_deposited: rewards.lifetime_deposited,
+        last_action_timestamp: rewards.last_action_timestamp,
+    };
+    match checkpoints.last() {
+        Some(last) if last.timestamp >= now => {
+            checkpoints.set(checkpoints.len() - 1, checkpoint);
+        }
+        _ => checkpoints.push_back(checkpoint),
+    }
+    env.storage().persistent().set(&key, &checkpoints);
+}
+
+/// Voting power of `lifetime_deposited`, decayed from `last_action_timestamp`
+/// to `timestamp` when a halflife is configured
+fn power_at(
+    env: &Env,
+    lifetime_deposited: i128,
+    last_action_timestamp: u64,
+    timestamp: u64,
+) -> u128 {
+    let power = lifetime_deposited.max(0) as u128;
+
+    let halflife = get_voting_power_halflife(env);
+    if halflife == 0 || power == 0 {
+        return power;
+    }
+    let elapsed = timestamp.saturating_sub(last_action_timestamp);
+    decay_voting_power(power, elapsed, halflife)
+}
+
+/// Halves `power` for every full `halflife` in `elapsed`, interpolating
+/// linearly within the current period.
+fn decay_voting_power(power: u128, elapsed: u64, halflife: u64) -> u128 {
+    let periods = elapsed / halflife;
+    if periods >= 128 {
+        return 0;
+    }
+    let halved = power >> periods;
+    let remainder = (elapsed % halflife) as u128;
+    halved - (halved / 2) * remainder / halflife as u128
+}
+
+/// Returns the voting power halflife in seconds (0 = decay disabled)
+pub fn get_voting_power_halflife(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::VotingPowerHalflife)
+        .unwrap_or(0)
+}
+
+/// Sets the voting power halflife (admin only). Zero disables decay.
+pub fn set_voting_power_halflife(
+    env: &Env,
+    admin: Address,
+    halflife_secs: u64,
+) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::VotingPowerHalflife, &halflife_secs);
+    env.events()
+        .publish((symbol_short!("set_hlife"),), halflife_secs);
+    Ok(())
+}
+
+/// Returns (proposals created, current voting power, proposals voted on) for a user
+pub fn get_user_governance_summary(env: &Env, user: Address) -> (u32, u128, u32) {
+    let proposals_created: u32 = env
+        .storage()
+        .persistent()
+        .get(&GovernanceKey::UserProposalCount(user.clone()))
+        .unwrap_or(0);
+    let votes_cast: u32 = env
+        .storage()
+        .persistent()
+        .get(&GovernanceKey::UserVoteCount(user.clone()))
+        .unwrap_or(0);
+    (proposals_created, get_voting_power(env, &user), votes_cast)
+}
+
+fn increment_user_counter(env: &Env, key: GovernanceKey) -> Result<(), SavingsError> {
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    let count = count.checked_add(1).ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&key, &count);
+    Ok(())
 }
 
 /// Creates a new governance proposal
 pub fn create_proposal(
     env: &Env,
     creator: Address,
+    title: String,
+    category: String,
     description: String,
 ) -> Result<u64, SavingsError> {
     creator.require_auth();
+    validate_proposal_metadata(&title, &category)?;
 
     let config = get_voting_config(env)?;
+    if get_voting_power(env, &creator) < config.proposal_threshold {
+        return Err(SavingsError::InsufficientBalance);
+    }
+
     let proposal_id = get_next_proposal_id(env);
     let now = env.ledger().timestamp();
 
     let proposal = Proposal {
         id: proposal_id,
         creator: creator.clone(),
+        title: title.clone(),
+        category: category.clone(),
         description: description.clone(),
         start_time: now,
         end_time: now + config.voting_period,
@@ -153,8 +423,10 @@ pub fn create_proposal(
         .persistent()
         .set(&GovernanceKey::NextProposalId, &(proposal_id + 1));
 
+    increment_user_counter(env, GovernanceKey::UserProposalCount(creator.clone()))?;
+
     // Emit event
-    emit_proposal_created(env, proposal_id, creator, description);
+    emit_proposal_created(env, proposal_id, creator, title, category, description);
 
     Ok(proposal_id)
 }
@@ -163,10 +435,13 @@ pub fn create_proposal(
 pub fn create_action_proposal(
     env: &Env,
     creator: Address,
+    title: String,
+    category: String,
     description: String,
     action: ProposalAction,
 ) -> Result<u64, SavingsError> {
     creator.require_auth();
+    validate_proposal_metadata(&title, &category)?;
 
     let config = get_voting_config(env)?;
     if get_voting_power(env, &creator) < config.proposal_threshold {
@@ -176,9 +451,26 @@ pub fn create_action_proposal(
     let proposal_id = get_next_proposal_id(env);
     let now = env.ledger().timestamp();
 
+    let action_key =
+        GovernanceKey::ActiveAction(env.crypto().sha256(&action.clone().to_xdr(env)).into());
+    if let Some(existing_id) = env.storage().persistent().get::<_, u64>(&action_key) {
+        // Executed (or canceled) and defeated proposals free the action;
+        // open, passing and queued ones still hold it
+        let status = get_proposal_status(env, existing_id);
+        if status.is_ok()
+            && status != Ok(PROPOSAL_STATUS_EXECUTED)
+            && status != Ok(PROPOSAL_STATUS_DEFEATED)
+        {
+            return Err(SavingsError::DuplicateProposal);
+        }
+    }
+    env.storage().persistent().set(&action_key, &proposal_id);
+
     let proposal = ActionProposal {
         id: proposal_id,
         creator: creator.clone(),
+        title: title.clone(),
+        category: category.clone(),
         description: description.clone(),
         start_time: now,
         end_time: now + config.voting_period,
@@ -208,12 +500,22 @@ pub fn create_action_proposal(
         .persistent()
         .set(&GovernanceKey::NextProposalId, &(proposal_id + 1));
 
+    increment_user_counter(env, GovernanceKey::UserProposalCount(creator.clone()))?;
+
     // Emit event
-    emit_proposal_created(env, proposal_id, creator, description);
+    emit_proposal_created(env, proposal_id, creator, title, category, description);
 
     Ok(proposal_id)
 }
 
+/// Rejects proposals without a title or category
+fn validate_proposal_metadata(title: &String, category: &String) -> Result<(), SavingsError> {
+    if title.is_empty() || category.is_empty() {
+        return Err(SavingsError::InvalidProposal);
+    }
+    Ok(())
+}
+
 /// Gets an action proposal by ID
 pub fn get_action_proposal(env: &Env, proposal_id: u64) -> Option<ActionProposal> {
     env.storage()
@@ -236,6 +538,27 @@ pub fn list_proposals(env: &Env) -> Vec<u64> {
         .unwrap_or(Vec::new(env))
 }
 
+/// Returns up to `limit` proposal IDs whose `get_proposal_status` equals
+/// `status_code`, skipping the first `offset` matches (creation order)
+pub fn list_proposals_by_status(env: &Env, status_code: u32, offset: u32, limit: u32) -> Vec<u64> {
+    let mut matches = Vec::new(env);
+    let mut skipped = 0u32;
+    for proposal_id in list_proposals(env).iter() {
+        if matches.len() >= limit {
+            break;
+        }
+        if get_proposal_status(env, proposal_id) != Ok(status_code) {
+            continue;
+        }
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+        matches.push_back(proposal_id);
+    }
+    matches
+}
+
 /// Gets the voting configuration
 pub fn get_voting_config(env: &Env) -> Result<VotingConfig, SavingsError> {
     env.storage()
@@ -266,9 +589,7 @@ pub fn init_voting_config(
         return Err(SavingsError::ConfigAlreadyInitialized);
     }
 
-    if config.voting_period == 0 || config.timelock_duration == 0 || config.max_voting_power == 0 {
-        return Err(SavingsError::InvalidAmount);
-    }
+    validate_voting_config(&config)?;
 
     env.storage()
         .persistent()
@@ -280,6 +601,18 @@ pub fn init_voting_config(
     Ok(())
 }
 
+/// Rejects voting configs with a quorum above 100% or zero-length periods
+fn validate_voting_config(config: &VotingConfig) -> Result<(), SavingsError> {
+    if config.quorum > 10_000
+        || config.voting_period == 0
+        || config.timelock_duration == 0
+        || config.max_voting_power == 0
+    {
+        return Err(SavingsError::InvalidAmount);
+    }
+    Ok(())
+}
+
 fn get_next_proposal_id(env: &Env) -> u64 {
     env.storage()
         .persistent()
@@ -287,7 +620,12 @@ fn get_next_proposal_id(env: &Env) -> u64 {
         .unwrap_or(1)
 }
 
-/// Casts a weighted vote on a proposal
+/// Returns the number of proposals created so far (regular and action)
+pub fn get_proposal_count(env: &Env) -> u64 {
+    get_next_proposal_id(env).saturating_sub(1)
+}
+
+/// Casts a vote weighted by the voter's power when the proposal was created
 pub fn vote(
     env: &Env,
     proposal_id: u64,
@@ -295,13 +633,48 @@ pub fn vote(
     voter: Address,
 ) -> Result<(), SavingsError> {
     voter.require_auth();
+    cast_vote(env, proposal_id, vote_type, voter)
+}
+
+/// Casts several votes under a single authorization. Each entry is
+/// `(proposal_id, vote_type)`; a failed vote is reported as `false` without
+/// reverting the others.
+pub fn batch_vote(env: &Env, votes: Vec<(u64, u32)>, voter: Address) -> Vec<bool> {
+    voter.require_auth();
 
+    let mut results = Vec::new(env);
+    for (proposal_id, vote_type) in votes.iter() {
+        results.push_back(cast_vote(env, proposal_id, vote_type, voter.clone()).is_ok());
+    }
+    results
+}
+
+/// Applies a vote for an already-authorized voter. Nothing is written
+/// unless the vote succeeds.
+fn cast_vote(
+    env: &Env,
+    proposal_id: u64,
+    vote_type: u32,
+    voter: Address,
+) -> Result<(), SavingsError> {
     // Validate vote_type: 1=for, 2=against, 3=abstain
     if !(1..=3).contains(&vote_type) {
         return Err(SavingsError::InvalidAmount);
     }
 
-    let weight = get_voting_power(env, &voter);
+    let voter_key = GovernanceKey::VoterRecord(proposal_id, voter.clone());
+    if env.storage().persistent().has(&voter_key) {
+        return Err(SavingsError::DuplicatePlanId);
+    }
+
+    let snapshot_time = if let Some(proposal) = get_proposal(env, proposal_id) {
+        proposal.start_time
+    } else if let Some(proposal) = get_action_proposal(env, proposal_id) {
+        proposal.start_time
+    } else {
+        return Err(SavingsError::PlanNotFound);
+    };
+    let weight = get_voting_power_at(env, &voter, snapshot_time);
     if weight == 0 {
         return Err(SavingsError::InsufficientBalance);
     }
@@ -309,11 +682,6 @@ pub fn vote(
     let config = get_voting_config(env)?;
     let capped_weight = weight.min(config.max_voting_power);
 
-    let voter_key = GovernanceKey::VoterRecord(proposal_id, voter.clone());
-    if env.storage().persistent().has(&voter_key) {
-        return Err(SavingsError::DuplicatePlanId);
-    }
-
     let now = env.ledger().timestamp();
 
     // Regular proposal
@@ -347,7 +715,8 @@ pub fn vote(
         env.storage()
             .persistent()
             .set(&GovernanceKey::Proposal(proposal_id), &proposal);
-        env.storage().persistent().set(&voter_key, &true);
+        record_vote(env, proposal_id, &voter, weight);
+        increment_user_counter(env, GovernanceKey::UserVoteCount(voter.clone()))?;
 
         emit_vote_cast(env, proposal_id, voter, vote_type, weight);
 
@@ -385,7 +754,8 @@ pub fn vote(
         env.storage()
             .persistent()
             .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
-        env.storage().persistent().set(&voter_key, &true);
+        record_vote(env, proposal_id, &voter, weight);
+        increment_user_counter(env, GovernanceKey::UserVoteCount(voter.clone()))?;
 
         emit_vote_cast(env, proposal_id, voter, vote_type, weight);
 
@@ -395,6 +765,26 @@ pub fn vote(
     Err(SavingsError::PlanNotFound)
 }
 
+/// Records the vote and the power it was cast with
+fn record_vote(env: &Env, proposal_id: u64, voter: &Address, weight: u128) {
+    env.storage().persistent().set(
+        &GovernanceKey::VoterRecord(proposal_id, voter.clone()),
+        &true,
+    );
+    env.storage().persistent().set(
+        &GovernanceKey::SnapshotPower(proposal_id, voter.clone()),
+        &weight,
+    );
+}
+
+/// Returns the voting power snapshotted for a voter on a proposal (0 if none)
+pub fn get_vote_weight(env: &Env, proposal_id: u64, voter: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::SnapshotPower(proposal_id, voter.clone()))
+        .unwrap_or(0)
+}
+
 /// Checks if a user has already voted on a proposal
 pub fn has_voted(env: &Env, proposal_id: u64, voter: &Address) -> bool {
     let voter_key = GovernanceKey::VoterRecord(proposal_id, voter.clone());
@@ -563,14 +953,34 @@ fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError
         }
         ProposalAction::PauseContract => {
             env.storage().persistent().set(&DataKey::Paused, &true);
+            crate::config::clear_auto_unpause(env);
             crate::ttl::extend_config_ttl(env, &DataKey::Paused);
             Ok(())
         }
         ProposalAction::UnpauseContract => {
             env.storage().persistent().set(&DataKey::Paused, &false);
+            crate::config::clear_auto_unpause(env);
             crate::ttl::extend_config_ttl(env, &DataKey::Paused);
             Ok(())
         }
+        ProposalAction::RegisterStrategy(strategy, risk_level) => {
+            crate::strategy::registry::store_strategy(env, strategy.clone(), *risk_level)
+        }
+        ProposalAction::DeregisterStrategy(strategy) => {
+            crate::strategy::registry::remove_strategy(env, strategy.clone())
+        }
+        ProposalAction::SetVotingConfig(config) => {
+            validate_voting_config(config)?;
+            env.storage()
+                .persistent()
+                .set(&GovernanceKey::VotingConfig, config);
+            Ok(())
+        }
+        ProposalAction::SetProtocolFee(fee_bps) => crate::config::store_protocol_fee(env, *fee_bps),
+        ProposalAction::SetTreasury(treasury) => {
+            crate::config::store_treasury(env, treasury.clone());
+            Ok(())
+        }
     }
 }
 
@@ -5,6 +5,8 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
 pub struct ProposalCreated {
     pub proposal_id: u64,
     pub creator: Address,
+    pub title: String,
+    pub category: String,
     pub description: String,
 }
 
@@ -38,10 +40,19 @@ pub struct ProposalCanceled {
     pub canceled_at: u64,
 }
 
-pub fn emit_proposal_created(env: &Env, proposal_id: u64, creator: Address, description: String) {
+pub fn emit_proposal_created(
+    env: &Env,
+    proposal_id: u64,
+    creator: Address,
+    title: String,
+    category: String,
+    description: String,
+) {
     let event = ProposalCreated {
         proposal_id,
         creator: creator.clone(),
+        title,
+        category,
         description,
     };
 
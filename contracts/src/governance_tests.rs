@@ -7,7 +7,7 @@ mod governance_tests {
         env.mock_all_auths();
         client.init_voting_config(&admin, &5000, &10, &5, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let voter1 = Address::generate(&env);
         let voter2 = Address::generate(&env);
         client.initialize_user(&voter1);
@@ -16,7 +16,12 @@ mod governance_tests {
         client.create_savings_plan(&voter2, &PlanType::Flexi, &20000);
 
         let desc = String::from_str(&env, "Lifecycle proposal");
-        let proposal_id = client.create_proposal(&creator, &desc);
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &desc,
+        );
 
         client.vote(&proposal_id, &1, &voter1); // For
         client.vote(&proposal_id, &1, &voter2); // For
@@ -36,13 +41,18 @@ mod governance_tests {
         env.mock_all_auths();
         client.init_voting_config(&admin, &5000, &10, &5, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let attacker = Address::generate(&env);
         client.initialize_user(&attacker);
         client.create_savings_plan(&attacker, &PlanType::Flexi, &50); // Not enough power
 
         let desc = String::from_str(&env, "Attack proposal");
-        let proposal_id = client.create_proposal(&creator, &desc);
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &desc,
+        );
 
         // Attacker tries to vote multiple times
         client.vote(&proposal_id, &1, &attacker);
@@ -62,7 +72,7 @@ mod governance_tests {
 
     use crate::governance_events::{ProposalCreated, VoteCast};
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::symbol_short;
     use soroban_sdk::IntoVal;
     use soroban_sdk::{
@@ -90,12 +100,23 @@ mod governance_tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
         client.initialize_rewards_config(&config);
 
         (env, client, admin)
     }
 
+    /// Creates a user whose deposits clear the proposal threshold used in these tests.
+    fn funded_creator(env: &Env, client: &NesteraContractClient<'static>) -> Address {
+        let creator = Address::generate(env);
+        client.initialize_user(&creator);
+        client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+        creator
+    }
+
     // ────────────────────────────────────────────────────────────────────────────────
     // Existing tests (kept + fixed unwrap usage)
     // ────────────────────────────────────────────────────────────────────────────────
@@ -136,6 +157,95 @@ mod governance_tests {
         assert_eq!(power, 1500);
     }
 
+    #[test]
+    fn test_voting_power_decays_after_halflife() {
+        let (env, client, admin) = setup_contract();
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize_user(&user);
+        let _ = client.create_savings_plan(&user, &PlanType::Flexi, &1000);
+        client.set_voting_power_halflife(&admin, &86_400);
+        assert_eq!(client.get_voting_power(&user), 1000);
+
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        assert_eq!(client.get_voting_power(&user), 500);
+    }
+
+    #[test]
+    fn test_set_voting_power_halflife_emits_event() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.set_voting_power_halflife(&admin, &86_400);
+
+        let events = env.events().all();
+        let event = events
+            .iter()
+            .rev()
+            .find(|e| e.0 == client.address && e.1 == (symbol_short!("set_hlife"),).into_val(&env));
+        let halflife: u64 = event.expect("halflife event not emitted").2.into_val(&env);
+        assert_eq!(halflife, 86_400);
+    }
+
+    #[test]
+    fn test_voting_power_restored_after_deposit() {
+        let (env, client, admin) = setup_contract();
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize_user(&user);
+        let _ = client.create_savings_plan(&user, &PlanType::Flexi, &1000);
+        client.set_voting_power_halflife(&admin, &86_400);
+
+        env.ledger().with_mut(|li| li.timestamp += 3 * 86_400);
+        assert_eq!(client.get_voting_power(&user), 125);
+
+        let _ = client.create_savings_plan(&user, &PlanType::Flexi, &100);
+        assert_eq!(client.get_voting_power(&user), 1100);
+    }
+
+    #[test]
+    fn test_user_governance_summary_tracks_participation() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        client.init_voting_config(&admin, &5000, &10, &5, &100, &10_000);
+
+        let user = funded_creator(&env, &client);
+        let other = funded_creator(&env, &client);
+        assert_eq!(client.get_user_governance_summary(&user), (0, 1000, 0));
+
+        let p1 = client.create_proposal(
+            &user,
+            &String::from_str(&env, "First"),
+            &String::from_str(&env, "general"),
+            &String::from_str(&env, "One"),
+        );
+        let p2 = client.create_proposal(
+            &user,
+            &String::from_str(&env, "Second"),
+            &String::from_str(&env, "general"),
+            &String::from_str(&env, "Two"),
+        );
+        let p3 = client.create_proposal(
+            &other,
+            &String::from_str(&env, "Third"),
+            &String::from_str(&env, "general"),
+            &String::from_str(&env, "Three"),
+        );
+
+        client.vote(&p1, &1, &user);
+        client.vote(&p3, &2, &user);
+        client.vote(&p2, &1, &other);
+        assert!(client.try_vote(&p1, &1, &user).is_err());
+
+        let (created, power, voted) = client.get_user_governance_summary(&user);
+        assert_eq!(created, 2);
+        assert_eq!(power, client.get_voting_power(&user));
+        assert_eq!(voted, 2);
+        assert_eq!(client.get_user_governance_summary(&other), (1, 1000, 1));
+    }
+
     #[test]
     fn test_init_voting_config() {
         let (env, client, admin) = setup_contract();
@@ -157,10 +267,15 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let description = String::from_str(&env, "Test proposal");
 
-        let proposal_id = client.create_proposal(&creator, &description);
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &description,
+        );
 
         assert_eq!(proposal_id, 1);
     }
@@ -172,9 +287,14 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let description = String::from_str(&env, "Test proposal");
-        let proposal_id = client.create_proposal(&creator, &description);
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &description,
+        );
 
         let proposal = client.get_proposal(&proposal_id).unwrap();
         let now = env.ledger().timestamp();
@@ -194,12 +314,22 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let desc1 = String::from_str(&env, "Proposal 1");
         let desc2 = String::from_str(&env, "Proposal 2");
 
-        let _ = client.create_proposal(&creator, &desc1);
-        let _ = client.create_proposal(&creator, &desc2);
+        let _ = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &desc1,
+        );
+        let _ = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &desc2,
+        );
 
         let proposals = client.list_proposals();
         assert_eq!(proposals.len(), 2);
@@ -214,9 +344,14 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let description = String::from_str(&env, "Store test");
-        let proposal_id = client.create_proposal(&creator, &description);
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &description,
+        );
 
         let proposal = client.get_proposal(&proposal_id).unwrap();
         let now = env.ledger().timestamp();
@@ -226,6 +361,85 @@ mod governance_tests {
         assert_eq!(proposal.end_time, now + 604800);
     }
 
+    #[test]
+    fn test_create_proposal_enforces_voting_power_threshold() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        assert_eq!(client.get_voting_config().proposal_threshold, 100);
+
+        let title = String::from_str(&env, "Title");
+        let category = String::from_str(&env, "general");
+        let description = String::from_str(&env, "Threshold test");
+
+        let below = Address::generate(&env);
+        client.initialize_user(&below);
+        client.create_savings_plan(&below, &PlanType::Flexi, &99);
+        assert_eq!(
+            client.try_create_proposal(&below, &title, &category, &description),
+            Err(Ok(SavingsError::InsufficientBalance))
+        );
+
+        let at = Address::generate(&env);
+        client.initialize_user(&at);
+        client.create_savings_plan(&at, &PlanType::Flexi, &100);
+        assert_eq!(client.get_voting_power(&at), 100);
+        assert!(client
+            .try_create_proposal(&at, &title, &category, &description)
+            .is_ok());
+
+        let above = funded_creator(&env, &client);
+        assert!(client
+            .try_create_proposal(&above, &title, &category, &description)
+            .is_ok());
+        assert_eq!(client.list_proposals().len(), 2);
+    }
+
+    #[test]
+    fn test_proposal_metadata_round_trips() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = funded_creator(&env, &client);
+        let title = String::from_str(&env, "Raise flexi rate");
+        let category = String::from_str(&env, "rates");
+        let proposal_id = client.create_proposal(
+            &creator,
+            &title,
+            &category,
+            &String::from_str(&env, "Bump flexi to 6%"),
+        );
+
+        let proposal = client.get_proposal(&proposal_id).unwrap();
+        assert_eq!(proposal.title, title);
+        assert_eq!(proposal.category, category);
+    }
+
+    #[test]
+    fn test_proposal_rejects_empty_title_or_category() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = funded_creator(&env, &client);
+        let empty = String::from_str(&env, "");
+        let text = String::from_str(&env, "text");
+
+        assert_eq!(
+            client.try_create_proposal(&creator, &empty, &text, &text),
+            Err(Ok(SavingsError::InvalidProposal))
+        );
+        assert_eq!(
+            client.try_create_proposal(&creator, &text, &empty, &text),
+            Err(Ok(SavingsError::InvalidProposal))
+        );
+        assert!(client.list_proposals().is_empty());
+    }
+
     // ────────────────────────────────────────────────────────────────────────────────
     // NEW TESTS: Governance Event Logging
     // ────────────────────────────────────────────────────────────────────────────────
@@ -237,10 +451,15 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let description = String::from_str(&env, "Test proposal description");
 
-        let proposal_id = client.create_proposal(&creator, &description);
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &description,
+        );
 
         let events = env.events().all();
 
@@ -263,6 +482,8 @@ mod governance_tests {
 
         assert_eq!(event_data.proposal_id, proposal_id);
         assert_eq!(event_data.creator, creator);
+        assert_eq!(event_data.title, String::from_str(&env, "Title"));
+        assert_eq!(event_data.category, String::from_str(&env, "general"));
         assert_eq!(event_data.description, description);
     }
 
@@ -273,13 +494,18 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = funded_creator(&env, &client);
         let voter = Address::generate(&env);
 
         client.initialize_user(&voter);
         client.create_savings_plan(&voter, &PlanType::Flexi, &10000);
 
-        let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Vote test"));
+        let proposal_id = client.create_proposal(
+            &creator,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &String::from_str(&env, "Vote test"),
+        );
 
         client.vote(&proposal_id, &1, &voter);
 
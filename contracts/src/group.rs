@@ -1,9 +1,11 @@
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
-use crate::storage_types::{DataKey, GroupSave};
+use crate::history;
+use crate::storage_types::{ConfigKey, DataKey, GroupKey, GroupSave, SavingsPlan, User};
+use crate::strategy::{registry, routing, routing::StrategyPositionKey};
 use crate::ttl;
 use crate::users;
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{symbol_short, Address, Env, String, Vec};
 
 /// Creates a new group savings plan.
 ///
@@ -74,6 +76,7 @@ pub fn create_group_save(
     if category.is_empty() {
         return Err(SavingsError::InvalidGroupConfig);
     }
+    users::ensure_plan_capacity(env, &creator)?;
 
     // Get the next group ID
     let next_id_key = DataKey::NextGroupId;
@@ -95,6 +98,8 @@ pub fn create_group_save(
         start_time,
         end_time,
         is_completed: false,
+        auto_distribute: false,
+        max_members: 0,
     };
 
     // Store the GroupSave in persistent storage
@@ -119,6 +124,17 @@ pub fn create_group_save(
     // Add group_id to the creator's UserGroupSaves list
     add_group_to_user_list(env, &creator, group_id)?;
 
+    if is_public {
+        let index_key = DataKey::PublicGroupIndex;
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or(Vec::new(env));
+        index.push_back(group_id);
+        env.storage().persistent().set(&index_key, &index);
+    }
+
     // Create a SavingsPlan for the creator so they can retrieve it via get_group_save
     let now = env.ledger().timestamp();
     let savings_plan = crate::storage_types::SavingsPlan {
@@ -133,13 +149,14 @@ pub fn create_group_save(
         start_time: now,
         last_deposit: 0,
         last_withdraw: 0,
-        interest_rate: 500, // Default 5%
+        interest_rate: crate::rates::resolve_group_rate(env)?,
         is_completed: false,
         is_withdrawn: false,
     };
 
     let plan_key = DataKey::SavingsPlan(creator.clone(), group_id);
     env.storage().persistent().set(&plan_key, &savings_plan);
+    users::record_plan_opened(env, &creator)?;
 
     // Extend TTL for new group, members list, and user data
     ttl::extend_group_ttl(env, group_id);
@@ -253,6 +270,8 @@ fn add_group_to_user_list(env: &Env, user: &Address, group_id: u64) -> Result<()
 /// - User doesn't exist
 /// - Group doesn't exist
 /// - Group is not public
+/// - Group has reached its member cap
+/// - User left the group within the join cooldown
 /// - User is already a member
 pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), SavingsError> {
     ensure_not_paused(env)?;
@@ -274,6 +293,26 @@ pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), Sa
         return Err(SavingsError::InvalidGroupConfig);
     }
 
+    // Block rejoining too soon after leaving
+    let cooldown = get_group_join_cooldown(env);
+    if cooldown > 0 {
+        let left_at: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::GroupLeaveTime(group_id, user.clone()));
+        if left_at
+            .is_some_and(|left_at| env.ledger().timestamp() < left_at.saturating_add(cooldown))
+        {
+            return Err(SavingsError::TooEarly);
+        }
+    }
+
+    // Reject once the member cap is reached
+    if group.max_members > 0 && group.member_count >= group.max_members {
+        return Err(SavingsError::GroupFull);
+    }
+    users::ensure_plan_capacity(env, &user)?;
+
     // Check if user is already a member
     let members_key = DataKey::GroupMembers(group_id);
     let mut members: Vec<Address> = env
@@ -299,6 +338,11 @@ pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), Sa
     group.member_count += 1;
     env.storage().persistent().set(&group_key, &group);
 
+    // A full group no longer shows up in public listings
+    if group.max_members > 0 && group.member_count >= group.max_members {
+        remove_from_public_index(env, group_id);
+    }
+
     // Add group to user's list of groups
     add_group_to_user_list(env, &user, group_id)?;
 
@@ -320,13 +364,14 @@ pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), Sa
         start_time: now,
         last_deposit: 0,
         last_withdraw: 0,
-        interest_rate: 500, // Default 5%
+        interest_rate: crate::rates::resolve_group_rate(env)?,
         is_completed: group.is_completed,
         is_withdrawn: false,
     };
 
     let plan_key = DataKey::SavingsPlan(user.clone(), group_id);
     env.storage().persistent().set(&plan_key, &savings_plan);
+    users::record_plan_opened(env, &user)?;
 
     // Extend TTL for group and user data
     ttl::extend_group_ttl(env, group_id);
@@ -352,6 +397,8 @@ pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), Sa
 /// `Ok(())` on success
 /// `Err(SavingsError)` if:
 /// - Amount is invalid (<= 0)
+/// - Amount is not a multiple of a fixed group's contribution, or is below a
+///   flexible group's minimum
 /// - User is not a member
 /// - Group doesn't exist
 pub fn contribute_to_group_save(
@@ -374,6 +421,29 @@ pub fn contribute_to_group_save(
         .get(&group_key)
         .ok_or(SavingsError::PlanNotFound)?;
 
+    // A completed group takes no more funds; the contribution that reaches
+    // the target may land exactly on it but not past it
+    if group.is_completed {
+        return Err(SavingsError::PlanCompleted);
+    }
+    let new_total = group
+        .current_amount
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    if new_total > group.target_amount {
+        return Err(SavingsError::InvalidContribution);
+    }
+
+    // Fixed groups take whole multiples of the contribution; flexible groups
+    // treat it as a minimum
+    match group.contribution_type {
+        0 if amount % group.contribution_amount != 0 => {
+            return Err(SavingsError::InvalidContribution)
+        }
+        1 if amount < group.contribution_amount => return Err(SavingsError::InvalidContribution),
+        _ => {}
+    }
+
     // Check if user is a member
     let members_key = DataKey::GroupMembers(group_id);
     let members: Vec<Address> = env
@@ -407,17 +477,21 @@ pub fn contribute_to_group_save(
     env.storage()
         .persistent()
         .set(&contribution_key, &new_contribution);
+    record_member_since(env, group_id, &user, current_contribution, amount)?;
 
     // Update group's current_amount
-    group.current_amount += amount;
+    group.current_amount = new_total;
 
     // Check if goal is reached
+    let was_completed = group.is_completed;
     if group.current_amount >= group.target_amount {
         group.is_completed = true;
     }
 
     // Save updated group
     env.storage().persistent().set(&group_key, &group);
+    crate::stats::record_deposit(env, amount)?;
+    history::record(env, &user, symbol_short!("group_dep"), amount, group_id);
 
     // Update the user's SavingsPlan to reflect the new balance
     let plan_key = DataKey::SavingsPlan(user.clone(), group_id);
@@ -445,11 +519,12 @@ pub fn contribute_to_group_save(
             start_time: now,
             last_deposit: now,
             last_withdraw: 0,
-            interest_rate: 500,
+            interest_rate: crate::rates::resolve_group_rate(env)?,
             is_completed: group.is_completed,
             is_withdrawn: false,
         };
         env.storage().persistent().set(&plan_key, &plan);
+        users::record_plan_opened(env, &user)?;
     }
 
     // Award deposit points
@@ -466,6 +541,399 @@ pub fn contribute_to_group_save(
         amount,
     );
 
+    if !group.is_completed {
+        route_group_contribution(env, group_id, amount)?;
+    } else if !was_completed && group.auto_distribute {
+        let strategy_yield = withdraw_group_from_strategy(env, group_id, group.current_amount)?;
+        distribute_group(env, &group, strategy_yield)?;
+    }
+
+    Ok(())
+}
+
+/// Routes a contribution into the group's strategy position.
+///
+/// A funded position keeps its strategy; otherwise the default group strategy
+/// is used. No-op when there is no strategy or it has been disabled.
+fn route_group_contribution(env: &Env, group_id: u64, amount: i128) -> Result<(), SavingsError> {
+    let position_key = StrategyPositionKey::Group(group_id);
+    let strategy_address = match routing::get_position(env, position_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position.strategy,
+        _ => match get_default_group_strategy(env) {
+            Some(strategy_address) => strategy_address,
+            None => return Ok(()),
+        },
+    };
+
+    let enabled = registry::get_strategy(env, strategy_address.clone())
+        .map(|info| info.enabled)
+        .unwrap_or(false);
+    if enabled {
+        routing::add_to_position(env, strategy_address, position_key, amount)?;
+    }
+    Ok(())
+}
+
+/// Pulls up to `amount` of a group's principal back from its strategy
+/// position, if it has one.
+///
+/// Harvests the strategy first so accrued yield is credited, then withdraws
+/// the principal and claims its pro-rata share of the strategy's user yield
+/// pool.
+///
+/// # Returns
+/// The strategy yield attributable to the withdrawn principal (0 if the
+/// group was never routed).
+fn withdraw_group_from_strategy(
+    env: &Env,
+    group_id: u64,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    let position_key = StrategyPositionKey::Group(group_id);
+    let position = match routing::get_position(env, position_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return Ok(0),
+    };
+    let principal = amount.min(position.principal_deposited);
+    if principal <= 0 {
+        return Ok(0);
+    }
+
+    // A failing harvest leaves state untouched; the principal is still withdrawn
+    let _ = routing::harvest_strategy(env, position.strategy.clone());
+    let yield_share = routing::take_position_yield(env, position_key.clone(), principal)?;
+
+    routing::withdraw_amount_from_strategy(
+        env,
+        position_key,
+        principal,
+        env.current_contract_address(),
+    )?;
+
+    Ok(yield_share)
+}
+
+/// Sets or clears the strategy Group Save contributions are routed into.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+/// * `StrategyNotFound` - If the strategy is not registered
+/// * `StrategyDisabled` - If the strategy is disabled
+pub fn set_default_group_strategy(
+    env: &Env,
+    admin: Address,
+    strategy_address: Option<Address>,
+) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    match strategy_address {
+        Some(strategy_address) => {
+            let info = registry::get_strategy(env, strategy_address.clone())?;
+            if !info.enabled {
+                return Err(SavingsError::StrategyDisabled);
+            }
+            env.storage()
+                .instance()
+                .set(&ConfigKey::DefaultGroupStrategy, &strategy_address);
+            env.events()
+                .publish((symbol_short!("grp_strt"),), strategy_address);
+        }
+        None => {
+            env.storage()
+                .instance()
+                .remove(&ConfigKey::DefaultGroupStrategy);
+            env.events().publish((symbol_short!("grp_strt"),), ());
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the strategy Group Save contributions are routed into, if any.
+pub fn get_default_group_strategy(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::DefaultGroupStrategy)
+}
+
+/// Sets how long a user must wait after leaving a group before rejoining it.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+pub fn set_group_join_cooldown(
+    env: &Env,
+    admin: Address,
+    seconds: u64,
+) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::GroupJoinCooldown, &seconds);
+    env.events()
+        .publish((symbol_short!("grp_cool"), admin), seconds);
+
+    Ok(())
+}
+
+/// Returns the group rejoin cooldown in seconds (0 = none).
+pub fn get_group_join_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::GroupJoinCooldown)
+        .unwrap_or(0)
+}
+
+/// Enables or disables automatic payout when the group reaches its target.
+///
+/// # Errors
+/// * `PlanNotFound` - If the group does not exist
+/// * `Unauthorized` - If `creator` is not the group creator
+/// * `PlanCompleted` - If the group has already reached its target
+pub fn set_group_auto_distribute(
+    env: &Env,
+    creator: Address,
+    group_id: u64,
+    enabled: bool,
+) -> Result<(), SavingsError> {
+    creator.require_auth();
+
+    let group_key = DataKey::GroupSave(group_id);
+    let mut group: GroupSave = env
+        .storage()
+        .persistent()
+        .get(&group_key)
+        .ok_or(SavingsError::PlanNotFound)?;
+    if group.creator != creator {
+        return Err(SavingsError::Unauthorized);
+    }
+    if group.is_completed {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    group.auto_distribute = enabled;
+    env.storage().persistent().set(&group_key, &group);
+    ttl::extend_group_ttl(env, group_id);
+
+    env.events().publish(
+        (soroban_sdk::symbol_short!("grp_auto"), creator, group_id),
+        enabled,
+    );
+
+    Ok(())
+}
+
+/// Caps the number of members, including the creator (0 = unlimited).
+///
+/// # Errors
+/// * `PlanNotFound` - If the group does not exist
+/// * `Unauthorized` - If `creator` is not the group creator
+/// * `PlanCompleted` - If the group has already reached its target
+/// * `InvalidGroupConfig` - If the cap is below the current member count
+pub fn set_group_max_members(
+    env: &Env,
+    creator: Address,
+    group_id: u64,
+    max_members: u32,
+) -> Result<(), SavingsError> {
+    creator.require_auth();
+
+    let group_key = DataKey::GroupSave(group_id);
+    let mut group: GroupSave = env
+        .storage()
+        .persistent()
+        .get(&group_key)
+        .ok_or(SavingsError::PlanNotFound)?;
+    if group.creator != creator {
+        return Err(SavingsError::Unauthorized);
+    }
+    if group.is_completed {
+        return Err(SavingsError::PlanCompleted);
+    }
+    if max_members > 0 && max_members < group.member_count {
+        return Err(SavingsError::InvalidGroupConfig);
+    }
+
+    group.max_members = max_members;
+    env.storage().persistent().set(&group_key, &group);
+    ttl::extend_group_ttl(env, group_id);
+
+    // Keep the public listing in step with whether the group can still be joined
+    if group.is_public {
+        if max_members > 0 && group.member_count >= max_members {
+            remove_from_public_index(env, group_id);
+        } else {
+            let index_key = DataKey::PublicGroupIndex;
+            let mut index: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&index_key)
+                .unwrap_or(Vec::new(env));
+            if !index.contains(group_id) {
+                index.push_back(group_id);
+                env.storage().persistent().set(&index_key, &index);
+            }
+        }
+    }
+
+    env.events().publish(
+        (soroban_sdk::symbol_short!("grp_cap"), creator, group_id),
+        max_members,
+    );
+
+    Ok(())
+}
+
+/// Pays the group pool into each member's Flexi balance: their contribution,
+/// group interest accrued since it went in, and a share of any strategy yield
+/// in proportion to their contribution. Integer rounding dust goes to the
+/// creator. Returns the total paid out.
+fn distribute_group(
+    env: &Env,
+    group: &GroupSave,
+    strategy_yield: i128,
+) -> Result<i128, SavingsError> {
+    let now = env.ledger().timestamp();
+    let creator_plan: Option<SavingsPlan> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SavingsPlan(group.creator.clone(), group.id));
+    let rate = match creator_plan {
+        Some(plan) => plan.interest_rate,
+        None => crate::rates::resolve_group_rate(env)?,
+    };
+
+    let mut pool = strategy_yield;
+    let mut distributed = 0i128;
+    for member in get_group_members(env, group.id).iter() {
+        let contribution = get_member_contribution(env, group.id, &member);
+        if contribution <= 0 {
+            continue;
+        }
+        // Each member accrues group interest from when their funds went in
+        let interest = crate::rates::calculate_plan_interest(
+            contribution,
+            rate,
+            now.saturating_sub(get_member_since(env, group.id, &member)),
+        );
+        let yield_share = strategy_yield
+            .checked_mul(contribution)
+            .ok_or(SavingsError::Overflow)?
+            / group.current_amount;
+        let share = contribution
+            .checked_add(interest)
+            .and_then(|share| share.checked_add(yield_share))
+            .ok_or(SavingsError::Overflow)?;
+        pool = pool
+            .checked_add(contribution)
+            .and_then(|pool| pool.checked_add(interest))
+            .ok_or(SavingsError::Overflow)?;
+        credit_member_flexi(env, &member, share)?;
+        history::record(env, &member, symbol_short!("group_wd"), share, group.id);
+        distributed = distributed
+            .checked_add(share)
+            .ok_or(SavingsError::Overflow)?;
+
+        let plan_key = DataKey::SavingsPlan(member.clone(), group.id);
+        if let Some(mut plan) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, SavingsPlan>(&plan_key)
+        {
+            if !plan.is_withdrawn {
+                users::record_plan_closed(env, &member);
+            }
+            plan.balance = 0;
+            plan.is_completed = true;
+            plan.is_withdrawn = true;
+            plan.last_withdraw = now;
+            env.storage().persistent().set(&plan_key, &plan);
+        }
+    }
+
+    let dust = pool
+        .checked_sub(distributed)
+        .ok_or(SavingsError::Underflow)?;
+    if dust > 0 {
+        credit_member_flexi(env, &group.creator, dust)?;
+        history::record(
+            env,
+            &group.creator,
+            symbol_short!("group_wd"),
+            dust,
+            group.id,
+        );
+    }
+
+    crate::stats::record_withdrawal(env, group.current_amount);
+    crate::stats::record_deposit(env, pool)?;
+
+    env.events()
+        .publish((soroban_sdk::symbol_short!("grp_done"), group.id), pool);
+
+    Ok(pool)
+}
+
+/// Moves a member's `MemberSince` time to the contribution-weighted average
+/// of their earlier funds and the `amount` being added now.
+fn record_member_since(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    prior: i128,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    let now = env.ledger().timestamp();
+    let since = if prior > 0 {
+        let total = prior.checked_add(amount).ok_or(SavingsError::Overflow)?;
+        prior
+            .checked_mul(get_member_since(env, group_id, member) as i128)
+            .and_then(|old| {
+                amount
+                    .checked_mul(now as i128)
+                    .and_then(|new| old.checked_add(new))
+            })
+            .ok_or(SavingsError::Overflow)?
+            / total
+    } else {
+        now as i128
+    };
+    env.storage().persistent().set(
+        &GroupKey::MemberSince(group_id, member.clone()),
+        &(since as u64),
+    );
+    Ok(())
+}
+
+/// Returns when a member's group funds started accruing, falling back to
+/// their plan's start time for contributions made before it was tracked.
+fn get_member_since(env: &Env, group_id: u64, member: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&GroupKey::MemberSince(group_id, member.clone()))
+        .or_else(|| {
+            env.storage()
+                .persistent()
+                .get::<DataKey, SavingsPlan>(&DataKey::SavingsPlan(member.clone(), group_id))
+                .map(|plan| plan.start_time)
+        })
+        .unwrap_or_else(|| env.ledger().timestamp())
+}
+
+/// Credits `amount` to a member's Flexi balance and total balance.
+fn credit_member_flexi(env: &Env, member: &Address, amount: i128) -> Result<(), SavingsError> {
+    crate::flexi::credit_flexi_balance(env, member, amount)?;
+
+    let user_key = DataKey::User(member.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    ttl::extend_user_ttl(env, member);
+
     Ok(())
 }
 
@@ -502,6 +970,42 @@ pub fn get_group_members(env: &Env, group_id: u64) -> Vec<Address> {
         .unwrap_or(Vec::new(env))
 }
 
+/// VIEW FUNCTION - Gets every member of a group with their recorded contribution
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group ID
+///
+/// # Returns
+/// A vector of (member, contribution) pairs in join order
+pub fn get_group_contributions(env: &Env, group_id: u64) -> Vec<(Address, i128)> {
+    let mut contributions = Vec::new(env);
+    for member in get_group_members(env, group_id).iter() {
+        let amount = get_member_contribution(env, group_id, &member);
+        contributions.push_back((member, amount));
+    }
+    contributions
+}
+
+/// VIEW FUNCTION - Checks whether a user is currently a member of a group
+///
+/// Every member has a contribution entry from the moment they join until
+/// they leave, so this is a single key lookup rather than a members scan.
+pub fn is_group_member(env: &Env, group_id: u64, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::GroupMemberContribution(group_id, user.clone()))
+}
+
+/// VIEW FUNCTION - Gets the stored member count of a group (0 if it doesn't exist)
+pub fn get_member_count(env: &Env, group_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<_, GroupSave>(&DataKey::GroupSave(group_id))
+        .map(|group| group.member_count)
+        .unwrap_or(0)
+}
+
 /// Helper function to remove a group ID from a user's list of groups.
 ///
 /// # Arguments
@@ -537,11 +1041,126 @@ fn remove_group_from_user_list(
     Ok(())
 }
 
+/// Closes a group and removes it from storage.
+///
+/// Only allowed once every member has left, or when the group has passed its
+/// end time without reaching the target. The group's strategy position is
+/// withdrawn first, then any remaining members are refunded their
+/// contributions plus a pro-rata share of the strategy yield to Flexi, and
+/// their plans, contribution entries and group index entries are removed
+/// with it.
+///
+/// # Errors
+/// * `PlanNotFound` - If the group does not exist
+/// * `Unauthorized` - If `caller` is neither the group creator nor the admin
+/// * `GroupNotEmpty` - If the group still has members and has not ended
+pub fn close_group(env: &Env, caller: Address, group_id: u64) -> Result<(), SavingsError> {
+    let group_key = DataKey::GroupSave(group_id);
+    let group: GroupSave = env
+        .storage()
+        .persistent()
+        .get(&group_key)
+        .ok_or(SavingsError::PlanNotFound)?;
+
+    if caller == group.creator {
+        caller.require_auth();
+    } else {
+        crate::config::require_admin(env, &caller)?;
+    }
+
+    let ended_incomplete = env.ledger().timestamp() >= group.end_time && !group.is_completed;
+    if group.member_count > 0 && !ended_incomplete {
+        return Err(SavingsError::GroupNotEmpty);
+    }
+
+    // Unwind the group's strategy position; its yield is shared like the pool
+    let strategy_yield = withdraw_group_from_strategy(env, group_id, group.current_amount)?;
+    crate::stats::record_deposit(env, strategy_yield)?;
+
+    let members_key = DataKey::GroupMembers(group_id);
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&members_key)
+        .unwrap_or(Vec::new(env));
+    let mut yield_paid = 0i128;
+    for member in members.iter() {
+        // The refund moves from the group into Flexi, so TVL is unchanged
+        let contribution = get_member_contribution(env, group_id, &member);
+        if contribution > 0 {
+            let yield_share = strategy_yield
+                .checked_mul(contribution)
+                .ok_or(SavingsError::Overflow)?
+                / group.current_amount;
+            yield_paid = yield_paid
+                .checked_add(yield_share)
+                .ok_or(SavingsError::Overflow)?;
+            let refund = contribution
+                .checked_add(yield_share)
+                .ok_or(SavingsError::Overflow)?;
+            credit_member_flexi(env, &member, refund)?;
+            history::record(env, &member, symbol_short!("group_wd"), refund, group_id);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GroupMemberContribution(group_id, member.clone()));
+        env.storage()
+            .persistent()
+            .remove(&GroupKey::MemberSince(group_id, member.clone()));
+        remove_member_plan(env, &member, group_id);
+        remove_group_from_user_list(env, &member, group_id)?;
+    }
+
+    let dust = strategy_yield
+        .checked_sub(yield_paid)
+        .ok_or(SavingsError::Underflow)?;
+    if dust > 0 {
+        credit_member_flexi(env, &group.creator, dust)?;
+    }
+
+    env.storage().persistent().remove(&members_key);
+    env.storage().persistent().remove(&group_key);
+
+    if group.is_public {
+        remove_from_public_index(env, group_id);
+    }
+
+    env.events()
+        .publish((symbol_short!("grp_close"), caller), group_id);
+
+    Ok(())
+}
+
+/// Deletes a member's SavingsPlan for a group, releasing its plan slot if
+/// the group had not already paid it out.
+fn remove_member_plan(env: &Env, member: &Address, group_id: u64) {
+    let plan_key = DataKey::SavingsPlan(member.clone(), group_id);
+    let plan: Option<SavingsPlan> = env.storage().persistent().get(&plan_key);
+    if plan.is_some_and(|plan| !plan.is_withdrawn) {
+        users::record_plan_closed(env, member);
+    }
+    env.storage().persistent().remove(&plan_key);
+}
+
+/// Drops a group from the public listing index, if present.
+fn remove_from_public_index(env: &Env, group_id: u64) {
+    let index_key = DataKey::PublicGroupIndex;
+    let mut index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&index_key)
+        .unwrap_or(Vec::new(env));
+    if let Some(position) = index.first_index_of(group_id) {
+        index.remove(position);
+        env.storage().persistent().set(&index_key, &index);
+    }
+}
+
 /// Allows a user to break or leave a Group Save plan before it is completed.
 ///
 /// This function handles:
 /// - Removing the user from the group member list
-/// - Refunding the user's contributions
+/// - Refunding the user's contributions, less the group break fee
 /// - Updating group state (member count, current amount)
 /// - Cleaning up all related storage entries
 ///
@@ -631,15 +1250,44 @@ pub fn break_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), S
     // Save updated group
     env.storage().persistent().set(&group_key, &group);
 
+    // Pull the member's share back from the group's strategy, with its yield
+    let refund = user_contribution
+        .checked_add(withdraw_group_from_strategy(
+            env,
+            group_id,
+            user_contribution,
+        )?)
+        .ok_or(SavingsError::Overflow)?;
+    crate::config::consume_daily_withdrawal(env, &user, refund)?;
+
+    // Leaving early is charged the group break fee (waived in emergency mode)
+    let fee_amount = crate::calculate_capped_fee(
+        env,
+        refund,
+        crate::config::get_break_fee_bps(env, ConfigKey::GroupBreakFeeBps),
+    )?;
+    let refund = refund
+        .checked_sub(fee_amount)
+        .ok_or(SavingsError::Underflow)?;
+    crate::config::accrue_break_fee(env, group_id, fee_amount)?;
+
     // Remove user's contribution entry
     env.storage().persistent().remove(&contribution_key);
+    env.storage()
+        .persistent()
+        .remove(&GroupKey::MemberSince(group_id, user.clone()));
+    env.storage().persistent().set(
+        &DataKey::GroupLeaveTime(group_id, user.clone()),
+        &env.ledger().timestamp(),
+    );
+    crate::stats::record_withdrawal(env, user_contribution);
+    history::record(env, &user, symbol_short!("group_wd"), refund, group_id);
 
     // Remove group from user's list of groups
     remove_group_from_user_list(env, &user, group_id)?;
 
     // Delete user's SavingsPlan for this group
-    let plan_key = DataKey::SavingsPlan(user.clone(), group_id);
-    env.storage().persistent().remove(&plan_key);
+    remove_member_plan(env, &user, group_id);
 
     // Extend TTL for group (still active for other members)
     ttl::extend_group_ttl(env, group_id);
@@ -647,7 +1295,7 @@ pub fn break_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), S
     // Emit event for leaving group
     env.events().publish(
         (soroban_sdk::symbol_short!("grp_leave"), user, group_id),
-        user_contribution,
+        refund,
     );
 
     Ok(())
@@ -0,0 +1,134 @@
+use crate::storage_types::{DataKey, HistoryEntry};
+use crate::ttl;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+// Per-user log of deposits and withdrawals across Flexi, Lock, Goal and
+// Group plans. The log is a bounded ring: once `HISTORY_SIZE` entries are
+// stored, each new entry evicts the oldest.
+
+/// Maximum number of entries kept per user.
+pub const HISTORY_SIZE: u32 = 50;
+
+/// Appends an entry to the user's history, evicting the oldest at capacity.
+pub(crate) fn record(env: &Env, user: &Address, kind: Symbol, amount: i128, plan_id: u64) {
+    let key = DataKey::UserHistory(user.clone());
+    let mut history: Vec<HistoryEntry> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if history.len() >= HISTORY_SIZE {
+        history.pop_front();
+    }
+    history.push_back(HistoryEntry {
+        kind,
+        amount,
+        timestamp: env.ledger().timestamp(),
+        plan_id,
+    });
+
+    env.storage().persistent().set(&key, &history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, ttl::low_threshold(env), ttl::extend_to(env));
+}
+
+/// Returns up to `limit` of the user's most recent entries, oldest first.
+pub fn get_user_history(env: &Env, user: Address, limit: u32) -> Vec<HistoryEntry> {
+    let history: Vec<HistoryEntry> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserHistory(user))
+        .unwrap_or(Vec::new(env));
+
+    let len = history.len();
+    if limit >= len {
+        return history;
+    }
+    history.slice(len - limit..len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HISTORY_SIZE;
+    use crate::{NesteraContract, NesteraContractClient};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env, Symbol,
+    };
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        (env, client, user)
+    }
+
+    #[test]
+    fn test_history_records_operations_in_order() {
+        let (env, client, user) = setup();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        client.deposit_flexi(&user, &500);
+        client.withdraw_flexi(&user, &200);
+        let lock_id = client.create_lock_save(&user, &300, &100, &false);
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "trip"), &1_000, &400);
+        client.deposit_to_goal_save(&user, &goal_id, &100);
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        client.withdraw_lock_save(&user, &lock_id);
+
+        let history = client.get_user_history(&user, &10);
+        let expected = [
+            (symbol_short!("flexi_dep"), 500, 0),
+            (symbol_short!("flexi_wd"), 200, 0),
+            (symbol_short!("lock_dep"), 300, lock_id),
+            (symbol_short!("goal_dep"), 400, goal_id),
+            (symbol_short!("goal_dep"), 100, goal_id),
+            (symbol_short!("lock_wd"), 300, lock_id),
+        ];
+        assert_eq!(history.len(), expected.len() as u32);
+        for (i, (kind, amount, plan_id)) in expected.iter().enumerate() {
+            let entry = history.get(i as u32).unwrap();
+            assert_eq!(entry.kind, *kind);
+            assert_eq!(entry.amount, *amount);
+            assert_eq!(entry.plan_id, *plan_id);
+        }
+        assert_eq!(history.get(0).unwrap().timestamp, 1_000);
+        assert_eq!(history.get(5).unwrap().timestamp, 1_100);
+
+        // A smaller limit returns the most recent entries
+        let recent = client.get_user_history(&user, &2);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent.get(0).unwrap().kind, symbol_short!("goal_dep"));
+        assert_eq!(recent.get(1).unwrap().kind, symbol_short!("lock_wd"));
+    }
+
+    #[test]
+    fn test_history_caps_at_ring_size() {
+        let (_env, client, user) = setup();
+
+        for amount in 1..=(HISTORY_SIZE as i128 + 5) {
+            client.deposit_flexi(&user, &amount);
+        }
+
+        let history = client.get_user_history(&user, &100);
+        assert_eq!(history.len(), HISTORY_SIZE);
+        // The five oldest deposits were evicted
+        assert_eq!(history.get(0).unwrap().amount, 6);
+        assert_eq!(
+            history.get(HISTORY_SIZE - 1).unwrap().amount,
+            HISTORY_SIZE as i128 + 5
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use crate::errors::SavingsError;
-// use soroban_sdk::{Address, Env};
+#[cfg(test)]
+use crate::storage_types::{DataKey, User};
+#[cfg(test)]
+use soroban_sdk::{Address, Env};
 
 /// Validates that an amount is positive.
 pub fn assert_non_negative(amount: i128) -> Result<(), SavingsError> {
@@ -26,6 +29,54 @@ pub fn assert_sufficient_balance(balance: i128, amount: i128) -> Result<(), Savi
     Ok(())
 }
 
+/// Validates that a user's recorded `total_balance` matches their holdings.
+///
+/// The expected total is the Flexi balance plus the principal of every
+/// active Lock Save and the current amount of every active Goal Save. Meant
+/// as a debug/test assertion to catch accounting drift.
+///
+/// # Errors
+/// * `UserNotFound` - If the user has not been initialized
+/// * `Overflow` - If summing the holdings overflows
+/// * `InvariantViolation` - If `total_balance` differs from the holdings
+#[cfg(test)]
+pub fn assert_balance_consistency(env: &Env, user: &Address) -> Result<(), SavingsError> {
+    let user_data: User = env
+        .storage()
+        .persistent()
+        .get(&DataKey::User(user.clone()))
+        .ok_or(SavingsError::UserNotFound)?;
+
+    let mut expected: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FlexiBalance(user.clone()))
+        .unwrap_or(0);
+    for lock_id in crate::lock::get_user_lock_saves(env, user).iter() {
+        if let Some(lock_save) = crate::lock::get_lock_save(env, lock_id) {
+            if !lock_save.is_withdrawn {
+                expected = expected
+                    .checked_add(lock_save.amount)
+                    .ok_or(SavingsError::Overflow)?;
+            }
+        }
+    }
+    for goal_id in crate::goal::get_user_goal_saves(env, user).iter() {
+        if let Some(goal_save) = crate::goal::get_goal_save(env, goal_id) {
+            if !goal_save.is_withdrawn {
+                expected = expected
+                    .checked_add(goal_save.current_amount)
+                    .ok_or(SavingsError::Overflow)?;
+            }
+        }
+    }
+
+    if user_data.total_balance != expected {
+        return Err(SavingsError::InvariantViolation);
+    }
+    Ok(())
+}
+
 // Checks if the contract is currently paused.
 // pub fn assert_not_paused(_env: &Env) -> Result<(), SavingsError> {
 //     // This assumes you have a State or Config object in storage
@@ -38,3 +89,65 @@ pub fn assert_sufficient_balance(balance: i128, amount: i128) -> Result<(), Savi
 // pub fn check_add(a: i128, b: i128) -> Result<i128, SavingsError> {
 //     a.checked_add(b).ok_or(SavingsError::Overflow)
 // }
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::SavingsError;
+    use crate::storage_types::{DataKey, User};
+    use crate::{NesteraContract, NesteraContractClient};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize(&admin, &BytesN::from_array(&env, &[1u8; 32]));
+
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &1_000);
+        client.create_lock_save(&user, &500, &86_400, &false);
+
+        (env, client, user)
+    }
+
+    #[test]
+    fn test_balance_consistency_holds_for_maintained_state() {
+        let (_env, client, user) = setup();
+        assert_eq!(client.get_user(&user).total_balance, 1_500);
+        client.assert_balance_consistency(&user);
+    }
+
+    #[test]
+    fn test_balance_consistency_detects_corrupted_total() {
+        let (env, client, user) = setup();
+        env.as_contract(&client.address, || {
+            let key = DataKey::User(user.clone());
+            let mut user_data: User = env.storage().persistent().get(&key).unwrap();
+            user_data.total_balance += 1;
+            env.storage().persistent().set(&key, &user_data);
+        });
+
+        assert_eq!(
+            client.try_assert_balance_consistency(&user),
+            Err(Ok(SavingsError::InvariantViolation))
+        );
+    }
+
+    #[test]
+    fn test_balance_consistency_detects_corrupted_flexi_balance() {
+        let (env, client, user) = setup();
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::FlexiBalance(user.clone()), &0i128);
+        });
+
+        assert_eq!(
+            client.try_assert_balance_consistency(&user),
+            Err(Ok(SavingsError::InvariantViolation))
+        );
+    }
+}
@@ -13,6 +13,7 @@ mod goal;
 mod governance;
 mod governance_events;
 mod group;
+mod history;
 mod invariants;
 mod lock;
 
@@ -27,13 +28,16 @@ mod users;
 mod security;
 
 mod rates;
+mod reentrancy;
+mod stats;
 mod views;
 
 pub use crate::config::Config;
 pub use crate::errors::SavingsError;
 pub use crate::storage_types::{
-    AutoSave, DataKey, GoalSave, GoalSaveView, GroupSave, GroupSaveView, LockSave, LockSaveView,
-    MintPayload, PlanType, SavingsPlan, User,
+    AutoSave, AutoSaveTarget, DataKey, FeeAccounting, GoalSave, GoalSaveView, GroupSave,
+    GroupSaveView, HistoryEntry, InitStatus, LockSave, LockSaveView, MintPayload, PlanType,
+    ProtocolStats, SavingsPlan, TtlConfig, User, UserPortfolio, WithdrawalReceipt,
 };
 pub use crate::strategy::registry::StrategyInfo;
 pub use crate::strategy::routing::{StrategyPosition, StrategyPositionKey};
@@ -46,6 +50,8 @@ pub enum ContractError {
     NotInitialized = 2,
     InvalidSignature = 3,
     SignatureExpired = 4,
+    NonceAlreadyUsed = 5,
+    WrongMintTarget = 6,
 }
 
 impl From<ContractError> for soroban_sdk::Error {
@@ -68,6 +74,38 @@ pub(crate) fn ensure_not_paused(env: &Env) -> Result<(), SavingsError> {
     config::require_not_paused(env)
 }
 
+/// Checks the global pause flag and then the pause flag for `op_code`.
+pub(crate) fn ensure_op_not_paused(env: &Env, op_code: u32) -> Result<(), SavingsError> {
+    ensure_not_paused(env)?;
+    config::require_op_not_paused(env, op_code)
+}
+
+/// Returns the last timestamp at which a mint payload is still valid.
+///
+/// Panics with `Overflow` if `timestamp + expiry_duration + grace_secs`
+/// does not fit in a u64.
+fn mint_payload_expiry(env: &Env, payload: &MintPayload, grace_secs: u64) -> u64 {
+    payload
+        .timestamp
+        .checked_add(payload.expiry_duration)
+        .and_then(|expiry| expiry.checked_add(grace_secs))
+        .unwrap_or_else(|| panic_with_error!(env, SavingsError::Overflow))
+}
+
+/// Marks a mint payload's (user, nonce) pair as consumed.
+///
+/// Panics with `NonceAlreadyUsed` if the pair was consumed before.
+fn consume_mint_nonce(env: &Env, payload: &MintPayload) {
+    let nonce_key = DataKey::UsedNonce(payload.user.clone(), payload.nonce);
+    if env.storage().persistent().has(&nonce_key) {
+        panic_with_error!(env, ContractError::NonceAlreadyUsed);
+    }
+    env.storage().persistent().set(&nonce_key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&nonce_key, ttl::low_threshold(env), ttl::extend_to(env));
+}
+
 pub(crate) fn calculate_fee(amount: i128, fee_bps: u32) -> Result<i128, SavingsError> {
     if fee_bps == 0 {
         return Ok(0);
@@ -83,6 +121,47 @@ pub(crate) fn calculate_fee(amount: i128, fee_bps: u32) -> Result<i128, SavingsE
     Ok(total / 10_000)
 }
 
+/// Calculates the deposit fee for `amount` using the configured fee tiers.
+///
+/// Selects the bps of the highest tier whose threshold is `<= amount`. If no
+/// tiers are configured, or `amount` is below the lowest threshold, the flat
+/// `PlatformFee` applies.
+pub(crate) fn calculate_tiered_fee(env: &Env, amount: i128) -> Result<i128, SavingsError> {
+    let mut fee_bps: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PlatformFee)
+        .unwrap_or(0);
+
+    for (threshold, tier_bps) in config::get_fee_tiers(env).iter() {
+        if threshold > amount {
+            break;
+        }
+        fee_bps = tier_bps;
+    }
+
+    calculate_capped_fee(env, amount, fee_bps)
+}
+
+/// Calculates the fee for `amount` at `fee_bps`, clamped to the configured
+/// per-transaction `MaxFeeAbsolute` when one is set.
+pub(crate) fn calculate_capped_fee(
+    env: &Env,
+    amount: i128,
+    fee_bps: u32,
+) -> Result<i128, SavingsError> {
+    let fee = calculate_fee(amount, fee_bps).map_err(|err| match err {
+        SavingsError::Overflow => errors::overflow(env, "fee_mul"),
+        err => err,
+    })?;
+    let max_fee = config::get_max_fee(env);
+    if max_fee > 0 && fee > max_fee {
+        Ok(max_fee)
+    } else {
+        Ok(fee)
+    }
+}
+
 #[cfg(test)]
 mod fee_tests {
     use super::calculate_fee;
@@ -128,6 +207,23 @@ mod fee_tests {
         // 0.01% (1 basis point) of 1,000,000 = 100
         assert_eq!(calculate_fee(1_000_000, 1).unwrap(), 100);
     }
+
+    #[test]
+    fn test_fee_overflow_logs_diagnostic() {
+        use super::{calculate_capped_fee, NesteraContract, SavingsError};
+        use soroban_sdk::{testutils::Logs, Env};
+
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+
+        let result = env.as_contract(&contract_id, || calculate_capped_fee(&env, i128::MAX, 100));
+        assert_eq!(result, Err(SavingsError::Overflow));
+
+        let logs = env.logs().all();
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("overflow") && log.contains("fee_mul")));
+    }
 }
 
 #[contractimpl]
@@ -137,6 +233,11 @@ impl NesteraContract {
         governance::get_user_voted_proposals(&env, user)
     }
 
+    /// Returns (proposals created, voting power, proposals voted on) for a user
+    pub fn get_user_governance_summary(env: Env, user: Address) -> (u32, u128, u32) {
+        governance::get_user_governance_summary(&env, user)
+    }
+
     /// Returns all active (non-executed, within voting period) proposal IDs
     pub fn get_active_proposals(env: Env) -> Vec<u64> {
         governance::get_active_proposals(&env)
@@ -146,6 +247,11 @@ impl NesteraContract {
     pub fn get_proposal_votes(env: Env, proposal_id: u64) -> (u128, u128, u128) {
         governance::get_proposal_votes(&env, proposal_id)
     }
+
+    /// Returns the proposal's status: 0=active, 1=passing, 2=failing, 3=queued, 4=executed, 5=defeated
+    pub fn get_proposal_status(env: Env, proposal_id: u64) -> Result<u32, SavingsError> {
+        governance::get_proposal_status(&env, proposal_id)
+    }
     /// Initialize a new user in the system
     pub fn init_user(env: Env, user: Address) -> User {
         ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
@@ -175,12 +281,30 @@ impl NesteraContract {
             .publish((symbol_short!("init"),), admin_public_key);
     }
 
+    /// Initializes the contract, global config and rewards config in a single
+    /// transaction. Panics (reverting every step) if any part fails.
+    pub fn bootstrap(
+        env: Env,
+        admin: Address,
+        admin_public_key: BytesN<32>,
+        treasury: Address,
+        protocol_fee_bps: u32,
+        rewards_config: rewards::storage_types::RewardsConfig,
+    ) {
+        Self::initialize(env.clone(), admin, admin_public_key);
+        config::store_initial_config(&env, treasury, protocol_fee_bps)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        rewards::config::initialize_rewards_config(&env, rewards_config)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+    }
+
     pub fn verify_signature(env: Env, payload: MintPayload, signature: BytesN<64>) -> bool {
         if !env.storage().instance().has(&DataKey::Initialized) {
             panic_with_error!(&env, ContractError::NotInitialized);
         }
         let current_timestamp = env.ledger().timestamp();
-        let expiry_time = payload.timestamp + payload.expiry_duration;
+        let expiry_time =
+            mint_payload_expiry(&env, &payload, config::get_signature_grace_secs(&env));
         if current_timestamp > expiry_time {
             panic_with_error!(&env, ContractError::SignatureExpired);
         }
@@ -196,13 +320,98 @@ impl NesteraContract {
     }
 
     pub fn mint(env: Env, payload: MintPayload, signature: BytesN<64>) -> i128 {
+        if payload.to_flexi {
+            panic_with_error!(&env, ContractError::WrongMintTarget);
+        }
         Self::verify_signature(env.clone(), payload.clone(), signature);
+
+        // Consume the nonce only once the signature has been verified
+        consume_mint_nonce(&env, &payload);
+
         let amount = payload.amount;
+        stats::record_mint(&env, &payload.user, amount)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         env.events()
             .publish((symbol_short!("mint"), payload.user), amount);
         amount
     }
 
+    /// Verifies a signed mint payload and credits `payload.amount` to the
+    /// user's Flexi balance, initializing the user if needed. Returns the
+    /// new Flexi balance.
+    ///
+    /// The payload must be signed with `to_flexi` set, so a payload issued for
+    /// `mint` cannot be redeemed as a deposit instead.
+    pub fn mint_to_flexi(env: Env, payload: MintPayload, signature: BytesN<64>) -> i128 {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        if !payload.to_flexi {
+            panic_with_error!(&env, ContractError::WrongMintTarget);
+        }
+        Self::verify_signature(env.clone(), payload.clone(), signature);
+        consume_mint_nonce(&env, &payload);
+
+        let new_balance = flexi::credit_minted_flexi(&env, &payload.user, payload.amount)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        stats::record_mint(&env, &payload.user, payload.amount)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        env.events()
+            .publish((symbol_short!("mint"), payload.user), payload.amount);
+        new_balance
+    }
+
+    /// Mints several payloads covered by one admin signature over the XDR of the whole vector.
+    ///
+    /// Every payload must be unexpired, carry an unused nonce and not be
+    /// marked `to_flexi`; if any check fails the whole batch reverts. Returns
+    /// the total amount minted.
+    pub fn batch_mint(env: Env, payloads: Vec<MintPayload>, signature: BytesN<64>) -> i128 {
+        if !env.storage().instance().has(&DataKey::Initialized) {
+            panic_with_error!(&env, ContractError::NotInitialized);
+        }
+        let admin_public_key: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminPublicKey)
+            .expect("Admin PK not found");
+        let payloads_bytes: Bytes = payloads.clone().to_xdr(&env);
+        env.crypto()
+            .ed25519_verify(&admin_public_key, &payloads_bytes, &signature);
+
+        let current_timestamp = env.ledger().timestamp();
+        let grace_secs = config::get_signature_grace_secs(&env);
+        let mut total: i128 = 0;
+        for payload in payloads.iter() {
+            if payload.to_flexi {
+                panic_with_error!(&env, ContractError::WrongMintTarget);
+            }
+            let expiry_time = mint_payload_expiry(&env, &payload, grace_secs);
+            if current_timestamp > expiry_time {
+                panic_with_error!(&env, ContractError::SignatureExpired);
+            }
+            consume_mint_nonce(&env, &payload);
+
+            total = total
+                .checked_add(payload.amount)
+                .unwrap_or_else(|| panic_with_error!(&env, SavingsError::Overflow));
+            stats::record_mint(&env, &payload.user, payload.amount)
+                .unwrap_or_else(|e| panic_with_error!(&env, e));
+            env.events()
+                .publish((symbol_short!("mint"), payload.user), payload.amount);
+        }
+        total
+    }
+
+    /// Returns the lifetime sum of all minted amounts.
+    pub fn get_total_minted(env: Env) -> i128 {
+        stats::get_total_minted(&env)
+    }
+
+    /// Returns the lifetime sum of amounts minted to `user`.
+    pub fn get_minted_by_user(env: Env, user: Address) -> i128 {
+        stats::get_minted_by_user(&env, &user)
+    }
+
     pub fn is_initialized(env: Env) -> bool {
         env.storage().instance().has(&DataKey::Initialized)
     }
@@ -214,7 +423,7 @@ impl NesteraContract {
         initial_deposit: i128,
     ) -> Result<u64, SavingsError> {
         // 1. CHECKS
-        ensure_not_paused(&env)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
         invariants::assert_non_negative(initial_deposit)?;
 
         rewards::storage::award_deposit_points(&env, user.clone(), initial_deposit)?;
@@ -222,6 +431,7 @@ impl NesteraContract {
         if !Self::is_initialized(env.clone()) {
             return Err(SavingsError::InternalError);
         }
+        users::ensure_plan_capacity(&env, &user)?;
 
         let mut user_data = Self::get_user(env.clone(), user.clone()).unwrap_or(User {
             total_balance: 0,
@@ -232,12 +442,12 @@ impl NesteraContract {
         user_data.savings_count = user_data
             .savings_count
             .checked_add(1)
-            .ok_or(SavingsError::Overflow)?;
+            .ok_or_else(|| errors::overflow(&env, "plan_count_add"))?;
 
         user_data.total_balance = user_data
             .total_balance
             .checked_add(initial_deposit)
-            .ok_or(SavingsError::Overflow)?;
+            .ok_or_else(|| errors::overflow(&env, "balance_add"))?;
 
         let plan_id = user_data.savings_count as u64;
 
@@ -260,6 +470,8 @@ impl NesteraContract {
         env.storage()
             .persistent()
             .set(&DataKey::SavingsPlan(user.clone(), plan_id), &new_plan);
+        users::record_plan_opened(&env, &user)?;
+        stats::record_deposit(&env, initial_deposit)?;
 
         // 3. INTERACTIONS (Events)
         env.events().publish(
@@ -281,47 +493,263 @@ impl NesteraContract {
         users::initialize_user(&env, user)
     }
 
+    /// Lets deposits register unknown users on the fly (admin only; off by default)
+    pub fn set_auto_init_users(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), SavingsError> {
+        users::set_auto_init_users(&env, admin, enabled)
+    }
+
+    /// Returns whether deposits register unknown users automatically
+    pub fn get_auto_init_users(env: Env) -> bool {
+        users::get_auto_init_users(&env)
+    }
+
     pub fn user_exists(env: Env, user: Address) -> bool {
         users::user_exists(&env, &user)
     }
 
+    /// Returns up to `limit` of the user's most recent deposits and withdrawals, oldest first.
+    pub fn get_user_history(env: Env, user: Address, limit: u32) -> Vec<HistoryEntry> {
+        history::get_user_history(&env, user, limit)
+    }
+
+    /// Returns the user's aggregated holdings across every savings product.
+    pub fn get_user_portfolio(env: Env, user: Address) -> Result<UserPortfolio, SavingsError> {
+        views::get_user_portfolio(&env, user)
+    }
+
+    /// Returns which setup steps (init, configs, governance, pause) are in place.
+    pub fn get_init_status(env: Env) -> InitStatus {
+        views::get_init_status(&env)
+    }
+
+    /// Returns up to `limit` of the user's savings plans, skipping the first `offset`.
+    pub fn get_user_savings_plans_paged(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<SavingsPlan> {
+        views::get_user_savings_plans_paged(&env, user, offset, limit)
+    }
+
+    /// Returns the number of savings plans the user has created.
+    pub fn get_user_plan_count(env: Env, user: Address) -> u64 {
+        views::get_user_plan_count(&env, &user)
+    }
+
+    /// Returns protocol-wide totals: users, value locked, proposals and fees.
+    pub fn get_protocol_stats(env: Env) -> ProtocolStats {
+        stats::get_protocol_stats(&env)
+    }
+
+    /// Returns lifetime fees collected and withdrawn, and the outstanding balance.
+    pub fn get_fee_accounting(env: Env) -> FeeAccounting {
+        stats::get_fee_accounting(&env)
+    }
+
     pub fn deposit_flexi(env: Env, user: Address, amount: i128) -> Result<(), SavingsError> {
-        ensure_not_paused(&env)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
         flexi::flexi_deposit(env, user, amount)
     }
 
     pub fn withdraw_flexi(env: Env, user: Address, amount: i128) -> Result<(), SavingsError> {
-        ensure_not_paused(&env)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)?;
         flexi::flexi_withdraw(env, user, amount)
     }
 
+    /// Sets the Flexi withdrawal cooldown after a deposit, in seconds (admin only)
+    pub fn set_flexi_withdraw_cooldown(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), SavingsError> {
+        flexi::set_flexi_withdraw_cooldown(&env, admin, seconds)
+    }
+
+    /// Returns the Flexi withdrawal cooldown in seconds (0 = none)
+    pub fn get_flexi_withdraw_cooldown(env: Env) -> u64 {
+        flexi::get_flexi_withdraw_cooldown(&env)
+    }
+
     pub fn get_flexi_balance(env: Env, user: Address) -> i128 {
         flexi::get_flexi_balance(&env, user).unwrap_or(0)
     }
 
     // --- Lock Save Logic ---
 
-    pub fn create_lock_save(env: Env, user: Address, amount: i128, duration: u64) -> u64 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+    /// Creates a Lock Save; `compound` selects yearly compounding over simple interest.
+    pub fn create_lock_save(
+        env: Env,
+        user: Address,
+        amount: i128,
+        duration: u64,
+        compound: bool,
+    ) -> u64 {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         user.require_auth();
-        lock::create_lock_save(&env, user, amount, duration)
+        lock::create_lock_save(&env, user, amount, duration, compound)
             .unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
     pub fn withdraw_lock_save(env: Env, user: Address, lock_id: u64) -> i128 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         user.require_auth();
         lock::withdraw_lock_save(&env, user, lock_id).unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
+    /// Withdraws every matured Lock Save the user owns and returns the total paid out.
+    pub fn withdraw_all_matured_locks(env: Env, user: Address) -> i128 {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        user.require_auth();
+        lock::withdraw_all_matured_locks(&env, user).unwrap_or_else(|e| panic_with_error!(&env, e))
+    }
+
+    /// Breaks a Lock Save before maturity and returns the gross/fee/net breakdown.
+    pub fn break_lock_save(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<WithdrawalReceipt, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)?;
+        user.require_auth();
+        lock::break_lock_save(&env, user, lock_id)
+    }
+
+    /// Rolls a matured Lock Save (principal plus yield) into a new lock; returns the new lock ID.
+    pub fn relock(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        new_duration: u64,
+    ) -> Result<u64, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
+        user.require_auth();
+        lock::relock(&env, user, lock_id, new_duration)
+    }
+
+    /// Withdraws a matured Lock Save (principal plus yield) into the user's Flexi balance.
+    pub fn withdraw_lock_to_flexi(env: Env, user: Address, lock_id: u64) -> i128 {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        user.require_auth();
+        lock::withdraw_lock_to_flexi(&env, user, lock_id)
+            .unwrap_or_else(|e| panic_with_error!(&env, e))
+    }
+
+    /// Moves `amount` from the user's Flexi balance into a new lock; returns the lock ID.
+    pub fn lock_from_flexi(
+        env: Env,
+        user: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<u64, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
+        user.require_auth();
+        lock::lock_from_flexi(&env, user, amount, duration)
+    }
+
+    /// Splits `split_amount` off a lock into a new lock with the same terms; returns the new lock ID.
+    pub fn split_lock_save(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        split_amount: i128,
+    ) -> Result<u64, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
+        user.require_auth();
+        lock::split_lock_save(&env, user, lock_id, split_amount)
+    }
+
+    /// Projects the bonus points (long-lock plus maturity) a lock is worth at maturity.
+    pub fn project_lock_rewards(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<u128, SavingsError> {
+        lock::project_lock_rewards(&env, user, lock_id)
+    }
+
+    /// Merges `lock_id_b` into `lock_id_a` when both share maturity and interest rate.
+    pub fn merge_lock_saves(
+        env: Env,
+        user: Address,
+        lock_id_a: u64,
+        lock_id_b: u64,
+    ) -> Result<(), SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
+        user.require_auth();
+        lock::merge_lock_saves(&env, user, lock_id_a, lock_id_b)
+    }
+
+    /// Sets the min and max Lock Save durations in seconds; 0 leaves that end unbounded (admin only).
+    pub fn set_lock_duration_bounds(
+        env: Env,
+        admin: Address,
+        min_duration: u64,
+        max_duration: u64,
+    ) -> Result<(), SavingsError> {
+        lock::set_lock_duration_bounds(&env, admin, min_duration, max_duration)
+    }
+
+    /// Returns the (min, max) Lock Save durations in seconds (0 = unbounded).
+    pub fn get_lock_duration_bounds(env: Env) -> (u64, u64) {
+        lock::get_lock_duration_bounds(&env)
+    }
+
+    /// Sets or clears the strategy new Lock Save deposits are routed into (admin only).
+    pub fn set_default_lock_strategy(
+        env: Env,
+        admin: Address,
+        strategy_address: Option<Address>,
+    ) -> Result<(), SavingsError> {
+        lock::set_default_lock_strategy(&env, admin, strategy_address)
+    }
+
+    /// Returns the strategy new Lock Save deposits are routed into, if any.
+    pub fn get_default_lock_strategy(env: Env) -> Option<Address> {
+        lock::get_default_lock_strategy(&env)
+    }
+
     pub fn check_matured_lock(env: Env, lock_id: u64) -> bool {
         lock::check_matured_lock(&env, lock_id)
     }
 
+    /// Returns (would succeed, reason code) for withdrawing a lock, without side effects
+    pub fn can_withdraw_lock(env: Env, user: Address, lock_id: u64) -> (bool, u32) {
+        lock::can_withdraw_lock(&env, &user, lock_id)
+    }
+
+    /// Returns seconds until a lock matures (negative once matured)
+    pub fn time_to_maturity(env: Env, lock_id: u64) -> Result<i64, SavingsError> {
+        lock::time_to_maturity(&env, lock_id)
+    }
+
+    /// Returns a lock's elapsed progress toward maturity in bps (capped at 10_000)
+    pub fn maturity_percentage(env: Env, lock_id: u64) -> Result<u32, SavingsError> {
+        lock::maturity_percentage(&env, lock_id)
+    }
+
     pub fn get_user_lock_saves(env: Env, user: Address) -> Vec<u64> {
         lock::get_user_lock_saves(&env, &user)
     }
 
+    /// Returns the user's Lock Saves that have not been withdrawn.
+    pub fn get_active_lock_saves(env: Env, user: Address) -> Vec<LockSave> {
+        lock::get_active_lock_saves(&env, &user)
+    }
+
+    /// Returns IDs of the user's matured Lock Saves that are ready to withdraw.
+    pub fn get_matured_lock_saves(env: Env, user: Address) -> Vec<u64> {
+        lock::get_matured_lock_saves(&env, &user)
+    }
+
     // ========== Goal Save Functions ==========
 
     pub fn create_goal_save(
@@ -331,28 +759,115 @@ impl NesteraContract {
         target_amount: i128,
         initial_deposit: i128,
     ) -> u64 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         goal::create_goal_save(&env, user, goal_name, target_amount, initial_deposit)
             .unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
     pub fn deposit_to_goal_save(env: Env, user: Address, goal_id: u64, amount: i128) {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         goal::deposit_to_goal_save(&env, user, goal_id, amount)
             .unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
-    pub fn withdraw_completed_goal_save(env: Env, user: Address, goal_id: u64) -> i128 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+    /// Returns (would succeed, reason code) for withdrawing a goal, without side effects
+    pub fn can_withdraw_goal(env: Env, user: Address, goal_id: u64) -> (bool, u32) {
+        goal::can_withdraw_goal(&env, &user, goal_id)
+    }
+
+    /// Withdraws a completed Goal Save and returns the gross/fee/net breakdown.
+    pub fn withdraw_completed_goal_save(
+        env: Env,
+        user: Address,
+        goal_id: u64,
+    ) -> WithdrawalReceipt {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         goal::withdraw_completed_goal_save(&env, user, goal_id)
             .unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
-    pub fn break_goal_save(env: Env, user: Address, goal_id: u64) -> i128 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+    /// Withdraws part of a completed goal's surplus above its target.
+    pub fn withdraw_goal_surplus(
+        env: Env,
+        user: Address,
+        goal_id: u64,
+        amount: i128,
+    ) -> WithdrawalReceipt {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
+        goal::withdraw_goal_surplus(&env, user, goal_id, amount)
+            .unwrap_or_else(|e| panic_with_error!(&env, e))
+    }
+
+    /// Breaks a Goal Save early and returns the gross/fee/net breakdown.
+    pub fn break_goal_save(env: Env, user: Address, goal_id: u64) -> WithdrawalReceipt {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)
+            .unwrap_or_else(|e| panic_with_error!(&env, e));
         goal::break_goal_save(&env, user, goal_id).unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
+    /// Sets the deadline after which an unfinished goal can be swept into Flexi
+    pub fn set_goal_deadline(
+        env: Env,
+        user: Address,
+        goal_id: u64,
+        deadline: u64,
+    ) -> Result<(), SavingsError> {
+        goal::set_goal_deadline(&env, user, goal_id, deadline)
+    }
+
+    /// Sets a recurring Flexi-to-goal contribution; an interval of 0 clears it
+    pub fn set_goal_contribution_schedule(
+        env: Env,
+        user: Address,
+        goal_id: u64,
+        interval: u64,
+        amount: i128,
+    ) -> Result<(), SavingsError> {
+        goal::set_goal_contribution_schedule(&env, user, goal_id, interval, amount)
+    }
+
+    /// Makes a goal's scheduled contribution if due; returns `false` when skipped
+    pub fn execute_goal_contribution(env: Env, goal_id: u64) -> Result<bool, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
+        goal::execute_goal_contribution(&env, goal_id)
+    }
+
+    /// Moves `amount` between two of the user's open goals without a fee
+    pub fn transfer_between_goals(
+        env: Env,
+        user: Address,
+        from_goal: u64,
+        to_goal: u64,
+        amount: i128,
+    ) -> Result<(), SavingsError> {
+        goal::transfer_between_goals(&env, user, from_goal, to_goal, amount)
+    }
+
+    /// Returns the goal's deadline, if one has been set
+    pub fn get_goal_deadline(env: Env, goal_id: u64) -> Option<u64> {
+        goal::get_goal_deadline(&env, goal_id)
+    }
+
+    /// Moves an overdue, unmet goal's balance into the owner's Flexi balance, fee-free
+    pub fn sweep_overdue_goal(env: Env, user: Address, goal_id: u64) -> Result<i128, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)?;
+        goal::sweep_overdue_goal(&env, user, goal_id)
+    }
+
+    /// Returns a Goal Save with its progress in basis points and remaining amount
+    pub fn get_goal_view(env: Env, goal_id: u64) -> Result<GoalSaveView, SavingsError> {
+        views::get_goal_view(&env, goal_id)
+    }
+
+    /// Returns a Lock Save with its time to maturity and projected yield
+    pub fn get_lock_view(env: Env, lock_id: u64) -> Result<LockSaveView, SavingsError> {
+        views::get_lock_view(&env, lock_id)
+    }
+
     pub fn get_goal_save_detail(env: Env, goal_id: u64) -> GoalSave {
         goal::get_goal_save(&env, goal_id)
             .unwrap_or_else(|| panic_with_error!(&env, SavingsError::PlanNotFound))
@@ -404,33 +919,125 @@ impl NesteraContract {
         group_id: u64,
         amount: i128,
     ) -> Result<(), SavingsError> {
-        ensure_not_paused(&env)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
         group::contribute_to_group_save(&env, user, group_id, amount)
     }
 
+    /// Pays members' shares into Flexi automatically once the target is reached (creator only)
+    pub fn set_group_auto_distribute(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        enabled: bool,
+    ) -> Result<(), SavingsError> {
+        group::set_group_auto_distribute(&env, creator, group_id, enabled)
+    }
+
+    /// Caps the number of members a group accepts, 0 for unlimited (creator only)
+    pub fn set_group_max_members(
+        env: Env,
+        creator: Address,
+        group_id: u64,
+        max_members: u32,
+    ) -> Result<(), SavingsError> {
+        group::set_group_max_members(&env, creator, group_id, max_members)
+    }
+
+    /// Sets or clears the strategy Group Save contributions are routed into (admin only).
+    pub fn set_default_group_strategy(
+        env: Env,
+        admin: Address,
+        strategy_address: Option<Address>,
+    ) -> Result<(), SavingsError> {
+        group::set_default_group_strategy(&env, admin, strategy_address)
+    }
+
+    /// Returns the strategy Group Save contributions are routed into, if any.
+    pub fn get_default_group_strategy(env: Env) -> Option<Address> {
+        group::get_default_group_strategy(&env)
+    }
+
+    /// Sets the wait in seconds before a user may rejoin a group they left (admin only).
+    pub fn set_group_join_cooldown(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), SavingsError> {
+        group::set_group_join_cooldown(&env, admin, seconds)
+    }
+
+    /// Returns the group rejoin cooldown in seconds (0 = none).
+    pub fn get_group_join_cooldown(env: Env) -> u64 {
+        group::get_group_join_cooldown(&env)
+    }
+
+    /// Lists public groups in creation order, `limit` at a time from `offset`
+    pub fn list_public_groups(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<GroupSaveView>, SavingsError> {
+        views::list_public_groups(&env, offset, limit)
+    }
+
     pub fn break_group_save(env: Env, user: Address, group_id: u64) -> Result<(), SavingsError> {
-        ensure_not_paused(&env)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_WITHDRAW)?;
         group::break_group_save(&env, user, group_id)
     }
 
+    /// Removes an empty or ended-and-incomplete group (creator or admin)
+    pub fn close_group(env: Env, caller: Address, group_id: u64) -> Result<(), SavingsError> {
+        group::close_group(&env, caller, group_id)
+    }
+
+    /// Returns true if `user` is currently a member of the group
+    pub fn is_group_member(env: Env, group_id: u64, user: Address) -> bool {
+        group::is_group_member(&env, group_id, &user)
+    }
+
+    /// Returns every member of a group paired with their recorded contribution
+    pub fn get_group_contributions(env: Env, group_id: u64) -> Vec<(Address, i128)> {
+        group::get_group_contributions(&env, group_id)
+    }
+
+    /// Returns the number of members in a group
+    pub fn get_member_count(env: Env, group_id: u64) -> u32 {
+        group::get_member_count(&env, group_id)
+    }
+
     // --- Admin Control Functions ---
 
+    /// Kept for existing callers; proposes `new_admin` exactly like `propose_admin`
     pub fn set_admin(
         env: Env,
         current_admin: Address,
         new_admin: Address,
     ) -> Result<(), SavingsError> {
-        current_admin.require_auth();
-        let stored_admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
-        if let Some(admin) = stored_admin {
-            if admin != current_admin {
-                return Err(SavingsError::Unauthorized);
-            }
-        }
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-        env.events()
-            .publish((symbol_short!("set_admin"),), new_admin);
-        Ok(())
+        config::propose_admin(&env, current_admin, new_admin)
+    }
+
+    /// Proposes a new admin; takes effect once they call `accept_admin`
+    pub fn propose_admin(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), SavingsError> {
+        config::propose_admin(&env, current_admin, new_admin)
+    }
+
+    /// Completes a pending admin transfer (proposed address only)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), SavingsError> {
+        config::accept_admin(&env, new_admin)
+    }
+
+    /// Cancels a pending admin transfer (admin only)
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), SavingsError> {
+        config::cancel_admin_transfer(&env, admin)
+    }
+
+    /// Returns the address awaiting acceptance as admin, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        config::get_pending_admin(&env)
     }
 
     pub fn set_flexi_rate(env: Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
@@ -454,6 +1061,18 @@ impl NesteraContract {
         rates::set_lock_rate(&env, caller, duration_days, rate)
     }
 
+    /// Overrides the interest rate of a single user plan (admin only)
+    pub fn set_plan_rate(
+        env: Env,
+        admin: Address,
+        user: Address,
+        plan_type: PlanType,
+        plan_id: u64,
+        rate: i128,
+    ) -> Result<(), SavingsError> {
+        rates::set_plan_rate(&env, admin, user, plan_type, plan_id, rate)
+    }
+
     pub fn set_early_break_fee_bps(env: Env, bps: u32) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -467,6 +1086,25 @@ impl NesteraContract {
         Ok(())
     }
 
+    /// Sets the Goal Save break fee, overriding the shared early-break fee (admin or governance)
+    pub fn set_goal_break_fee_bps(env: Env, caller: Address, bps: u32) -> Result<(), SavingsError> {
+        config::set_goal_break_fee_bps(&env, caller, bps)
+    }
+
+    /// Sets the Lock Save break fee, overriding the shared early-break fee (admin or governance)
+    pub fn set_lock_break_fee_bps(env: Env, caller: Address, bps: u32) -> Result<(), SavingsError> {
+        config::set_lock_break_fee_bps(&env, caller, bps)
+    }
+
+    /// Sets the Group Save leave fee, overriding the shared early-break fee (admin or governance)
+    pub fn set_group_break_fee_bps(
+        env: Env,
+        caller: Address,
+        bps: u32,
+    ) -> Result<(), SavingsError> {
+        config::set_group_break_fee_bps(&env, caller, bps)
+    }
+
     pub fn set_fee_recipient(env: Env, recipient: Address) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -493,6 +1131,7 @@ impl NesteraContract {
         governance::validate_admin_or_governance(&env, &caller)?;
 
         env.storage().persistent().set(&DataKey::Paused, &true);
+        config::clear_auto_unpause(&env);
         ttl::extend_config_ttl(&env, &DataKey::Paused);
         env.events().publish((symbol_short!("pause"), caller), ());
         Ok(())
@@ -503,6 +1142,7 @@ impl NesteraContract {
         governance::validate_admin_or_governance(&env, &caller)?;
 
         env.storage().persistent().set(&DataKey::Paused, &false);
+        config::clear_auto_unpause(&env);
         ttl::extend_config_ttl(&env, &DataKey::Paused);
         env.events().publish((symbol_short!("unpause"), caller), ());
         Ok(())
@@ -575,6 +1215,7 @@ impl NesteraContract {
                     let amount = lock.amount;
                     lock.is_withdrawn = true;
                     env.storage().persistent().set(&lock_key, &lock);
+                    users::record_plan_closed(&env, &user);
 
                     // Update user total balance
                     let user_key = DataKey::User(user.clone());
@@ -601,6 +1242,7 @@ impl NesteraContract {
                     let amount = goal.current_amount;
                     goal.is_withdrawn = true;
                     env.storage().persistent().set(&goal_key, &goal);
+                    users::record_plan_closed(&env, &user);
 
                     // Update user total balance
                     let user_key = DataKey::User(user.clone());
@@ -657,6 +1299,8 @@ impl NesteraContract {
             }
         };
 
+        stats::record_withdrawal(&env, withdrawn_amount);
+
         // 4. Mark strategy as disabled
         env.storage().persistent().set(&disabled_key, &true);
         ttl::extend_config_ttl(&env, &disabled_key);
@@ -688,7 +1332,7 @@ impl NesteraContract {
 
     pub fn is_paused(env: Env) -> bool {
         let paused_key = DataKey::Paused;
-        let is_paused = env.storage().persistent().get(&paused_key).unwrap_or(false);
+        let is_paused = config::is_paused(&env);
 
         // Extend TTL on read (only if the key exists)
         if env.storage().persistent().has(&paused_key) {
@@ -732,13 +1376,43 @@ impl NesteraContract {
             .unwrap_or(0)
     }
 
+    /// Estimates the fee on `amount` for a fee kind (0 = protocol deposit, 1 = goal break,
+    /// 2 = lock break, 3 = group break).
+    pub fn estimate_fee(env: Env, amount: i128, fee_kind: u32) -> Result<i128, SavingsError> {
+        config::estimate_fee(&env, amount, fee_kind)
+    }
+
+    /// Returns the protocol fees accrued to `recipient`, separate from any savings they hold.
     pub fn get_protocol_fee_balance(env: Env, recipient: Address) -> i128 {
         env.storage()
             .persistent()
-            .get(&DataKey::TotalBalance(recipient))
+            .get(&DataKey::FeeAccrued(recipient))
             .unwrap_or(0)
     }
 
+    /// Withdraws `amount` of accrued protocol fees (recipient or admin only)
+    pub fn withdraw_protocol_fees(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), SavingsError> {
+        config::withdraw_protocol_fees(&env, caller, recipient, amount)
+    }
+
+    /// Withdraws all accrued protocol fees and returns the amount (recipient or admin only)
+    pub fn withdraw_all_protocol_fees(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+    ) -> Result<i128, SavingsError> {
+        let amount = Self::get_protocol_fee_balance(env.clone(), recipient.clone());
+        if amount > 0 {
+            config::withdraw_protocol_fees(&env, caller, recipient, amount)?;
+        }
+        Ok(amount)
+    }
+
     // ========== Rewards Functions ==========
 
     pub fn init_rewards_config(
@@ -775,6 +1449,9 @@ impl NesteraContract {
             action_cooldown_seconds,
             max_daily_points,
             max_streak_multiplier,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
 
         rewards::config::initialize_rewards_config(&env, config)
@@ -807,10 +1484,54 @@ impl NesteraContract {
         rewards::config::get_rewards_config(&env)
     }
 
+    /// Returns the rewards config, or a disabled all-zero config if none is stored
+    pub fn get_rewards_config_or_default(env: Env) -> rewards::storage_types::RewardsConfig {
+        rewards::config::get_rewards_config_or_default(&env)
+    }
+
+    /// Scales the goal completion bonus to `bps` of the target; 0 keeps the flat bonus (admin only)
+    pub fn set_goal_completion_bonus_bps(
+        env: Env,
+        admin: Address,
+        bps: u32,
+    ) -> Result<(), SavingsError> {
+        rewards::config::set_goal_completion_bonus_bps(&env, admin, bps)
+    }
+
+    /// Returns the goal completion bonus in bps of target (0 = flat bonus)
+    pub fn get_goal_completion_bonus_bps(env: Env) -> u32 {
+        rewards::config::get_goal_completion_bonus_bps(&env)
+    }
+
+    /// Opens a promotional window earning extra `multiplier_bps` of deposit points (admin only)
+    pub fn set_bonus_window(
+        env: Env,
+        admin: Address,
+        start: u64,
+        end: u64,
+        multiplier_bps: u32,
+    ) -> Result<(), SavingsError> {
+        rewards::config::set_bonus_window(&env, admin, start, end, multiplier_bps)
+    }
+
+    /// Returns the promotional deposit-points window, if one is set
+    pub fn get_bonus_window(env: Env) -> Option<rewards::storage_types::BonusWindow> {
+        rewards::config::get_bonus_window(&env)
+    }
+
     pub fn get_user_rewards(env: Env, user: Address) -> rewards::storage_types::UserRewards {
         rewards::storage::get_user_rewards(&env, user)
     }
 
+    /// Previews (base_points, streak_bonus_points) a deposit of `amount` would earn
+    pub fn preview_deposit_rewards(
+        env: Env,
+        user: Address,
+        amount: i128,
+    ) -> Result<(u128, u128), SavingsError> {
+        rewards::storage::preview_deposit_rewards(&env, user, amount)
+    }
+
     pub fn update_streak(env: Env, user: Address) -> Result<u32, SavingsError> {
         user.require_auth();
         rewards::storage::update_streak(&env, user)
@@ -848,6 +1569,17 @@ impl NesteraContract {
         rewards::redemption::redeem_points(&env, user, amount)
     }
 
+    /// Redeems points for a lock-rate boost lasting `duration_days`; returns the boost in bps
+    pub fn redeem_for_rate_boost(
+        env: Env,
+        user: Address,
+        points: u128,
+        duration_days: u64,
+    ) -> Result<u32, SavingsError> {
+        user.require_auth();
+        rewards::redemption::redeem_for_rate_boost(&env, user, points, duration_days)
+    }
+
     // ========== AutoSave Functions ==========
 
     /// Creates a new AutoSave schedule for recurring Flexi deposits
@@ -862,18 +1594,58 @@ impl NesteraContract {
         autosave::create_autosave(&env, user, amount, interval_seconds, start_time)
     }
 
-    /// Executes an AutoSave schedule if it's due
-    pub fn execute_autosave(env: Env, schedule_id: u64) -> Result<(), SavingsError> {
+    /// Creates a new AutoSave schedule that deposits into a Flexi, Goal or Lock plan
+    pub fn create_targeted_autosave(
+        env: Env,
+        user: Address,
+        amount: i128,
+        interval_seconds: u64,
+        start_time: u64,
+        target: AutoSaveTarget,
+    ) -> Result<u64, SavingsError> {
         ensure_not_paused(&env)?;
+        autosave::create_targeted_autosave(&env, user, amount, interval_seconds, start_time, target)
+    }
+
+    /// Executes an AutoSave schedule if it's due; returns `false` when not yet due
+    pub fn execute_autosave(env: Env, schedule_id: u64) -> Result<bool, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
         autosave::execute_autosave(&env, schedule_id)
     }
 
     /// Batch-executes multiple due AutoSave schedules in a single call.
     /// Returns a Vec<bool> indicating success (true) or skip/failure (false) per schedule.
-    pub fn execute_due_autosaves(env: Env, schedule_ids: Vec<u64>) -> Vec<bool> {
+    /// Batches larger than the configured max batch size are rejected.
+    pub fn execute_due_autosaves(
+        env: Env,
+        schedule_ids: Vec<u64>,
+    ) -> Result<Vec<bool>, SavingsError> {
+        config::require_op_not_paused(&env, config::PAUSE_OP_DEPOSIT)?;
         autosave::execute_due_autosaves(&env, schedule_ids)
     }
 
+    /// Previews the next execution of a schedule as `(gross, net_after_fee)`
+    pub fn preview_autosave_execution(
+        env: Env,
+        schedule_id: u64,
+    ) -> Result<(i128, i128), SavingsError> {
+        autosave::preview_autosave_execution(&env, schedule_id)
+    }
+
+    /// Sets the maximum number of schedules per `execute_due_autosaves` call (admin only)
+    pub fn set_autosave_max_batch_size(
+        env: Env,
+        admin: Address,
+        max_batch_size: u32,
+    ) -> Result<(), SavingsError> {
+        autosave::set_max_batch_size(&env, admin, max_batch_size)
+    }
+
+    /// Gets the maximum number of schedules per `execute_due_autosaves` call
+    pub fn get_autosave_max_batch_size(env: Env) -> u32 {
+        autosave::get_max_batch_size(&env)
+    }
+
     /// Cancels an AutoSave schedule
     pub fn cancel_autosave(env: Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
         ensure_not_paused(&env)?;
@@ -925,24 +1697,176 @@ impl NesteraContract {
         config::set_protocol_fee(&env, admin, new_fee_bps)
     }
 
+    /// Sets the deposit fee tiers as (threshold, bps) pairs (admin only)
+    pub fn set_fee_tiers(
+        env: Env,
+        admin: Address,
+        tiers: Vec<(i128, u32)>,
+    ) -> Result<(), SavingsError> {
+        config::set_fee_tiers(&env, admin, tiers)
+    }
+
+    /// Returns the configured deposit fee tiers
+    pub fn get_fee_tiers(env: Env) -> Vec<(i128, u32)> {
+        config::get_fee_tiers(&env)
+    }
+
+    /// Sets the maximum absolute fee per transaction; 0 removes the cap (admin only)
+    pub fn set_max_fee(env: Env, admin: Address, max_fee: i128) -> Result<(), SavingsError> {
+        config::set_max_fee(&env, admin, max_fee)
+    }
+
+    /// Returns the maximum absolute fee per transaction (0 = uncapped)
+    pub fn get_max_fee(env: Env) -> i128 {
+        config::get_max_fee(&env)
+    }
+
+    /// Sets the most a user may withdraw per day; 0 removes the limit (admin only)
+    pub fn set_daily_withdraw_limit(
+        env: Env,
+        admin: Address,
+        limit: i128,
+    ) -> Result<(), SavingsError> {
+        config::set_daily_withdraw_limit(&env, admin, limit)
+    }
+
+    /// Returns the per-user daily withdrawal limit (0 = no limit)
+    pub fn get_daily_withdraw_limit(env: Env) -> i128 {
+        config::get_daily_withdraw_limit(&env)
+    }
+
+    pub fn set_max_plans_per_user(
+        env: Env,
+        admin: Address,
+        max_plans: u32,
+    ) -> Result<(), SavingsError> {
+        config::set_max_plans_per_user(&env, admin, max_plans)
+    }
+
+    /// Returns the maximum open plans per user (0 = unlimited)
+    pub fn get_max_plans_per_user(env: Env) -> u32 {
+        config::get_max_plans_per_user(&env)
+    }
+
+    /// Sets the grace seconds allowed past a signed payload's expiry (admin only)
+    pub fn set_signature_grace_secs(
+        env: Env,
+        admin: Address,
+        grace_secs: u64,
+    ) -> Result<(), SavingsError> {
+        config::set_signature_grace_secs(&env, admin, grace_secs)
+    }
+
+    /// Returns the grace seconds allowed past a signed payload's expiry
+    pub fn get_signature_grace_secs(env: Env) -> u64 {
+        config::get_signature_grace_secs(&env)
+    }
+
     /// Pauses the contract via config module (admin only)
     pub fn pause_contract(env: Env, admin: Address) -> Result<(), SavingsError> {
         config::pause_contract(&env, admin)
     }
 
+    /// Pauses the contract and lifts the pause automatically after `duration` seconds (admin only)
+    pub fn pause_contract_with_timeout(
+        env: Env,
+        admin: Address,
+        duration: u64,
+    ) -> Result<(), SavingsError> {
+        config::pause_contract_with_timeout(&env, admin, duration)
+    }
+
+    /// Returns when the current pause lifts itself (0 = manual unpause only)
+    pub fn get_auto_unpause_at(env: Env) -> u64 {
+        config::get_auto_unpause_at(&env)
+    }
+
     /// Unpauses the contract via config module (admin only)
     pub fn unpause_contract(env: Env, admin: Address) -> Result<(), SavingsError> {
         config::unpause_contract(&env, admin)
     }
 
-    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
-        upgrade::upgrade_contract(&env, admin, new_wasm_hash);
+    /// Pauses one operation scope (`config::PAUSE_OP_*`) while leaving the rest live
+    pub fn pause_op(env: Env, caller: Address, op_code: u32) -> Result<(), SavingsError> {
+        config::pause_op(&env, caller, op_code)
+    }
+
+    /// Lifts an operation-scoped pause
+    pub fn unpause_op(env: Env, caller: Address, op_code: u32) -> Result<(), SavingsError> {
+        config::unpause_op(&env, caller, op_code)
+    }
+
+    /// Returns whether an operation scope is paused (ignores the global pause)
+    pub fn is_op_paused(env: Env, op_code: u32) -> bool {
+        config::is_op_paused(&env, op_code)
+    }
+
+    /// Enables emergency mode: deposits blocked, withdrawal fees waived (admin or governance)
+    pub fn enable_emergency_mode(env: Env, caller: Address) -> Result<(), SavingsError> {
+        config::enable_emergency_mode(&env, caller)
+    }
+
+    /// Disables emergency mode (admin or governance)
+    pub fn disable_emergency_mode(env: Env, caller: Address) -> Result<(), SavingsError> {
+        config::disable_emergency_mode(&env, caller)
+    }
+
+    /// Returns whether emergency mode is on
+    pub fn is_emergency_mode(env: Env) -> bool {
+        config::is_emergency_mode(&env)
+    }
+
+    /// Overrides the storage TTL thresholds used for extensions (admin only)
+    pub fn set_ttl_config(
+        env: Env,
+        admin: Address,
+        low_threshold: u32,
+        extend_to: u32,
+        extend_archived: u32,
+    ) -> Result<(), SavingsError> {
+        ttl::set_ttl_config(&env, admin, low_threshold, extend_to, extend_archived)
+    }
+
+    /// Returns the TTL thresholds currently in effect
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        ttl::get_ttl_config(&env)
+    }
+
+    /// Extends the storage TTL of a user's `lock`, `goal` or `group` plan
+    pub fn bump_plan_ttl(
+        env: Env,
+        user: Address,
+        plan_kind: Symbol,
+        plan_id: u64,
+    ) -> Result<(), SavingsError> {
+        ttl::bump_plan_ttl(&env, user, plan_kind, plan_id)
+    }
+
+    /// Swaps in new WASM and records `new_version`, which must be above the
+    /// current version (admin only)
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+    ) -> Result<(), SavingsError> {
+        upgrade::upgrade_contract(&env, admin, new_wasm_hash, new_version)
     }
 
     pub fn version(env: Env) -> u32 {
         upgrade::get_version(&env)
     }
 
+    /// Returns every (version, timestamp) recorded by `upgrade`
+    pub fn get_version_history(env: Env) -> Vec<(u32, u64)> {
+        upgrade::get_version_history(&env)
+    }
+
+    /// Runs data migrations for the current version, once per version (admin only)
+    pub fn migrate(env: Env, admin: Address) -> Result<(), SavingsError> {
+        upgrade::migrate(&env, admin)
+    }
+
     // ========== Governance Functions ==========
 
     /// Initializes voting configuration (admin only)
@@ -974,19 +1898,25 @@ impl NesteraContract {
     pub fn create_proposal(
         env: Env,
         creator: Address,
+        title: String,
+        category: String,
         description: String,
     ) -> Result<u64, SavingsError> {
-        governance::create_proposal(&env, creator, description)
+        config::require_op_not_paused(&env, config::PAUSE_OP_GOVERNANCE)?;
+        governance::create_proposal(&env, creator, title, category, description)
     }
 
     /// Creates a governance proposal with an action
     pub fn create_action_proposal(
         env: Env,
         creator: Address,
+        title: String,
+        category: String,
         description: String,
         action: governance::ProposalAction,
     ) -> Result<u64, SavingsError> {
-        governance::create_action_proposal(&env, creator, description, action)
+        config::require_op_not_paused(&env, config::PAUSE_OP_GOVERNANCE)?;
+        governance::create_action_proposal(&env, creator, title, category, description, action)
     }
 
     /// Gets a proposal by ID
@@ -1004,18 +1934,43 @@ impl NesteraContract {
         governance::list_proposals(&env)
     }
 
+    /// Lists a page of proposal IDs with the given status code (see `get_proposal_status`)
+    pub fn list_proposals_by_status(
+        env: Env,
+        status_code: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        governance::list_proposals_by_status(&env, status_code, offset, limit)
+    }
+
     /// Gets the voting power for a user based on their lifetime deposited funds
     pub fn get_voting_power(env: Env, user: Address) -> u128 {
         governance::get_voting_power(&env, &user)
     }
 
-    /// Casts a weighted vote on a proposal
+    /// Sets the inactivity halflife for voting power decay (admin only, 0 disables)
+    pub fn set_voting_power_halflife(
+        env: Env,
+        admin: Address,
+        halflife_secs: u64,
+    ) -> Result<(), SavingsError> {
+        governance::set_voting_power_halflife(&env, admin, halflife_secs)
+    }
+
+    /// Gets the voting power decay halflife in seconds
+    pub fn get_voting_power_halflife(env: Env) -> u64 {
+        governance::get_voting_power_halflife(&env)
+    }
+
+    /// Casts a vote weighted by the voter's power when the proposal was created
     pub fn vote(
         env: Env,
         proposal_id: u64,
         vote_type: u32,
         voter: Address,
     ) -> Result<(), SavingsError> {
+        config::require_op_not_paused(&env, config::PAUSE_OP_GOVERNANCE)?;
         governance::vote(&env, proposal_id, vote_type, voter)
     }
 
@@ -1024,13 +1979,30 @@ impl NesteraContract {
         governance::has_voted(&env, proposal_id, &voter)
     }
 
+    /// Casts `(proposal_id, vote_type)` votes under one authorization; returns per-vote success
+    pub fn batch_vote(
+        env: Env,
+        votes: Vec<(u64, u32)>,
+        voter: Address,
+    ) -> Result<Vec<bool>, SavingsError> {
+        config::require_op_not_paused(&env, config::PAUSE_OP_GOVERNANCE)?;
+        Ok(governance::batch_vote(&env, votes, voter))
+    }
+
+    /// Returns the voting power snapshotted for a voter on a proposal
+    pub fn get_vote_weight(env: Env, proposal_id: u64, voter: Address) -> u128 {
+        governance::get_vote_weight(&env, proposal_id, &voter)
+    }
+
     /// Queues a proposal for execution after timelock
     pub fn queue_proposal(env: Env, proposal_id: u64) -> Result<(), SavingsError> {
+        config::require_op_not_paused(&env, config::PAUSE_OP_GOVERNANCE)?;
         governance::queue_proposal(&env, proposal_id)
     }
 
     /// Executes a queued proposal after timelock period
     pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), SavingsError> {
+        config::require_op_not_paused(&env, config::PAUSE_OP_GOVERNANCE)?;
         governance::execute_proposal(&env, proposal_id)
     }
 
@@ -1065,6 +2037,35 @@ impl NesteraContract {
         strategy::registry::disable_strategy(&env, caller, strategy_address)
     }
 
+    /// Enables or disables a registered strategy for new deposits (admin/governance only).
+    pub fn set_strategy_enabled(
+        env: Env,
+        caller: Address,
+        strategy_address: Address,
+        enabled: bool,
+    ) -> Result<(), SavingsError> {
+        strategy::registry::set_strategy_enabled(&env, caller, strategy_address, enabled)
+    }
+
+    /// Removes a strategy with no routed principal from the registry (admin/governance only).
+    pub fn deregister_strategy(
+        env: Env,
+        caller: Address,
+        strategy_address: Address,
+    ) -> Result<(), SavingsError> {
+        strategy::registry::deregister_strategy(&env, caller, strategy_address)
+    }
+
+    /// Caps the total principal routed into a strategy; 0 removes the cap (admin/governance only).
+    pub fn set_strategy_cap(
+        env: Env,
+        caller: Address,
+        strategy_address: Address,
+        cap: i128,
+    ) -> Result<(), SavingsError> {
+        strategy::registry::set_strategy_cap(&env, caller, strategy_address, cap)
+    }
+
     /// Returns info about a registered strategy.
     pub fn get_strategy(env: Env, strategy_address: Address) -> Result<StrategyInfo, SavingsError> {
         strategy::registry::get_strategy(&env, strategy_address)
@@ -1075,7 +2076,17 @@ impl NesteraContract {
         strategy::registry::get_all_strategies(&env)
     }
 
-    /// Routes a LockSave deposit to a yield strategy.
+    /// Returns the APY (bps) reported by a registered strategy.
+    pub fn get_strategy_apy(env: Env, strategy_address: Address) -> Result<u32, SavingsError> {
+        strategy::routing::get_strategy_apy(&env, strategy_address)
+    }
+
+    /// Returns the enabled strategy reporting the highest APY, if any.
+    pub fn get_best_strategy(env: Env) -> Option<Address> {
+        strategy::routing::get_best_strategy(&env)
+    }
+
+    /// Routes a LockSave deposit to a yield strategy (admin only).
     pub fn route_lock_to_strategy(
         env: Env,
         caller: Address,
@@ -1083,13 +2094,13 @@ impl NesteraContract {
         strategy_address: Address,
         amount: i128,
     ) -> Result<i128, SavingsError> {
-        caller.require_auth();
-        ensure_not_paused(&env)?;
+        config::require_admin(&env, &caller)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
         let position_key = StrategyPositionKey::Lock(lock_id);
         strategy::routing::route_to_strategy(&env, strategy_address, position_key, amount)
     }
 
-    /// Routes a GroupSave pooled deposit to a yield strategy.
+    /// Routes a GroupSave pooled deposit to a yield strategy (admin only).
     pub fn route_group_to_strategy(
         env: Env,
         caller: Address,
@@ -1097,12 +2108,50 @@ impl NesteraContract {
         strategy_address: Address,
         amount: i128,
     ) -> Result<i128, SavingsError> {
-        caller.require_auth();
-        ensure_not_paused(&env)?;
+        config::require_admin(&env, &caller)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
         let position_key = StrategyPositionKey::Group(group_id);
         strategy::routing::route_to_strategy(&env, strategy_address, position_key, amount)
     }
 
+    /// Splits a LockSave deposit across several yield strategies by weight in bps (admin only).
+    pub fn route_lock_to_strategies(
+        env: Env,
+        caller: Address,
+        lock_id: u64,
+        allocations: Vec<(Address, u32)>,
+        amount: i128,
+    ) -> Result<i128, SavingsError> {
+        config::require_admin(&env, &caller)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        let position_key = StrategyPositionKey::Lock(lock_id);
+        strategy::routing::route_to_strategies(&env, allocations, position_key, amount)
+    }
+
+    /// Splits a GroupSave pooled deposit across several yield strategies by weight in bps (admin only).
+    pub fn route_group_to_strategies(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        allocations: Vec<(Address, u32)>,
+        amount: i128,
+    ) -> Result<i128, SavingsError> {
+        config::require_admin(&env, &caller)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        let position_key = StrategyPositionKey::Group(group_id);
+        strategy::routing::route_to_strategies(&env, allocations, position_key, amount)
+    }
+
+    /// Returns the multi-strategy sub-positions for a lock plan.
+    pub fn get_lock_strategy_positions(env: Env, lock_id: u64) -> Option<Vec<StrategyPosition>> {
+        strategy::routing::get_positions(&env, StrategyPositionKey::Lock(lock_id))
+    }
+
+    /// Returns the multi-strategy sub-positions for a group plan.
+    pub fn get_group_strategy_positions(env: Env, group_id: u64) -> Option<Vec<StrategyPosition>> {
+        strategy::routing::get_positions(&env, StrategyPositionKey::Group(group_id))
+    }
+
     /// Returns the strategy position for a lock plan.
     pub fn get_lock_strategy_position(env: Env, lock_id: u64) -> Option<StrategyPosition> {
         strategy::routing::get_position(&env, StrategyPositionKey::Lock(lock_id))
@@ -1113,28 +2162,52 @@ impl NesteraContract {
         strategy::routing::get_position(&env, StrategyPositionKey::Group(group_id))
     }
 
-    /// Withdraws funds from a lock's strategy position.
+    /// Withdraws funds from a lock's strategy position, reverting if less than `min_out` returns.
+    ///
+    /// Admin only; lock owners are paid out through the lock's own withdraw path.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If `caller` is not the admin
+    /// * `AmountBelowMinimum` - If the strategy returns less than `min_out`
     pub fn withdraw_lock_strategy(
         env: Env,
         caller: Address,
         lock_id: u64,
         to: Address,
+        min_out: i128,
     ) -> Result<i128, SavingsError> {
-        caller.require_auth();
-        ensure_not_paused(&env)?;
-        strategy::routing::withdraw_from_strategy(&env, StrategyPositionKey::Lock(lock_id), to)
+        config::require_admin(&env, &caller)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        strategy::routing::withdraw_from_strategy(
+            &env,
+            StrategyPositionKey::Lock(lock_id),
+            to,
+            min_out,
+        )
     }
 
-    /// Withdraws funds from a group's strategy position.
+    /// Withdraws funds from a group's strategy position, reverting if less than `min_out` returns.
+    ///
+    /// Admin only; members are paid out through the group's own close and break paths.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If `caller` is not the admin
+    /// * `AmountBelowMinimum` - If the strategy returns less than `min_out`
     pub fn withdraw_group_strategy(
         env: Env,
         caller: Address,
         group_id: u64,
         to: Address,
+        min_out: i128,
     ) -> Result<i128, SavingsError> {
-        caller.require_auth();
-        ensure_not_paused(&env)?;
-        strategy::routing::withdraw_from_strategy(&env, StrategyPositionKey::Group(group_id), to)
+        config::require_admin(&env, &caller)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        strategy::routing::withdraw_from_strategy(
+            &env,
+            StrategyPositionKey::Group(group_id),
+            to,
+            min_out,
+        )
     }
 
     /// Harvests yield from a yield strategy.
@@ -1149,10 +2222,88 @@ impl NesteraContract {
         strategy_address: Address,
     ) -> Result<i128, SavingsError> {
         caller.require_auth();
-        ensure_not_paused(&env)?;
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
         strategy::routing::harvest_strategy(&env, strategy_address)
     }
 
+    /// Harvests every registered strategy, skipping any whose harvest fails.
+    ///
+    /// Returns `(strategy, actual_yield)` for each strategy harvested.
+    pub fn harvest_all_strategies(env: Env) -> Result<Vec<(Address, i128)>, SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        Ok(strategy::routing::harvest_all_strategies(&env))
+    }
+
+    /// Harvests yield from every strategy backing a lock plan's position.
+    pub fn harvest_lock_strategies(
+        env: Env,
+        caller: Address,
+        lock_id: u64,
+    ) -> Result<i128, SavingsError> {
+        caller.require_auth();
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        strategy::routing::harvest_position_strategies(&env, StrategyPositionKey::Lock(lock_id))
+    }
+
+    /// Harvests yield from every strategy backing a group plan's position.
+    pub fn harvest_group_strategies(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+    ) -> Result<i128, SavingsError> {
+        caller.require_auth();
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        strategy::routing::harvest_position_strategies(&env, StrategyPositionKey::Group(group_id))
+    }
+
+    /// Claims a lock's pro-rata share of harvested strategy yield into the owner's Flexi balance.
+    pub fn claim_strategy_yield(
+        env: Env,
+        user: Address,
+        position_key: StrategyPositionKey,
+    ) -> Result<i128, SavingsError> {
+        user.require_auth();
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        strategy::routing::claim_strategy_yield(&env, user, position_key)
+    }
+
+    /// Returns the harvested yield a strategy position has not yet been paid.
+    pub fn get_pending_strategy_yield(env: Env, position_key: StrategyPositionKey) -> i128 {
+        strategy::routing::get_pending_yield(&env, position_key)
+    }
+
+    /// Withdraws a strategy's full balance regardless of recorded principal (admin only).
+    pub fn emergency_withdraw_strategy(
+        env: Env,
+        admin: Address,
+        strategy_address: Address,
+        to: Address,
+    ) -> Result<i128, SavingsError> {
+        strategy::routing::emergency_withdraw_strategy(&env, admin, strategy_address, to)
+    }
+
+    /// Overwrites a position's recorded principal after an incident (admin only).
+    pub fn force_reconcile_position(
+        env: Env,
+        admin: Address,
+        position_key: StrategyPositionKey,
+        actual_amount: i128,
+    ) -> Result<i128, SavingsError> {
+        strategy::routing::force_reconcile_position(&env, admin, position_key, actual_amount)
+    }
+
+    /// Moves principal from one yield strategy to another (admin only).
+    pub fn rebalance_strategy(
+        env: Env,
+        admin: Address,
+        from_strategy: Address,
+        to_strategy: Address,
+        amount: i128,
+    ) -> Result<(), SavingsError> {
+        ensure_op_not_paused(&env, config::PAUSE_OP_STRATEGY)?;
+        strategy::routing::rebalance_strategy(&env, admin, from_strategy, to_strategy, amount)
+    }
+
     /// Returns the total principal that Nestera has deposited into a given strategy.
     ///
     /// This is the sum of all routed deposits minus all withdrawals.
@@ -1170,6 +2321,20 @@ impl NesteraContract {
             .get(&DataKey::StrategyYield(strategy_address))
             .unwrap_or(0)
     }
+
+    /// Returns the principal paid out from contract funds because a strategy failed to return it.
+    pub fn get_strategy_shortfall(env: Env, strategy_address: Address) -> i128 {
+        strategy::routing::get_shortfall(&env, strategy_address)
+    }
+}
+
+#[cfg(test)]
+#[contractimpl]
+impl NesteraContract {
+    /// Test-only: checks that a user's `total_balance` matches their holdings.
+    pub fn assert_balance_consistency(env: Env, user: Address) -> Result<(), SavingsError> {
+        invariants::assert_balance_consistency(&env, &user)
+    }
 }
 
 #[cfg(test)]
@@ -1181,6 +2346,8 @@ mod execution_tests;
 #[cfg(test)]
 mod governance_tests;
 #[cfg(test)]
+mod mint_tests;
+#[cfg(test)]
 mod rates_test;
 #[cfg(test)]
 mod test;
@@ -1,17 +1,38 @@
+use crate::config;
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
-use crate::rewards::storage;
-use crate::storage_types::{DataKey, LockSave, User};
+use crate::history;
+use crate::rates;
+use crate::rewards::{redemption, storage};
+use crate::storage_types::{ConfigKey, DataKey, LockSave, User, WithdrawalReceipt};
+use crate::strategy::{registry, routing, routing::StrategyPositionKey};
 use crate::ttl;
 use crate::users;
-use soroban_sdk::{symbol_short, Address, Env, Vec};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 /// Creates a new Lock Save plan for a user
+///
+/// With `compound` set, interest compounds once per elapsed year; otherwise
+/// the lock accrues simple interest.
 pub fn create_lock_save(
     env: &Env,
     user: Address,
     amount: i128,
     duration: u64,
+    compound: bool,
+) -> Result<u64, SavingsError> {
+    open_lock_save(env, user, amount, duration, compound, true)
+}
+
+/// Opens a lock for `amount`. Base deposit points are only awarded for new
+/// money (`award_deposit`); the long-lock bonus applies either way.
+fn open_lock_save(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    duration: u64,
+    compound: bool,
+    award_deposit: bool,
 ) -> Result<u64, SavingsError> {
     ensure_not_paused(env)?;
     // Note: user.require_auth() is already called in lib.rs wrapper function
@@ -24,11 +45,16 @@ pub fn create_lock_save(
         // Aligned with the test expectation of a generic invalid duration error
         return Err(SavingsError::InvalidTimestamp);
     }
+    let (min_duration, max_duration) = get_lock_duration_bounds(env);
+    if duration < min_duration || (max_duration > 0 && duration > max_duration) {
+        return Err(SavingsError::InvalidTimestamp);
+    }
 
     // Ensure user exists using your users module
     if !users::user_exists(env, &user) {
         return Err(SavingsError::UserNotFound);
     }
+    users::ensure_plan_capacity(env, &user)?;
 
     // ID Logic
     let lock_id = get_next_lock_id(env);
@@ -43,10 +69,13 @@ pub fn create_lock_save(
         id: lock_id,
         owner: user.clone(),
         amount,
-        interest_rate: 500, // Matching your test expectation of 500 (5%)
+        interest_rate: rates::resolve_lock_rate(env, duration)?
+            .checked_add(redemption::active_rate_boost_bps(env, &user))
+            .ok_or(SavingsError::Overflow)?,
         start_time,
         maturity_time,
         is_withdrawn: false,
+        compound,
     };
 
     // Store the LockSave
@@ -56,6 +85,7 @@ pub fn create_lock_save(
 
     // Update user's lock list
     add_lock_to_user(env, &user, lock_id);
+    users::record_plan_opened(env, &user)?;
 
     // Update user's profile stats
     let user_key = DataKey::User(user.clone());
@@ -63,8 +93,12 @@ pub fn create_lock_save(
     user_data.total_balance += amount;
     user_data.savings_count += 1;
     env.storage().persistent().set(&user_key, &user_data);
+    crate::stats::record_deposit(env, amount)?;
+    history::record(env, &user, symbol_short!("lock_dep"), amount, lock_id);
 
-    storage::award_deposit_points(env, user.clone(), amount)?;
+    if award_deposit {
+        storage::award_deposit_points(env, user.clone(), amount)?;
+    }
     storage::award_long_lock_bonus(env, user.clone(), amount, duration)?;
 
     // Extend TTL for new lock save and user data
@@ -72,6 +106,75 @@ pub fn create_lock_save(
     ttl::extend_user_ttl(env, &user);
     ttl::extend_user_plan_list_ttl(env, &DataKey::UserLockSaves(user.clone()));
 
+    // Route locked funds into the default strategy, if one is configured and enabled
+    if let Some(strategy_address) = get_default_lock_strategy(env) {
+        let enabled = registry::get_strategy(env, strategy_address.clone())
+            .map(|info| info.enabled)
+            .unwrap_or(false);
+        if enabled {
+            routing::route_to_strategy(
+                env,
+                strategy_address,
+                StrategyPositionKey::Lock(lock_id),
+                amount,
+            )?;
+        }
+    }
+
+    Ok(lock_id)
+}
+
+/// Moves `amount` from the user's Flexi balance into a new lock.
+///
+/// No deposit fee or base deposit points apply since the funds are already
+/// saved; the long-lock bonus is awarded as for any new lock.
+///
+/// # Returns
+/// The ID of the new lock
+///
+/// # Errors
+/// * `InvalidAmount` - If `amount` <= 0
+/// * `InsufficientBalance` - If the Flexi balance is below `amount`
+/// * Any error from creating the lock
+pub fn lock_from_flexi(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    duration: u64,
+) -> Result<u64, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let flexi_balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    if flexi_balance < amount {
+        return Err(SavingsError::InsufficientBalance);
+    }
+    env.storage()
+        .persistent()
+        .set(&flexi_key, &(flexi_balance - amount));
+
+    // The lock adds the amount back to total_balance and the deposit stats
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_sub(amount)
+            .ok_or(SavingsError::Underflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    crate::stats::record_withdrawal(env, amount);
+    history::record(env, &user, symbol_short!("flexi_wd"), amount, 0);
+
+    let lock_id = open_lock_save(env, user.clone(), amount, duration, false, false)?;
+
+    env.events()
+        .publish((Symbol::new(env, "flexi2lock"), user, lock_id), amount);
+
     Ok(lock_id)
 }
 
@@ -93,12 +196,17 @@ pub fn withdraw_lock_save(env: &Env, user: Address, lock_id: u64) -> Result<i128
         return Err(SavingsError::TooEarly);
     }
 
-    let final_amount = calculate_lock_save_yield(&lock_save, env.ledger().timestamp());
+    let mut final_amount = calculate_lock_save_yield(&lock_save, env.ledger().timestamp());
+    final_amount = final_amount
+        .checked_add(withdraw_lock_from_strategy(env, lock_id)?)
+        .ok_or(SavingsError::Overflow)?;
+    config::consume_daily_withdrawal(env, &user, final_amount)?;
 
     lock_save.is_withdrawn = true;
     env.storage()
         .persistent()
         .set(&DataKey::LockSave(lock_id), &lock_save);
+    users::record_plan_closed(env, &user);
 
     // Update user's total balance (subtracting the locked portion)
     let user_key = DataKey::User(user.clone());
@@ -106,6 +214,9 @@ pub fn withdraw_lock_save(env: &Env, user: Address, lock_id: u64) -> Result<i128
         user_data.total_balance -= lock_save.amount;
         env.storage().persistent().set(&user_key, &user_data);
     }
+    crate::stats::record_withdrawal(env, lock_save.amount);
+    history::record(env, &user, symbol_short!("lock_wd"), final_amount, lock_id);
+    storage::award_maturity_bonus(env, user.clone(), lock_save.amount)?;
 
     // Extend TTL (completed locks get shorter extension)
     ttl::extend_lock_ttl(env, lock_id);
@@ -117,6 +228,678 @@ pub fn withdraw_lock_save(env: &Env, user: Address, lock_id: u64) -> Result<i128
     Ok(final_amount)
 }
 
+/// Withdraws every matured, not-yet-withdrawn Lock Save owned by `user`.
+///
+/// Immature and already-withdrawn locks are skipped. Each withdrawal emits its
+/// own `withdraw` event, followed by one `bulk_wd` summary event.
+///
+/// # Returns
+/// The total amount returned across all withdrawn locks (0 if none matured)
+///
+/// # Errors
+/// * `Overflow` - If the running total overflows
+pub fn withdraw_all_matured_locks(env: &Env, user: Address) -> Result<i128, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    let matured = get_matured_lock_saves(env, &user);
+    let mut total: i128 = 0;
+    for lock_id in matured.iter() {
+        let amount = withdraw_lock_save(env, user.clone(), lock_id)?;
+        total = total.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    }
+
+    env.events()
+        .publish((symbol_short!("bulk_wd"), user), (matured.len(), total));
+
+    Ok(total)
+}
+
+/// Withdraws a matured Lock Save straight into the owner's Flexi balance.
+///
+/// The full matured amount (principal plus yield, including any strategy
+/// yield) is credited to Flexi instead of leaving the contract.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The lock owner
+/// * `lock_id` - The lock to withdraw
+///
+/// # Returns
+/// The amount credited to the Flexi balance
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+/// * `Unauthorized` - If `user` does not own the lock
+/// * `PlanCompleted` - If the lock has already been withdrawn
+/// * `TooEarly` - If the lock has not matured yet
+pub fn withdraw_lock_to_flexi(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+) -> Result<i128, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    let mut lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    if lock_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    if !check_matured_lock(env, lock_id) {
+        return Err(SavingsError::TooEarly);
+    }
+
+    let final_amount = calculate_lock_save_yield(&lock_save, env.ledger().timestamp())
+        .checked_add(withdraw_lock_from_strategy(env, lock_id)?)
+        .ok_or(SavingsError::Overflow)?;
+
+    lock_save.is_withdrawn = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id), &lock_save);
+    users::record_plan_closed(env, &user);
+
+    crate::flexi::credit_flexi_balance(env, &user, final_amount)?;
+
+    // The locked principal moves out and the matured amount lands in Flexi
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_sub(lock_save.amount)
+            .and_then(|balance| balance.checked_add(final_amount))
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    crate::stats::record_withdrawal(env, lock_save.amount);
+    crate::stats::record_deposit(env, final_amount)?;
+    history::record(env, &user, symbol_short!("lock_wd"), final_amount, lock_id);
+    storage::award_maturity_bonus(env, user.clone(), lock_save.amount)?;
+
+    ttl::extend_lock_ttl(env, lock_id);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events().publish(
+        (Symbol::new(env, "lock2flexi"), user, lock_id),
+        final_amount,
+    );
+
+    Ok(final_amount)
+}
+
+/// Breaks a Lock Save before maturity, paying out its principal less the
+/// lock break fee.
+///
+/// Lock interest is forfeited; yield earned in a strategy is still returned.
+/// The fee falls back to the shared early-break fee when no lock-specific fee
+/// is set, and is waived in emergency mode.
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+/// * `Unauthorized` - If `user` does not own the lock
+/// * `PlanCompleted` - If the lock has already been withdrawn
+/// * `TooLate` - If the lock has matured; use `withdraw_lock_save` instead
+pub fn break_lock_save(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+) -> Result<WithdrawalReceipt, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    let mut lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    if lock_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    if check_matured_lock(env, lock_id) {
+        return Err(SavingsError::TooLate);
+    }
+
+    let gross_amount = lock_save
+        .amount
+        .checked_add(withdraw_lock_from_strategy(env, lock_id)?)
+        .ok_or(SavingsError::Overflow)?;
+    config::consume_daily_withdrawal(env, &user, gross_amount)?;
+
+    let fee_bps = config::get_break_fee_bps(env, ConfigKey::LockBreakFeeBps);
+    let fee_amount = crate::calculate_capped_fee(env, gross_amount, fee_bps)?;
+    let net_amount = gross_amount
+        .checked_sub(fee_amount)
+        .ok_or(SavingsError::Underflow)?;
+
+    lock_save.is_withdrawn = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id), &lock_save);
+    users::record_plan_closed(env, &user);
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_sub(lock_save.amount)
+            .ok_or(SavingsError::Underflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    crate::stats::record_withdrawal(env, lock_save.amount);
+    history::record(env, &user, symbol_short!("lock_wd"), net_amount, lock_id);
+    config::accrue_break_fee(env, lock_id, fee_amount)?;
+
+    ttl::extend_lock_ttl(env, lock_id);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events()
+        .publish((symbol_short!("lock_brk"), user, lock_id), net_amount);
+
+    Ok(WithdrawalReceipt {
+        gross: gross_amount,
+        fee: fee_amount,
+        net: net_amount,
+        plan_id: lock_id,
+        plan_kind: symbol_short!("lock"),
+    })
+}
+
+/// Rolls a matured Lock Save over into a new lock for `new_duration`.
+///
+/// The matured amount (principal plus yield, including any strategy yield)
+/// becomes the new lock's principal and the old lock is marked withdrawn.
+/// The new lock keeps the old lock's compounding choice. The maturity bonus
+/// is awarded for the old lock and the long-lock bonus for the new one.
+///
+/// # Returns
+/// The ID of the new lock
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+/// * `Unauthorized` - If `user` does not own the lock
+/// * `PlanCompleted` - If the lock has already been withdrawn
+/// * `TooEarly` - If the lock has not matured yet
+/// * Any error from creating the new lock
+pub fn relock(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+    new_duration: u64,
+) -> Result<u64, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    let mut lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    if lock_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    if !check_matured_lock(env, lock_id) {
+        return Err(SavingsError::TooEarly);
+    }
+
+    let final_amount = calculate_lock_save_yield(&lock_save, env.ledger().timestamp())
+        .checked_add(withdraw_lock_from_strategy(env, lock_id)?)
+        .ok_or(SavingsError::Overflow)?;
+
+    lock_save.is_withdrawn = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id), &lock_save);
+    users::record_plan_closed(env, &user);
+
+    // The new lock adds the matured amount back to total_balance and the stats
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_sub(lock_save.amount)
+            .ok_or(SavingsError::Underflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    crate::stats::record_withdrawal(env, lock_save.amount);
+    history::record(env, &user, symbol_short!("lock_wd"), final_amount, lock_id);
+    storage::award_maturity_bonus(env, user.clone(), lock_save.amount)?;
+    ttl::extend_lock_ttl(env, lock_id);
+
+    let new_lock_id = open_lock_save(
+        env,
+        user.clone(),
+        final_amount,
+        new_duration,
+        lock_save.compound,
+        false,
+    )?;
+
+    env.events().publish(
+        (symbol_short!("relock"), user, lock_id),
+        (new_lock_id, final_amount),
+    );
+
+    Ok(new_lock_id)
+}
+
+/// Adds `amount` to the principal of an active Lock Save.
+///
+/// Used by lock-targeted AutoSave schedules. The maturity time is unchanged;
+/// the start time moves to the amount-weighted average of the old start and
+/// now, so the top-up only accrues interest from the moment it is added.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The lock owner
+/// * `lock_id` - The lock to top up
+/// * `amount` - The amount to add (must be > 0)
+///
+/// # Errors
+/// * `InvalidAmount` - If amount <= 0
+/// * `PlanNotFound` - If the lock does not exist
+/// * `Unauthorized` - If `user` does not own the lock
+/// * `PlanCompleted` - If the lock has already been withdrawn
+/// * `TooLate` - If the lock has already matured
+pub(crate) fn top_up_lock_save(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    ensure_not_paused(env)?;
+    user.require_auth();
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let mut lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if lock_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    if env.ledger().timestamp() >= lock_save.maturity_time {
+        return Err(SavingsError::TooLate);
+    }
+
+    let new_amount = lock_save
+        .amount
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    let weighted_start = lock_save
+        .amount
+        .checked_mul(lock_save.start_time as i128)
+        .and_then(|old| {
+            amount
+                .checked_mul(env.ledger().timestamp() as i128)
+                .and_then(|added| old.checked_add(added))
+        })
+        .ok_or(SavingsError::Overflow)?
+        / new_amount;
+    lock_save.start_time = weighted_start as u64;
+    lock_save.amount = new_amount;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id), &lock_save);
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    crate::stats::record_deposit(env, amount)?;
+    history::record(env, &user, symbol_short!("lock_dep"), amount, lock_id);
+
+    storage::award_deposit_points(env, user.clone(), amount)?;
+
+    ttl::extend_lock_ttl(env, lock_id);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events()
+        .publish((symbol_short!("lock_top"), user, lock_id), amount);
+
+    Ok(())
+}
+
+/// Splits `split_amount` off a lock into a new lock owned by the same user.
+///
+/// The new lock copies the original's start time, maturity, interest rate
+/// and compounding, so the two locks together accrue exactly what the
+/// original would have. A strategy position backing the lock is split in
+/// the same proportion.
+///
+/// # Returns
+/// The ID of the new lock
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+/// * `Unauthorized` - If `user` does not own the lock
+/// * `PlanCompleted` - If the lock has already been withdrawn
+/// * `InvalidAmount` - Unless `0 < split_amount < amount`
+/// * `MaxPlansExceeded` - If the user is at their open plan limit
+pub fn split_lock_save(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+    split_amount: i128,
+) -> Result<u64, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    let mut lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if lock_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    if split_amount <= 0 || split_amount >= lock_save.amount {
+        return Err(SavingsError::InvalidAmount);
+    }
+    users::ensure_plan_capacity(env, &user)?;
+
+    let new_lock_id = get_next_lock_id(env);
+    increment_next_lock_id(env);
+
+    lock_save.amount -= split_amount;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id), &lock_save);
+
+    let new_lock = LockSave {
+        id: new_lock_id,
+        amount: split_amount,
+        ..lock_save
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(new_lock_id), &new_lock);
+    add_lock_to_user(env, &user, new_lock_id);
+    users::record_plan_opened(env, &user)?;
+
+    // Principal only moves between locks, so total_balance is unchanged
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.savings_count += 1;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+
+    routing::split_position(
+        env,
+        StrategyPositionKey::Lock(lock_id),
+        StrategyPositionKey::Lock(new_lock_id),
+        split_amount,
+    )?;
+
+    ttl::extend_lock_ttl(env, lock_id);
+    ttl::extend_lock_ttl(env, new_lock_id);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events().publish(
+        (symbol_short!("lock_spl"), user, lock_id),
+        (new_lock_id, split_amount),
+    );
+
+    Ok(new_lock_id)
+}
+
+/// Merges lock `lock_id_b` into `lock_id_a`, summing their principal.
+///
+/// Both locks must share maturity, interest rate and compounding. The merged
+/// lock accrues from the later of the two start times so combining locks
+/// never adds interest, and any strategy positions are merged as well.
+/// `lock_id_b` is marked withdrawn and dropped from the user's lock list.
+///
+/// # Errors
+/// * `PlanNotFound` - If either lock does not exist
+/// * `Unauthorized` - If `user` does not own both locks
+/// * `PlanCompleted` - If either lock has already been withdrawn
+/// * `InvalidAmount` - If both IDs refer to the same lock
+/// * `InvalidGroupConfig` - If maturity, rate or compounding differ
+/// * `InvalidPlanConfig` - If the locks are routed to different strategies
+pub fn merge_lock_saves(
+    env: &Env,
+    user: Address,
+    lock_id_a: u64,
+    lock_id_b: u64,
+) -> Result<(), SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    if lock_id_a == lock_id_b {
+        return Err(SavingsError::InvalidAmount);
+    }
+    let mut lock_a = get_lock_save(env, lock_id_a).ok_or(SavingsError::PlanNotFound)?;
+    let mut lock_b = get_lock_save(env, lock_id_b).ok_or(SavingsError::PlanNotFound)?;
+    if lock_a.owner != user || lock_b.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if lock_a.is_withdrawn || lock_b.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    if lock_a.maturity_time != lock_b.maturity_time
+        || lock_a.interest_rate != lock_b.interest_rate
+        || lock_a.compound != lock_b.compound
+    {
+        return Err(SavingsError::InvalidGroupConfig);
+    }
+
+    // Weight the start time by amount so neither lock loses accrued interest
+    let new_amount = lock_a
+        .amount
+        .checked_add(lock_b.amount)
+        .ok_or(SavingsError::Overflow)?;
+    let weighted_start = lock_a
+        .amount
+        .checked_mul(lock_a.start_time as i128)
+        .and_then(|a| {
+            lock_b
+                .amount
+                .checked_mul(lock_b.start_time as i128)
+                .and_then(|b| a.checked_add(b))
+        })
+        .ok_or(SavingsError::Overflow)?
+        / new_amount;
+
+    routing::merge_position(
+        env,
+        StrategyPositionKey::Lock(lock_id_b),
+        StrategyPositionKey::Lock(lock_id_a),
+    )?;
+
+    lock_a.amount = new_amount;
+    lock_a.start_time = weighted_start as u64;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id_a), &lock_a);
+
+    let merged_amount = lock_b.amount;
+    lock_b.amount = 0;
+    lock_b.is_withdrawn = true;
+    env.storage()
+        .persistent()
+        .set(&DataKey::LockSave(lock_id_b), &lock_b);
+    users::record_plan_closed(env, &user);
+    remove_lock_from_user(env, &user, lock_id_b);
+
+    ttl::extend_lock_ttl(env, lock_id_a);
+    ttl::extend_user_ttl(env, &user);
+
+    env.events().publish(
+        (symbol_short!("lock_mrg"), user, lock_id_a),
+        (lock_id_b, merged_amount),
+    );
+
+    Ok(())
+}
+
+/// Sets the shortest and longest durations accepted for new Lock Saves.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The contract admin
+/// * `min_duration` - Minimum duration in seconds (0 = no bound)
+/// * `max_duration` - Maximum duration in seconds (0 = no bound)
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+/// * `InvalidTimestamp` - If both bounds are set and `min_duration > max_duration`
+pub fn set_lock_duration_bounds(
+    env: &Env,
+    admin: Address,
+    min_duration: u64,
+    max_duration: u64,
+) -> Result<(), SavingsError> {
+    config::require_admin(env, &admin)?;
+
+    if max_duration > 0 && min_duration > max_duration {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MinLockDuration, &min_duration);
+    env.storage()
+        .instance()
+        .set(&ConfigKey::MaxLockDuration, &max_duration);
+    env.events().publish(
+        (symbol_short!("lock_dur"), admin),
+        (min_duration, max_duration),
+    );
+
+    Ok(())
+}
+
+/// Returns the (minimum, maximum) Lock Save durations in seconds (0 = no bound).
+pub fn get_lock_duration_bounds(env: &Env) -> (u64, u64) {
+    let storage = env.storage().instance();
+    (
+        storage.get(&ConfigKey::MinLockDuration).unwrap_or(0),
+        storage.get(&ConfigKey::MaxLockDuration).unwrap_or(0),
+    )
+}
+
+/// Sets (or clears with `None`) the strategy new Lock Save deposits are routed into.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The contract admin
+/// * `strategy_address` - Registered, enabled strategy, or `None` to stop routing
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+/// * `StrategyNotFound` - If the strategy is not registered
+/// * `StrategyDisabled` - If the strategy is disabled
+pub fn set_default_lock_strategy(
+    env: &Env,
+    admin: Address,
+    strategy_address: Option<Address>,
+) -> Result<(), SavingsError> {
+    config::require_admin(env, &admin)?;
+
+    match strategy_address {
+        Some(strategy_address) => {
+            let info = registry::get_strategy(env, strategy_address.clone())?;
+            if !info.enabled {
+                return Err(SavingsError::StrategyDisabled);
+            }
+            env.storage()
+                .instance()
+                .set(&ConfigKey::DefaultLockStrategy, &strategy_address);
+            env.events()
+                .publish((symbol_short!("lock_strt"),), strategy_address);
+        }
+        None => {
+            env.storage()
+                .instance()
+                .remove(&ConfigKey::DefaultLockStrategy);
+            env.events().publish((symbol_short!("lock_strt"),), ());
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the strategy new Lock Save deposits are routed into, if any.
+pub fn get_default_lock_strategy(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::DefaultLockStrategy)
+}
+
+/// Pulls a lock's funds back from its strategy position, if it has one.
+///
+/// Harvests the strategy first so accrued yield is credited, then withdraws
+/// the lock's principal and claims its pro-rata share of the strategy's user
+/// yield pool.
+///
+/// # Returns
+/// The strategy yield attributable to this lock (0 if it was never routed).
+fn withdraw_lock_from_strategy(env: &Env, lock_id: u64) -> Result<i128, SavingsError> {
+    let position_key = StrategyPositionKey::Lock(lock_id);
+    let position = match routing::get_position(env, position_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return Ok(0),
+    };
+
+    // A failing harvest leaves state untouched; the principal is still withdrawn
+    let _ = routing::harvest_strategy(env, position.strategy.clone());
+    let yield_share =
+        routing::take_position_yield(env, position_key.clone(), position.principal_deposited)?;
+
+    // A broken strategy must not trap the lock; any unreturned principal is
+    // recorded as a strategy shortfall and the lock is paid from contract funds
+    routing::try_withdraw_from_strategy(env, position_key, env.current_contract_address())?;
+
+    Ok(yield_share)
+}
+
+/// Projects the bonus points a lock is worth to its owner at maturity.
+///
+/// Sums the long-lock bonus for the lock's duration and, while the lock is
+/// still open, the maturity bonus its principal would earn on withdrawal.
+/// Both use the current rewards config; base deposit points are excluded.
+/// Read-only.
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+/// * `Unauthorized` - If `user` does not own the lock
+pub fn project_lock_rewards(env: &Env, user: Address, lock_id: u64) -> Result<u128, SavingsError> {
+    let lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    let config = match crate::rewards::config::get_rewards_config(env) {
+        Ok(config) if config.enabled => config,
+        _ => return Ok(0),
+    };
+
+    let duration = lock_save.maturity_time.saturating_sub(lock_save.start_time);
+    let mut points = storage::long_lock_bonus_points(&config, lock_save.amount, duration)?;
+    if !lock_save.is_withdrawn {
+        points = points
+            .checked_add(storage::maturity_bonus_points(&config, lock_save.amount)?)
+            .ok_or(SavingsError::Overflow)?;
+    }
+    Ok(points)
+}
+
 pub fn check_matured_lock(env: &Env, lock_id: u64) -> bool {
     if let Some(lock_save) = get_lock_save(env, lock_id) {
         // Extend TTL on check
@@ -127,6 +910,65 @@ pub fn check_matured_lock(env: &Env, lock_id: u64) -> bool {
     }
 }
 
+/// Reason codes returned by `can_withdraw_lock` and `can_withdraw_goal`.
+pub const WITHDRAW_OK: u32 = 0;
+pub const WITHDRAW_NOT_OWNER: u32 = 1;
+pub const WITHDRAW_ALREADY_WITHDRAWN: u32 = 2;
+pub const WITHDRAW_NOT_MATURED: u32 = 3;
+pub const WITHDRAW_PAUSED: u32 = 4;
+
+/// Reports whether `withdraw_lock_save` would succeed for `user`, without
+/// changing any state. A missing lock is reported as `WITHDRAW_NOT_OWNER`.
+///
+/// # Returns
+/// `(true, WITHDRAW_OK)` or `(false, reason)` using the `WITHDRAW_*` codes
+pub fn can_withdraw_lock(env: &Env, user: &Address, lock_id: u64) -> (bool, u32) {
+    let reason = if config::is_withdraw_paused(env) {
+        WITHDRAW_PAUSED
+    } else {
+        match get_lock_save(env, lock_id) {
+            Some(lock_save) if lock_save.owner == *user => {
+                if lock_save.is_withdrawn {
+                    WITHDRAW_ALREADY_WITHDRAWN
+                } else if env.ledger().timestamp() < lock_save.maturity_time {
+                    WITHDRAW_NOT_MATURED
+                } else {
+                    WITHDRAW_OK
+                }
+            }
+            _ => WITHDRAW_NOT_OWNER,
+        }
+    };
+    (reason == WITHDRAW_OK, reason)
+}
+
+/// Returns seconds until the lock matures; negative once it has matured.
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+pub fn time_to_maturity(env: &Env, lock_id: u64) -> Result<i64, SavingsError> {
+    let lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+    Ok(lock_save.maturity_time as i64 - env.ledger().timestamp() as i64)
+}
+
+/// Returns elapsed lock time as basis points of its duration, capped at 10_000.
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+pub fn maturity_percentage(env: &Env, lock_id: u64) -> Result<u32, SavingsError> {
+    let lock_save = get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+    let duration = lock_save.maturity_time.saturating_sub(lock_save.start_time);
+    if duration == 0 {
+        return Ok(10_000);
+    }
+    let elapsed = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(lock_save.start_time)
+        .min(duration);
+    Ok((elapsed as u128 * 10_000 / duration as u128) as u32)
+}
+
 pub fn get_lock_save(env: &Env, lock_id: u64) -> Option<LockSave> {
     let lock_save = env.storage().persistent().get(&DataKey::LockSave(lock_id));
     if lock_save.is_some() {
@@ -152,6 +994,31 @@ pub fn get_user_lock_saves(env: &Env, user: &Address) -> Vec<u64> {
     locks
 }
 
+/// Returns every Lock Save the user has not yet withdrawn.
+pub fn get_active_lock_saves(env: &Env, user: &Address) -> Vec<LockSave> {
+    let mut active = Vec::new(env);
+    for lock_id in get_user_lock_saves(env, user).iter() {
+        if let Some(lock_save) = get_lock_save(env, lock_id) {
+            if !lock_save.is_withdrawn {
+                active.push_back(lock_save);
+            }
+        }
+    }
+    active
+}
+
+/// Returns the IDs of the user's matured, not-yet-withdrawn Lock Saves.
+pub fn get_matured_lock_saves(env: &Env, user: &Address) -> Vec<u64> {
+    let now = env.ledger().timestamp();
+    let mut matured = Vec::new(env);
+    for lock_save in get_active_lock_saves(env, user).iter() {
+        if now >= lock_save.maturity_time {
+            matured.push_back(lock_save.id);
+        }
+    }
+    matured
+}
+
 // --- Internal Helper Functions ---
 
 fn get_next_lock_id(env: &Env) -> u64 {
@@ -183,7 +1050,33 @@ fn add_lock_to_user(env: &Env, user: &Address, lock_id: u64) {
         .set(&DataKey::UserLockSaves(user.clone()), &user_locks);
 }
 
+fn remove_lock_from_user(env: &Env, user: &Address, lock_id: u64) {
+    let mut user_locks = Vec::new(env);
+    for id in get_user_lock_saves(env, user).iter() {
+        if id != lock_id {
+            user_locks.push_back(id);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserLockSaves(user.clone()), &user_locks);
+}
+
+/// Yield a lock pays on top of its principal if withdrawn exactly at maturity.
+///
+/// Excludes any strategy yield, which is only known at withdrawal time.
+pub(crate) fn projected_lock_yield(lock_save: &LockSave) -> i128 {
+    calculate_lock_save_yield(lock_save, lock_save.maturity_time).saturating_sub(lock_save.amount)
+}
+
+/// Seconds in one compounding period (a 365.25-day year)
+const COMPOUND_PERIOD_SECS: u64 = 31_557_600;
+
 fn calculate_lock_save_yield(lock_save: &LockSave, current_time: u64) -> i128 {
+    if lock_save.compound {
+        return calculate_compound_lock_yield(lock_save, current_time);
+    }
+
     let duration_seconds = current_time.saturating_sub(lock_save.start_time);
     let duration_years = (duration_seconds as f64) / (365.25 * 24.0 * 3600.0);
     let rate_decimal = (lock_save.interest_rate as f64) / 10000.0;
@@ -191,14 +1084,38 @@ fn calculate_lock_save_yield(lock_save: &LockSave, current_time: u64) -> i128 {
     (lock_save.amount as f64 * multiplier) as i128
 }
 
+/// Compounds interest once per whole elapsed year using integer math, then
+/// accrues simple interest on the compounded balance for the partial year.
+fn calculate_compound_lock_yield(lock_save: &LockSave, current_time: u64) -> i128 {
+    let duration_seconds = current_time.saturating_sub(lock_save.start_time);
+    let periods = duration_seconds / COMPOUND_PERIOD_SECS;
+    let remainder = duration_seconds % COMPOUND_PERIOD_SECS;
+    let rate = lock_save.interest_rate as i128;
+
+    let mut balance = lock_save.amount;
+    for _ in 0..periods {
+        balance = balance.saturating_add(balance.saturating_mul(rate) / 10_000);
+    }
+
+    let partial = balance
+        .saturating_mul(rate)
+        .saturating_mul(remainder as i128)
+        / (10_000 * COMPOUND_PERIOD_SECS as i128);
+    balance.saturating_add(partial)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::rewards::events::BonusAwarded;
     use crate::rewards::storage::LONG_LOCK_BONUS_THRESHOLD_SECS;
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient};
+    use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
+    use crate::strategy::routing::StrategyPositionKey;
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{
+        symbol_short,
         testutils::{Address as _, Events, Ledger},
-        Address, BytesN, Env, IntoVal, Symbol,
+        Address, BytesN, Env, IntoVal, Symbol, TryFromVal,
     };
 
     fn setup_env_with_rewards_enabled(
@@ -223,6 +1140,9 @@ mod tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
         assert!(client.try_initialize_rewards_config(&config).is_ok());
 
@@ -285,7 +1205,7 @@ mod tests {
 
         let amount = 1_000i128;
         let above_threshold = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
-        client.create_lock_save(&user, &amount, &above_threshold);
+        client.create_lock_save(&user, &amount, &above_threshold, &false);
 
         let rewards = client.get_user_rewards(&user);
         // base points = 1000 * 10 = 10000, bonus = 20% = 2000
@@ -302,7 +1222,7 @@ mod tests {
         client.initialize_user(&user);
 
         let amount = 1_000i128;
-        client.create_lock_save(&user, &amount, &LONG_LOCK_BONUS_THRESHOLD_SECS);
+        client.create_lock_save(&user, &amount, &LONG_LOCK_BONUS_THRESHOLD_SECS, &false);
 
         let rewards = client.get_user_rewards(&user);
         // base points = 1000 * 10 = 10000
@@ -319,7 +1239,7 @@ mod tests {
 
         let amount = 1_000i128;
         let below_threshold = LONG_LOCK_BONUS_THRESHOLD_SECS - 1;
-        client.create_lock_save(&user, &amount, &below_threshold);
+        client.create_lock_save(&user, &amount, &below_threshold, &false);
 
         let rewards = client.get_user_rewards(&user);
         // base points = 1000 * 10 = 10000
@@ -336,7 +1256,7 @@ mod tests {
 
         let amount = 1_000i128;
         let above_threshold = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
-        client.create_lock_save(&user, &amount, &above_threshold);
+        client.create_lock_save(&user, &amount, &above_threshold, &false);
 
         let rewards = client.get_user_rewards(&user);
         assert_eq!(rewards.total_points, 0);
@@ -352,7 +1272,7 @@ mod tests {
 
         let amount = 1_000i128;
         let duration = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
-        let lock_id = client.create_lock_save(&user, &amount, &duration);
+        let lock_id = client.create_lock_save(&user, &amount, &duration, &false);
 
         env.ledger().with_mut(|li| {
             li.timestamp = li.timestamp + duration + 1;
@@ -364,4 +1284,783 @@ mod tests {
         // base points = 1000 * 10 = 10000, bonus = 2000
         assert_eq!(rewards.total_points, 12_000);
     }
+
+    #[test]
+    fn test_lock_save_routes_to_default_strategy_and_back() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let strategy = env.register(MockStrategy, ());
+        client.register_strategy(&admin, &strategy, &1u32);
+        client.set_default_lock_strategy(&admin, &Some(strategy.clone()));
+
+        let lock_id = client.create_lock_save(&user, &1_000, &1, &false);
+
+        let position = client.get_lock_strategy_position(&lock_id).unwrap();
+        assert_eq!(position.strategy, strategy);
+        assert_eq!(position.principal_deposited, 1_000);
+        assert_eq!(client.get_strategy_principal(&strategy), 1_000);
+        let strategy_client = MockStrategyClient::new(&env, &strategy);
+        assert_eq!(strategy_client.strategy_balance(&user), 1_000);
+
+        // Strategy earns 100 of yield while the lock matures
+        strategy_client.add_yield(&100);
+        env.ledger().with_mut(|li| li.timestamp += 1);
+
+        let final_amount = client.withdraw_lock_save(&user, &lock_id);
+        assert_eq!(final_amount, 1_100);
+        assert_eq!(strategy_client.strategy_balance(&user), 0);
+        assert_eq!(client.get_strategy_principal(&strategy), 0);
+        assert_eq!(client.get_strategy_yield(&strategy), 0);
+        assert_eq!(
+            client
+                .get_lock_strategy_position(&lock_id)
+                .unwrap()
+                .principal_deposited,
+            0
+        );
+    }
+
+    #[test]
+    fn test_lock_withdraw_falls_back_when_strategy_fails() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let strategy = env.register(MockStrategy, ());
+        client.register_strategy(&admin, &strategy, &1u32);
+        client.set_default_lock_strategy(&admin, &Some(strategy.clone()));
+
+        let lock_id = client.create_lock_save(&user, &1_000, &1, &false);
+        MockStrategyClient::new(&env, &strategy).set_failing(&true);
+        env.ledger().with_mut(|li| li.timestamp += 1);
+
+        // The lock is still paid its principal; the strategy owes the shortfall
+        assert_eq!(client.withdraw_lock_save(&user, &lock_id), 1_000);
+        assert_eq!(client.get_strategy_shortfall(&strategy), 1_000);
+        assert_eq!(client.get_strategy_principal(&strategy), 0);
+        assert_eq!(
+            client
+                .get_lock_strategy_position(&lock_id)
+                .unwrap()
+                .principal_deposited,
+            0
+        );
+    }
+
+    #[test]
+    fn test_lock_save_without_default_strategy_is_not_routed() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        assert_eq!(client.get_default_lock_strategy(), None);
+        let lock_id = client.create_lock_save(&user, &1_000, &1, &false);
+        assert!(client.get_lock_strategy_position(&lock_id).is_none());
+
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        assert_eq!(client.withdraw_lock_save(&user, &lock_id), 1_000);
+    }
+
+    #[test]
+    fn test_set_default_lock_strategy_rejects_disabled_and_clears() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let strategy = env.register(MockStrategy, ());
+        client.register_strategy(&admin, &strategy, &1u32);
+        client.disable_strategy(&admin, &strategy);
+
+        let result = client.try_set_default_lock_strategy(&admin, &Some(strategy.clone()));
+        assert_eq!(result, Err(Ok(SavingsError::StrategyDisabled)));
+
+        let other = env.register(MockStrategy, ());
+        client.register_strategy(&admin, &other, &1u32);
+        client.set_default_lock_strategy(&admin, &Some(other.clone()));
+        assert_eq!(client.get_default_lock_strategy(), Some(other));
+
+        client.set_default_lock_strategy(&admin, &None);
+        assert_eq!(client.get_default_lock_strategy(), None);
+    }
+
+    #[test]
+    fn test_withdraw_lock_to_flexi_credits_principal_plus_yield() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        // One 365.25-day year at 5% turns 10_000 into 10_500
+        let duration = 31_557_600u64;
+        let lock_id = client.create_lock_save(&user, &10_000, &duration, &false);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+
+        env.ledger().with_mut(|li| li.timestamp += duration);
+
+        let credited = client.withdraw_lock_to_flexi(&user, &lock_id);
+        assert_eq!(credited, 10_500);
+        assert_eq!(client.get_flexi_balance(&user), 10_500);
+        assert_eq!(client.get_user(&user).total_balance, 10_500);
+
+        let lock_save = env.as_contract(&client.address, || {
+            super::get_lock_save(&env, lock_id).unwrap()
+        });
+        assert!(lock_save.is_withdrawn);
+
+        let again = client.try_withdraw_lock_to_flexi(&user, &lock_id);
+        assert_eq!(
+            again,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::PlanCompleted as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn test_withdraw_lock_to_flexi_rejects_immature_and_foreign_locks() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.initialize_user(&user);
+        client.initialize_user(&other);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+
+        let early = client.try_withdraw_lock_to_flexi(&user, &lock_id);
+        assert_eq!(
+            early,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::TooEarly as u32
+            )))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        let foreign = client.try_withdraw_lock_to_flexi(&other, &lock_id);
+        assert_eq!(
+            foreign,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::Unauthorized as u32
+            )))
+        );
+        assert_eq!(client.get_flexi_balance(&other), 0);
+    }
+
+    #[test]
+    fn test_compound_lock_yield_exceeds_simple_yield() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let three_years = 3 * super::COMPOUND_PERIOD_SECS;
+        let simple_id = client.create_lock_save(&user, &10_000, &three_years, &false);
+        let compound_id = client.create_lock_save(&user, &10_000, &three_years, &true);
+
+        env.ledger().with_mut(|li| li.timestamp += three_years);
+
+        // 5% simple over three years vs. 10_000 * 1.05^3 = 11_576.25
+        let simple = client.withdraw_lock_save(&user, &simple_id);
+        let compound = client.withdraw_lock_save(&user, &compound_id);
+        assert_eq!(simple, 11_500);
+        assert_eq!(compound, 11_576);
+        assert!(compound > simple);
+    }
+
+    #[test]
+    fn test_compound_lock_yield_accrues_partial_period() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &10_000, &1, &true);
+        let lock_save = env.as_contract(&client.address, || {
+            super::get_lock_save(&env, lock_id).unwrap()
+        });
+        assert!(lock_save.compound);
+
+        // One full year (10_500) plus half a year of simple interest on it (262)
+        let elapsed = lock_save.start_time + super::COMPOUND_PERIOD_SECS * 3 / 2;
+        assert_eq!(
+            super::calculate_lock_save_yield(&lock_save, elapsed),
+            10_762
+        );
+    }
+
+    #[test]
+    fn test_active_and_matured_lock_views_filter_by_state() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let withdrawn_id = client.create_lock_save(&user, &1_000, &100, &false);
+        let matured_id = client.create_lock_save(&user, &2_000, &100, &false);
+        let immature_id = client.create_lock_save(&user, &3_000, &10_000, &false);
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        client.withdraw_lock_save(&user, &withdrawn_id);
+
+        assert_eq!(client.get_user_lock_saves(&user).len(), 3);
+
+        let active = client.get_active_lock_saves(&user);
+        assert_eq!(active.len(), 2);
+        assert_eq!(active.get(0).unwrap().id, matured_id);
+        assert_eq!(active.get(1).unwrap().id, immature_id);
+
+        let matured = client.get_matured_lock_saves(&user);
+        assert_eq!(matured, soroban_sdk::vec![&env, matured_id]);
+
+        env.ledger().with_mut(|li| li.timestamp += 10_000);
+        let matured = client.get_matured_lock_saves(&user);
+        assert_eq!(matured, soroban_sdk::vec![&env, matured_id, immature_id]);
+    }
+
+    #[test]
+    fn test_lock_maturity_countdown_views() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &1_000, &false);
+        assert_eq!(client.time_to_maturity(&lock_id), 1_000);
+        assert_eq!(client.maturity_percentage(&lock_id), 0);
+
+        env.ledger().with_mut(|li| li.timestamp += 500);
+        assert_eq!(client.time_to_maturity(&lock_id), 500);
+        assert_eq!(client.maturity_percentage(&lock_id), 5_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 750);
+        assert_eq!(client.time_to_maturity(&lock_id), -250);
+        assert_eq!(client.maturity_percentage(&lock_id), 10_000);
+    }
+
+    #[test]
+    fn test_lock_maturity_views_reject_unknown_lock() {
+        let (_env, client, _admin) = setup_env_with_rewards();
+
+        assert_eq!(
+            client.try_time_to_maturity(&99),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+        assert_eq!(
+            client.try_maturity_percentage(&99),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
+
+    #[test]
+    fn test_active_lock_views_empty_for_unknown_user() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        assert!(client.get_active_lock_saves(&user).is_empty());
+        assert!(client.get_matured_lock_saves(&user).is_empty());
+    }
+
+    #[test]
+    fn test_withdraw_all_matured_locks_skips_immature_and_withdrawn() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let early_id = client.create_lock_save(&user, &1_000, &100, &false);
+        let second_id = client.create_lock_save(&user, &2_000, &200, &false);
+        let late_id = client.create_lock_save(&user, &4_000, &10_000, &false);
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        let first = client.withdraw_lock_save(&user, &early_id);
+        assert_eq!(first, 1_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        let total = client.withdraw_all_matured_locks(&user);
+        // Only the 200s lock is newly matured; its yield rounds down to nothing
+        assert_eq!(total, 2_000);
+
+        let active = client.get_active_lock_saves(&user);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active.get(0).unwrap().id, late_id);
+        assert_eq!(
+            client.try_withdraw_lock_save(&user, &second_id),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::PlanCompleted as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn test_withdraw_all_matured_locks_sums_each_matured_amount() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let year = super::COMPOUND_PERIOD_SECS;
+        client.create_lock_save(&user, &10_000, &year, &false);
+        client.create_lock_save(&user, &20_000, &(year / 2), &false);
+        client.create_lock_save(&user, &5_000, &(year * 2), &false);
+
+        env.ledger().with_mut(|li| li.timestamp += year);
+
+        // Both matured locks accrue 5% over the full elapsed year
+        let total = client.withdraw_all_matured_locks(&user);
+        assert_eq!(total, 10_500 + 21_000);
+        assert_eq!(client.get_matured_lock_saves(&user).len(), 0);
+        assert_eq!(client.get_active_lock_saves(&user).len(), 1);
+
+        assert_eq!(client.withdraw_all_matured_locks(&user), 0);
+    }
+
+    #[test]
+    fn test_can_withdraw_lock_reports_each_reason() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+        assert_eq!(client.can_withdraw_lock(&user, &lock_id), (false, 3));
+        assert_eq!(client.can_withdraw_lock(&other, &lock_id), (false, 1));
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        assert_eq!(client.can_withdraw_lock(&user, &lock_id), (true, 0));
+
+        client.pause_op(&admin, &crate::config::PAUSE_OP_WITHDRAW);
+        assert_eq!(client.can_withdraw_lock(&user, &lock_id), (false, 4));
+        client.unpause_op(&admin, &crate::config::PAUSE_OP_WITHDRAW);
+
+        client.withdraw_lock_save(&user, &lock_id);
+        assert_eq!(client.can_withdraw_lock(&user, &lock_id), (false, 2));
+    }
+
+    #[test]
+    fn test_plan_rate_override_applies_to_lock_yield() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let year = super::COMPOUND_PERIOD_SECS;
+        let lock_id = client.create_lock_save(&user, &10_000, &year, &false);
+        client.set_plan_rate(&admin, &user, &PlanType::Lock(0), &lock_id, &1_000);
+        assert_eq!(client.get_lock_view(&lock_id).interest_rate, 1_000);
+
+        env.ledger().with_mut(|li| li.timestamp += year);
+        // 10% promotional rate instead of the 5% default
+        assert_eq!(client.withdraw_lock_save(&user, &lock_id), 11_000);
+    }
+
+    #[test]
+    fn test_plan_rate_override_rejects_non_admin() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+
+        assert_eq!(
+            client.try_set_plan_rate(&user, &user, &PlanType::Lock(0), &lock_id, &1_000),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_plan_rate(&admin, &user, &PlanType::Lock(0), &lock_id, &-1),
+            Err(Ok(SavingsError::InvalidInterestRate))
+        );
+    }
+
+    #[test]
+    fn test_plan_rate_override_checks_plan_type() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        // Lock and goal counters both start at 1, so the IDs collide
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+        let goal_id = client.create_goal_save(&user, &symbol_short!("trip"), &5_000, &1_000);
+        assert_eq!(lock_id, goal_id);
+
+        let goal_type = PlanType::Goal(symbol_short!("trip"), 0, 0);
+        client.set_plan_rate(&admin, &user, &goal_type, &goal_id, &1_000);
+        assert_eq!(client.get_goal_save_detail(&goal_id).interest_rate, 1_000);
+        assert_ne!(client.get_lock_view(&lock_id).interest_rate, 1_000);
+
+        assert_eq!(
+            client.try_set_plan_rate(&admin, &user, &PlanType::Flexi, &lock_id, &1_000),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
+
+    #[test]
+    fn test_lock_duration_bounds_enforced() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.set_lock_duration_bounds(&admin, &(7 * 86_400), &(365 * 86_400));
+        assert_eq!(
+            client.get_lock_duration_bounds(),
+            (7 * 86_400, 365 * 86_400)
+        );
+
+        let invalid =
+            soroban_sdk::Error::from_contract_error(SavingsError::InvalidTimestamp as u32);
+        assert_eq!(
+            client.try_create_lock_save(&user, &1_000, &86_400, &false),
+            Err(Ok(invalid))
+        );
+        assert!(client
+            .try_create_lock_save(&user, &1_000, &(30 * 86_400), &false)
+            .is_ok());
+        assert_eq!(
+            client.try_create_lock_save(&user, &1_000, &(366 * 86_400), &false),
+            Err(Ok(invalid))
+        );
+    }
+
+    #[test]
+    fn test_split_lock_save_keeps_terms() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        let lock_id = client.create_lock_save(&user, &1_000, &(30 * 86_400), &false);
+
+        let new_lock_id = client.split_lock_save(&user, &lock_id, &400);
+        assert_ne!(new_lock_id, lock_id);
+
+        let original = client.get_lock_view(&lock_id);
+        let split = client.get_lock_view(&new_lock_id);
+        assert_eq!(original.balance, 600);
+        assert_eq!(split.balance, 400);
+        assert_eq!(split.locked_until, original.locked_until);
+        assert_eq!(split.interest_rate, original.interest_rate);
+        assert_eq!(split.start_time, original.start_time);
+        assert_eq!(client.get_user(&user).total_balance, 1_000);
+
+        assert_eq!(
+            client.try_split_lock_save(&user, &lock_id, &600),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_split_lock_save(&user, &lock_id, &1_000),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_split_lock_save(&user, &lock_id, &0),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_split_lock_save(&Address::generate(&env), &lock_id, &100),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_split_lock_save_splits_strategy_position() {
+        let (env, client, admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        let strategy = env.register(MockStrategy, ());
+
+        env.mock_all_auths();
+        client.initialize_config(&admin, &Address::generate(&env), &0u32);
+        client.register_strategy(&admin, &strategy, &1u32);
+        client.set_default_lock_strategy(&admin, &Some(strategy.clone()));
+        client.initialize_user(&user);
+        let lock_id = client.create_lock_save(&user, &1_000, &(30 * 86_400), &false);
+
+        let new_lock_id = client.split_lock_save(&user, &lock_id, &400);
+        let original = client.get_lock_strategy_position(&lock_id).unwrap();
+        let split = client.get_lock_strategy_position(&new_lock_id).unwrap();
+        assert_eq!(original.principal_deposited, 600);
+        assert_eq!(split.principal_deposited, 400);
+        assert_eq!(split.strategy, strategy);
+        assert_eq!(client.get_strategy_principal(&strategy), 1_000);
+
+        // Yield harvested after the split is shared by both halves
+        MockStrategyClient::new(&env, &strategy).add_yield(&100i128);
+        client.harvest_strategy(&admin, &strategy);
+        assert_eq!(
+            client.get_pending_strategy_yield(&StrategyPositionKey::Lock(lock_id)),
+            60
+        );
+        assert_eq!(
+            client.get_pending_strategy_yield(&StrategyPositionKey::Lock(new_lock_id)),
+            40
+        );
+    }
+
+    #[test]
+    fn test_merge_lock_saves_combines_matching_locks() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        let lock_a = client.create_lock_save(&user, &1_000, &(30 * 86_400), &false);
+        let lock_b = client.create_lock_save(&user, &500, &(30 * 86_400), &false);
+
+        client.merge_lock_saves(&user, &lock_a, &lock_b);
+        assert_eq!(client.get_lock_view(&lock_a).balance, 1_500);
+        assert!(client.get_lock_view(&lock_b).is_withdrawn);
+        assert_eq!(client.get_user_lock_saves(&user).len(), 1);
+        assert_eq!(client.get_user(&user).total_balance, 1_500);
+
+        assert_eq!(
+            client.try_merge_lock_saves(&user, &lock_a, &lock_b),
+            Err(Ok(SavingsError::PlanCompleted))
+        );
+    }
+
+    #[test]
+    fn test_merge_lock_saves_keeps_older_lock_interest() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        let lock_a = client.create_lock_save(&user, &1_000, &(60 * 86_400), &false);
+        env.ledger().with_mut(|li| li.timestamp += 30 * 86_400);
+        let lock_b = client.create_lock_save(&user, &3_000, &(30 * 86_400), &false);
+
+        let maturity = env.ledger().timestamp() + 30 * 86_400;
+        let payout = |lock_id: u64| {
+            env.as_contract(&client.address, || {
+                super::calculate_lock_save_yield(
+                    &super::get_lock_save(&env, lock_id).unwrap(),
+                    maturity,
+                )
+            })
+        };
+        let expected = payout(lock_a) + payout(lock_b);
+
+        client.merge_lock_saves(&user, &lock_a, &lock_b);
+        let merged = payout(lock_a);
+        assert!(merged > 4_000);
+        assert!((expected - merged).abs() <= 1);
+    }
+
+    #[test]
+    fn test_merge_lock_saves_rejects_mismatched_maturity() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        let lock_a = client.create_lock_save(&user, &1_000, &(30 * 86_400), &false);
+        let lock_b = client.create_lock_save(&user, &500, &(31 * 86_400), &false);
+
+        assert_eq!(
+            client.try_merge_lock_saves(&user, &lock_a, &lock_b),
+            Err(Ok(SavingsError::InvalidGroupConfig))
+        );
+        assert_eq!(client.get_lock_view(&lock_a).balance, 1_000);
+        assert_eq!(client.get_lock_view(&lock_b).balance, 500);
+    }
+
+    fn setup_env_with_maturity_bonus(
+        enabled: bool,
+    ) -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &BytesN::from_array(&env, &[1u8; 32]));
+        let config = RewardsConfig {
+            points_per_token: 10,
+            streak_bonus_bps: 0,
+            long_lock_bonus_bps: 0,
+            goal_completion_bonus: 0,
+            enabled,
+            min_deposit_for_rewards: 0,
+            action_cooldown_seconds: 0,
+            max_daily_points: 1_000_000,
+            max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 1_000, // 10% of base points
+        };
+        client.initialize_rewards_config(&config);
+
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_maturity_bonus_awarded_on_matured_withdrawal() {
+        let (env, client, _admin) = setup_env_with_maturity_bonus(true);
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400, &false);
+        // base points = 1000 * 10 = 10000
+        assert_eq!(client.get_user_rewards(&user).total_points, 10_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        client.withdraw_lock_save(&user, &lock_id);
+
+        let expected_topics = (
+            symbol_short!("rewards"),
+            symbol_short!("bonus"),
+            user.clone(),
+        )
+            .into_val(&env);
+        let expected = BonusAwarded {
+            user: user.clone(),
+            amount: 1_000,
+            bonus_type: Symbol::new(&env, "maturity"),
+        };
+        assert!(env.events().all().iter().any(|(contract, topics, data)| {
+            contract == client.address
+                && topics == expected_topics
+                && BonusAwarded::try_from_val(&env, &data) == Ok(expected.clone())
+        }));
+
+        // maturity bonus = 10% of 10000 = 1000
+        assert_eq!(client.get_user_rewards(&user).total_points, 11_000);
+    }
+
+    #[test]
+    fn test_maturity_bonus_not_awarded_on_early_break() {
+        let (env, client, admin) = setup_env_with_maturity_bonus(true);
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400, &false);
+        assert_eq!(
+            client.try_withdraw_lock_save(&user, &lock_id),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                SavingsError::TooEarly as u32
+            )))
+        );
+        client.emergency_withdraw(&admin, &user, &PlanType::Lock(86_400), &lock_id);
+
+        assert_eq!(client.get_user_rewards(&user).total_points, 10_000);
+    }
+
+    #[test]
+    fn test_maturity_bonus_not_awarded_when_rewards_disabled() {
+        let (env, client, _admin) = setup_env_with_maturity_bonus(false);
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400, &false);
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        client.withdraw_lock_save(&user, &lock_id);
+
+        assert_eq!(client.get_user_rewards(&user).total_points, 0);
+    }
+
+    #[test]
+    fn test_project_lock_rewards_includes_long_lock_bonus() {
+        let (env, client, _) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        let long_lock =
+            client.create_lock_save(&user, &1_000, &(LONG_LOCK_BONUS_THRESHOLD_SECS + 1), &false);
+        // long-lock bonus = 20% of 1000 * 10 = 2000, on top of 10000 base points
+        assert_eq!(client.project_lock_rewards(&user, &long_lock), 2_000);
+        assert_eq!(client.get_user_rewards(&user).total_points, 12_000);
+
+        let short_lock = client.create_lock_save(&user, &1_000, &86_400, &false);
+        assert_eq!(client.project_lock_rewards(&user, &short_lock), 0);
+
+        assert_eq!(
+            client.try_project_lock_rewards(&Address::generate(&env), &long_lock),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_project_lock_rewards_matches_maturity_bonus() {
+        let (env, client, _admin) = setup_env_with_maturity_bonus(true);
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400, &false);
+        let projected = client.project_lock_rewards(&user, &lock_id);
+        assert_eq!(projected, 1_000);
+
+        let before = client.get_user_rewards(&user).total_points;
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        client.withdraw_lock_save(&user, &lock_id);
+        assert_eq!(
+            client.get_user_rewards(&user).total_points - before,
+            projected
+        );
+        assert_eq!(client.project_lock_rewards(&user, &lock_id), 0);
+    }
+
+    #[test]
+    fn test_lock_from_flexi_moves_balance_into_lock() {
+        let (env, client, _) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &1_000);
+        let points_before = client.get_user_rewards(&user).total_points;
+
+        let duration = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
+        let lock_id = client.lock_from_flexi(&user, &600, &duration);
+
+        assert_eq!(client.get_flexi_balance(&user), 400);
+        assert_eq!(client.get_lock_view(&lock_id).balance, 600);
+        assert_eq!(client.get_user(&user).total_balance, 1_000);
+        // Only the long-lock bonus: 20% of 600 * 10 = 1200
+        assert_eq!(
+            client.get_user_rewards(&user).total_points - points_before,
+            1_200
+        );
+
+        assert_eq!(
+            client.try_lock_from_flexi(&user, &401, &86_400),
+            Err(Ok(SavingsError::InsufficientBalance))
+        );
+        assert_eq!(client.get_flexi_balance(&user), 400);
+    }
+
+    #[test]
+    fn test_relock_rolls_matured_amount_into_new_lock() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        // One 365.25-day year at 5% turns 10_000 into 10_500
+        let duration = 31_557_600u64;
+        let lock_id = client.create_lock_save(&user, &10_000, &duration, &false);
+        env.ledger().with_mut(|li| li.timestamp += duration);
+
+        let new_lock_id = client.relock(&user, &lock_id, &duration);
+        assert_ne!(new_lock_id, lock_id);
+
+        let (old_lock, new_lock) = env.as_contract(&client.address, || {
+            (
+                super::get_lock_save(&env, lock_id).unwrap(),
+                super::get_lock_save(&env, new_lock_id).unwrap(),
+            )
+        });
+        assert!(old_lock.is_withdrawn);
+        assert_eq!(new_lock.amount, 10_500);
+        assert!(!new_lock.is_withdrawn);
+        assert_eq!(new_lock.maturity_time, env.ledger().timestamp() + duration);
+        assert_eq!(client.get_user(&user).total_balance, 10_500);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_relock_rejects_immature_lock() {
+        let (env, client, _admin) = setup_env_with_rewards();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+        env.ledger().with_mut(|li| li.timestamp += 99);
+
+        assert_eq!(
+            client.try_relock(&user, &lock_id, &100),
+            Err(Ok(SavingsError::TooEarly))
+        );
+        let lock_save = env.as_contract(&client.address, || {
+            super::get_lock_save(&env, lock_id).unwrap()
+        });
+        assert!(!lock_save.is_withdrawn);
+    }
 }
@@ -0,0 +1,452 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, BytesN, Env, Error, Vec,
+};
+
+use crate::{ContractError, MintPayload, NesteraContract, NesteraContractClient, SavingsError};
+
+// ========== Test Helpers ==========
+
+/// Sets up a contract whose admin public key matches `signing_key`
+fn setup() -> (Env, NesteraContractClient<'static>, SigningKey) {
+    let (env, client, _admin, signing_key) = setup_with_admin();
+    (env, client, signing_key)
+}
+
+/// Like `setup`, but also returns the admin address
+fn setup_with_admin() -> (Env, NesteraContractClient<'static>, Address, SigningKey) {
+    let env = Env::default();
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let admin_pk = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+
+    env.mock_all_auths();
+    client.initialize(&admin, &admin_pk);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    (env, client, admin, signing_key)
+}
+
+fn payload(env: &Env, user: &Address, amount: i128, nonce: u64) -> MintPayload {
+    MintPayload {
+        user: user.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+        expiry_duration: 3_600,
+        nonce,
+        to_flexi: false,
+    }
+}
+
+fn flexi_payload(env: &Env, user: &Address, amount: i128, nonce: u64) -> MintPayload {
+    MintPayload {
+        to_flexi: true,
+        ..payload(env, user, amount, nonce)
+    }
+}
+
+/// Signs the XDR encoding of `payload` with the admin key
+fn sign(env: &Env, signing_key: &SigningKey, payload: &MintPayload) -> BytesN<64> {
+    let xdr = payload.clone().to_xdr(env);
+    let mut message = std::vec![0u8; xdr.len() as usize];
+    xdr.copy_into_slice(&mut message);
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+/// Signs the XDR encoding of a whole batch of payloads with the admin key
+fn sign_batch(env: &Env, signing_key: &SigningKey, payloads: &Vec<MintPayload>) -> BytesN<64> {
+    let xdr = payloads.clone().to_xdr(env);
+    let mut message = std::vec![0u8; xdr.len() as usize];
+    xdr.copy_into_slice(&mut message);
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+// ========== Nonce Tests ==========
+
+#[test]
+fn test_mint_with_fresh_nonce_succeeds() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+
+    assert_eq!(client.mint(&payload, &signature), 500);
+}
+
+#[test]
+fn test_mint_replay_same_nonce_fails() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+    client.mint(&payload, &signature);
+
+    let result = client.try_mint(&payload, &signature);
+    assert_eq!(
+        result,
+        Err(Ok(Error::from_contract_error(
+            ContractError::NonceAlreadyUsed as u32
+        )))
+    );
+}
+
+#[test]
+fn test_mint_different_nonces_both_succeed() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let first = payload(&env, &user, 500, 1);
+    let second = payload(&env, &user, 500, 2);
+
+    assert_eq!(client.mint(&first, &sign(&env, &signing_key, &first)), 500);
+    assert_eq!(
+        client.mint(&second, &sign(&env, &signing_key, &second)),
+        500
+    );
+}
+
+#[test]
+fn test_mint_nonce_not_consumed_by_failed_verification() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = payload(&env, &user, 500, 1);
+    let bad_signature = BytesN::from_array(&env, &[0u8; 64]);
+    assert!(client.try_mint(&payload, &bad_signature).is_err());
+
+    let signature = sign(&env, &signing_key, &payload);
+    assert_eq!(client.mint(&payload, &signature), 500);
+}
+
+// ========== Expiry Grace Tests ==========
+
+#[test]
+fn test_mint_within_signature_grace_succeeds() {
+    let (env, client, admin, signing_key) = setup_with_admin();
+    let user = Address::generate(&env);
+    client.set_signature_grace_secs(&admin, &60);
+
+    let payload = payload(&env, &user, 100, 1);
+    let signature = sign(&env, &signing_key, &payload);
+    env.ledger().with_mut(|li| li.timestamp += 3_600 + 30);
+
+    assert_eq!(client.mint(&payload, &signature), 100);
+}
+
+#[test]
+fn test_mint_past_signature_grace_fails() {
+    let (env, client, admin, signing_key) = setup_with_admin();
+    let user = Address::generate(&env);
+    client.set_signature_grace_secs(&admin, &60);
+
+    let payload = payload(&env, &user, 100, 1);
+    let signature = sign(&env, &signing_key, &payload);
+    env.ledger().with_mut(|li| li.timestamp += 3_600 + 90);
+
+    assert_eq!(
+        client.try_mint(&payload, &signature),
+        Err(Ok(Error::from_contract_error(
+            ContractError::SignatureExpired as u32
+        )))
+    );
+}
+
+#[test]
+fn test_mint_at_expiry_boundary_without_grace() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+    assert_eq!(client.get_signature_grace_secs(), 0);
+
+    let first = payload(&env, &user, 100, 1);
+    let second = payload(&env, &user, 100, 2);
+    let first_sig = sign(&env, &signing_key, &first);
+    let second_sig = sign(&env, &signing_key, &second);
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    assert_eq!(client.mint(&first, &first_sig), 100);
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    assert_eq!(
+        client.try_mint(&second, &second_sig),
+        Err(Ok(Error::from_contract_error(
+            ContractError::SignatureExpired as u32
+        )))
+    );
+}
+
+// ========== Batch Mint Tests ==========
+
+#[test]
+fn test_batch_mint_three_payloads_totals() {
+    let (env, client, signing_key) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let payloads = vec![
+        &env,
+        payload(&env, &alice, 100, 1),
+        payload(&env, &bob, 250, 1),
+        payload(&env, &alice, 50, 2),
+    ];
+    let signature = sign_batch(&env, &signing_key, &payloads);
+
+    assert_eq!(client.batch_mint(&payloads, &signature), 400);
+
+    // Every nonce in the batch is now consumed
+    let replay = payload(&env, &bob, 250, 1);
+    let result = client.try_mint(&replay, &sign(&env, &signing_key, &replay));
+    assert_eq!(
+        result,
+        Err(Ok(Error::from_contract_error(
+            ContractError::NonceAlreadyUsed as u32
+        )))
+    );
+}
+
+#[test]
+fn test_batch_mint_with_expired_payload_reverts() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let mut expired = payload(&env, &user, 100, 2);
+    expired.timestamp = 0;
+    expired.expiry_duration = 10;
+    let payloads = vec![&env, payload(&env, &user, 100, 1), expired];
+    let signature = sign_batch(&env, &signing_key, &payloads);
+
+    let result = client.try_batch_mint(&payloads, &signature);
+    assert_eq!(
+        result,
+        Err(Ok(Error::from_contract_error(
+            ContractError::SignatureExpired as u32
+        )))
+    );
+
+    // The valid payload's nonce was rolled back with the batch
+    let first = payload(&env, &user, 100, 1);
+    assert_eq!(client.mint(&first, &sign(&env, &signing_key, &first)), 100);
+}
+
+#[test]
+fn test_batch_mint_tampered_batch_fails_verification() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payloads = vec![
+        &env,
+        payload(&env, &user, 100, 1),
+        payload(&env, &user, 100, 2),
+    ];
+    let signature = sign_batch(&env, &signing_key, &payloads);
+
+    let tampered = vec![
+        &env,
+        payload(&env, &user, 100, 1),
+        payload(&env, &user, 9_999, 2),
+    ];
+    assert!(client.try_batch_mint(&tampered, &signature).is_err());
+}
+
+#[test]
+fn test_batch_mint_duplicate_nonce_in_batch_reverts() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payloads = vec![
+        &env,
+        payload(&env, &user, 100, 1),
+        payload(&env, &user, 100, 1),
+    ];
+    let signature = sign_batch(&env, &signing_key, &payloads);
+
+    assert!(client.try_batch_mint(&payloads, &signature).is_err());
+}
+
+#[test]
+fn test_mint_expiry_overflow_reverts() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let mut single = payload(&env, &user, 100, 1);
+    single.timestamp = 1;
+    single.expiry_duration = u64::MAX;
+    let signature = sign(&env, &signing_key, &single);
+    assert_eq!(
+        client.try_mint(&single, &signature),
+        Err(Ok(Error::from_contract_error(
+            SavingsError::Overflow as u32
+        )))
+    );
+
+    let mut batched = payload(&env, &user, 100, 2);
+    batched.timestamp = 1;
+    batched.expiry_duration = u64::MAX;
+    let payloads = vec![&env, batched];
+    assert_eq!(
+        client.try_batch_mint(&payloads, &sign_batch(&env, &signing_key, &payloads)),
+        Err(Ok(Error::from_contract_error(
+            SavingsError::Overflow as u32
+        )))
+    );
+    assert_eq!(client.get_total_minted(), 0);
+}
+
+// ========== Mint to Flexi Tests ==========
+
+#[test]
+fn test_mint_to_flexi_credits_balance_and_creates_user() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+    assert!(!client.user_exists(&user));
+
+    let payload = flexi_payload(&env, &user, 750, 1);
+    let signature = sign(&env, &signing_key, &payload);
+
+    assert_eq!(client.mint_to_flexi(&payload, &signature), 750);
+    assert!(client.user_exists(&user));
+    assert_eq!(client.get_flexi_balance(&user), 750);
+    assert_eq!(client.get_user(&user).total_balance, 750);
+}
+
+#[test]
+fn test_mint_to_flexi_adds_to_existing_balance() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+    client.deposit_flexi(&user, &1_000);
+
+    let payload = flexi_payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+
+    assert_eq!(client.mint_to_flexi(&payload, &signature), 1_500);
+    assert_eq!(client.get_flexi_balance(&user), 1_500);
+}
+
+#[test]
+fn test_mint_to_flexi_expired_payload_reverts() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = flexi_payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+    env.ledger().with_mut(|li| li.timestamp += 3_601);
+
+    let result = client.try_mint_to_flexi(&payload, &signature);
+    assert_eq!(
+        result,
+        Err(Ok(Error::from_contract_error(
+            ContractError::SignatureExpired as u32
+        )))
+    );
+    assert!(!client.user_exists(&user));
+}
+
+#[test]
+fn test_mint_to_flexi_replay_is_blocked() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = flexi_payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+    client.mint_to_flexi(&payload, &signature);
+
+    let result = client.try_mint_to_flexi(&payload, &signature);
+    assert_eq!(
+        result,
+        Err(Ok(Error::from_contract_error(
+            ContractError::NonceAlreadyUsed as u32
+        )))
+    );
+    assert_eq!(client.get_flexi_balance(&user), 500);
+}
+
+#[test]
+fn test_mint_payload_cannot_be_redeemed_to_flexi() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+
+    assert_eq!(
+        client.try_mint_to_flexi(&payload, &signature),
+        Err(Ok(Error::from_contract_error(
+            ContractError::WrongMintTarget as u32
+        )))
+    );
+    assert!(!client.user_exists(&user));
+    // The nonce is untouched, so the payload still mints where it was meant to
+    assert_eq!(client.mint(&payload, &signature), 500);
+}
+
+#[test]
+fn test_flexi_payload_cannot_be_minted_directly() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let payload = flexi_payload(&env, &user, 500, 1);
+    let signature = sign(&env, &signing_key, &payload);
+    assert_eq!(
+        client.try_mint(&payload, &signature),
+        Err(Ok(Error::from_contract_error(
+            ContractError::WrongMintTarget as u32
+        )))
+    );
+
+    let payloads = vec![&env, payload.clone()];
+    assert_eq!(
+        client.try_batch_mint(&payloads, &sign_batch(&env, &signing_key, &payloads)),
+        Err(Ok(Error::from_contract_error(
+            ContractError::WrongMintTarget as u32
+        )))
+    );
+    assert_eq!(client.get_total_minted(), 0);
+}
+
+// ========== Mint Accounting Tests ==========
+
+#[test]
+fn test_mints_accumulate_global_and_per_user_totals() {
+    let (env, client, signing_key) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let first = payload(&env, &alice, 500, 1);
+    client.mint(&first, &sign(&env, &signing_key, &first));
+    let second = flexi_payload(&env, &bob, 200, 1);
+    client.mint_to_flexi(&second, &sign(&env, &signing_key, &second));
+
+    let payloads = vec![
+        &env,
+        payload(&env, &alice, 100, 2),
+        payload(&env, &bob, 50, 2),
+    ];
+    client.batch_mint(&payloads, &sign_batch(&env, &signing_key, &payloads));
+
+    assert_eq!(client.get_total_minted(), 850);
+    assert_eq!(client.get_minted_by_user(&alice), 600);
+    assert_eq!(client.get_minted_by_user(&bob), 250);
+}
+
+#[test]
+fn test_zero_amount_mint_leaves_totals_unchanged() {
+    let (env, client, signing_key) = setup();
+    let user = Address::generate(&env);
+
+    let first = payload(&env, &user, 300, 1);
+    client.mint(&first, &sign(&env, &signing_key, &first));
+    let zero = payload(&env, &user, 0, 2);
+    assert_eq!(client.mint(&zero, &sign(&env, &signing_key, &zero)), 0);
+
+    assert_eq!(client.get_total_minted(), 300);
+    assert_eq!(client.get_minted_by_user(&user), 300);
+}
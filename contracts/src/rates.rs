@@ -1,7 +1,16 @@
 use crate::governance;
-use crate::storage_types::DataKey;
+use crate::storage_types::{ConfigKey, DataKey, GoalSave, LockSave, PlanType, SavingsPlan};
 use crate::SavingsError;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+
+/// Rate (bps) for locks whose duration is below every configured tier
+pub const DEFAULT_LOCK_RATE: u32 = 500;
+
+/// Rate (bps) for goals and groups when no `GoalRate`/`GroupRate` is configured
+pub const DEFAULT_PLAN_RATE: u32 = 500;
+
+/// Highest rate (bps) an admin may grant to a single plan
+pub const MAX_PLAN_RATE: i128 = 10_000;
 
 // --- Admin Setters (with governance transition) ---
 
@@ -53,6 +62,83 @@ pub fn set_lock_rate(
     env.storage()
         .instance()
         .set(&DataKey::LockRate(duration_days), &rate);
+
+    // Track the tier so lock creation can find the best match
+    let mut tiers = get_lock_rate_tiers(env);
+    if !tiers.contains(duration_days) {
+        let position = tiers
+            .iter()
+            .position(|days| days > duration_days)
+            .unwrap_or(tiers.len() as usize);
+        tiers.insert(position as u32, duration_days);
+        env.storage()
+            .instance()
+            .set(&ConfigKey::LockRateTiers, &tiers);
+    }
+    Ok(())
+}
+
+/// Overrides the interest rate of one of `user`'s plans (admin only).
+///
+/// `plan_type` says which kind of plan `plan_id` refers to, since Lock and
+/// Goal IDs come from separate counters and can collide. Only its variant is
+/// matched; the fields it carries are ignored. The new rate is used by every
+/// later yield calculation for that plan.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+/// * `InvalidInterestRate` - If `rate` is negative or above `MAX_PLAN_RATE`
+/// * `PlanNotFound` - If `user` owns no plan of `plan_type` with `plan_id`
+pub fn set_plan_rate(
+    env: &Env,
+    admin: Address,
+    user: Address,
+    plan_type: PlanType,
+    plan_id: u64,
+    rate: i128,
+) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    if !(0..=MAX_PLAN_RATE).contains(&rate) {
+        return Err(SavingsError::InvalidInterestRate);
+    }
+    let rate = rate as u32;
+
+    let storage = env.storage().persistent();
+    match plan_type {
+        PlanType::Lock(_) => {
+            let lock_key = DataKey::LockSave(plan_id);
+            let mut lock_save: LockSave = storage
+                .get(&lock_key)
+                .filter(|lock_save: &LockSave| lock_save.owner == user)
+                .ok_or(SavingsError::PlanNotFound)?;
+            lock_save.interest_rate = rate;
+            storage.set(&lock_key, &lock_save);
+        }
+        PlanType::Goal(..) => {
+            let goal_key = DataKey::GoalSave(plan_id);
+            let mut goal_save: GoalSave = storage
+                .get(&goal_key)
+                .filter(|goal_save: &GoalSave| goal_save.owner == user)
+                .ok_or(SavingsError::PlanNotFound)?;
+            goal_save.interest_rate = rate;
+            storage.set(&goal_key, &goal_save);
+        }
+        PlanType::Flexi | PlanType::Group(..) => {
+            let plan_key = DataKey::SavingsPlan(user.clone(), plan_id);
+            let mut plan: SavingsPlan = storage
+                .get(&plan_key)
+                .filter(|plan: &SavingsPlan| {
+                    core::mem::discriminant(&plan.plan_type) == core::mem::discriminant(&plan_type)
+                })
+                .ok_or(SavingsError::PlanNotFound)?;
+            plan.interest_rate = rate;
+            storage.set(&plan_key, &plan);
+        }
+    }
+
+    env.events()
+        .publish((symbol_short!("rate_ovr"), user, plan_id), rate);
     Ok(())
 }
 
@@ -86,6 +172,60 @@ pub fn get_lock_rate(env: &Env, duration_days: u64) -> Result<i128, SavingsError
         .ok_or(SavingsError::PlanNotFound)
 }
 
+/// Returns the configured lock tiers (in days), ascending.
+pub fn get_lock_rate_tiers(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::LockRateTiers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Resolves the interest rate for a lock of `duration_seconds`.
+///
+/// Uses the highest configured tier whose `duration_days` does not exceed the
+/// lock's duration (in whole days), or `DEFAULT_LOCK_RATE` if none applies.
+///
+/// # Errors
+/// * `InvalidInterestRate` - If the matched tier's rate does not fit in a `u32`
+pub fn resolve_lock_rate(env: &Env, duration_seconds: u64) -> Result<u32, SavingsError> {
+    let duration_days = duration_seconds / 86_400;
+    let mut rate = None;
+    for tier_days in get_lock_rate_tiers(env).iter() {
+        if tier_days > duration_days {
+            break;
+        }
+        rate = get_lock_rate(env, tier_days).ok();
+    }
+
+    match rate {
+        Some(rate) => u32::try_from(rate).map_err(|_| SavingsError::InvalidInterestRate),
+        None => Ok(DEFAULT_LOCK_RATE),
+    }
+}
+
+/// Returns the configured goal rate, or `DEFAULT_PLAN_RATE` if unset.
+///
+/// # Errors
+/// * `InvalidInterestRate` - If the configured rate does not fit in a `u32`
+pub fn resolve_goal_rate(env: &Env) -> Result<u32, SavingsError> {
+    resolve_plan_rate(env, &DataKey::GoalRate)
+}
+
+/// Returns the configured group rate, or `DEFAULT_PLAN_RATE` if unset.
+///
+/// # Errors
+/// * `InvalidInterestRate` - If the configured rate does not fit in a `u32`
+pub fn resolve_group_rate(env: &Env) -> Result<u32, SavingsError> {
+    resolve_plan_rate(env, &DataKey::GroupRate)
+}
+
+fn resolve_plan_rate(env: &Env, key: &DataKey) -> Result<u32, SavingsError> {
+    match env.storage().instance().get::<DataKey, i128>(key) {
+        Some(rate) => u32::try_from(rate).map_err(|_| SavingsError::InvalidInterestRate),
+        None => Ok(DEFAULT_PLAN_RATE),
+    }
+}
+
 // --- Interest Calculation Helpers ---
 
 pub fn calculate_flexi_interest(balance: i128, rate: i128, duration_seconds: u64) -> i128 {
@@ -131,3 +271,8 @@ pub fn calculate_lock_interest(amount: i128, rate: i128) -> i128 {
 
     amount.checked_mul(rate).unwrap_or(0) / 10000
 }
+
+/// Simple interest for goals and groups, accrued the same way as Flexi.
+pub fn calculate_plan_interest(balance: i128, rate: u32, duration_seconds: u64) -> i128 {
+    calculate_flexi_interest(balance, rate as i128, duration_seconds)
+}
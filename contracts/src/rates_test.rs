@@ -115,3 +115,52 @@ fn test_invalid_rates() {
     let res = client.try_set_flexi_rate(&admin, &-100);
     assert_eq!(res.unwrap_err(), Ok(SavingsError::InvalidInterestRate));
 }
+
+#[test]
+fn test_lock_creation_uses_highest_matching_rate_tier() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+
+    // Configure out of order to exercise tier sorting
+    client.set_lock_rate(&admin, &180, &900);
+    client.set_lock_rate(&admin, &30, &300);
+    client.set_lock_rate(&admin, &90, &600);
+
+    let day = 86_400u64;
+    let cases = [
+        (10 * day, 500u32), // below every tier: default rate
+        (30 * day, 300),
+        (89 * day, 300),
+        (100 * day, 600),
+        (365 * day, 900),
+    ];
+    for (duration, _) in cases.iter() {
+        client.create_lock_save(&user, &1_000, duration, &false);
+    }
+
+    let locks = client.get_active_lock_saves(&user);
+    assert_eq!(locks.len(), cases.len() as u32);
+    for (i, (_, expected_rate)) in cases.iter().enumerate() {
+        assert_eq!(locks.get(i as u32).unwrap().interest_rate, *expected_rate);
+    }
+}
+
+#[test]
+fn test_updating_lock_tier_does_not_duplicate_it() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+
+    client.set_lock_rate(&admin, &30, &300);
+    client.set_lock_rate(&admin, &30, &450);
+
+    let lock_id = client.create_lock_save(&user, &1_000, &(45 * 86_400), &false);
+    let lock = client.get_active_lock_saves(&user).get(0).unwrap();
+    assert_eq!(lock.id, lock_id);
+    assert_eq!(lock.interest_rate, 450);
+    assert_eq!(
+        env.as_contract(&client.address, || crate::rates::get_lock_rate_tiers(&env)),
+        soroban_sdk::vec![&env, 30u64]
+    );
+}
@@ -0,0 +1,83 @@
+use crate::errors::SavingsError;
+use crate::storage_types::DataKey;
+use soroban_sdk::Env;
+
+/// Scoped lock held for the duration of a function that calls out to an
+/// external strategy contract.
+///
+/// Acquiring sets `DataKey::ReentrancyGuard` in temporary storage; the flag is
+/// cleared when the guard is dropped, including on early `?` returns, so a
+/// guarded function cannot forget to release it.
+///
+/// ```ignore
+/// let _guard = ReentrancyGuard::acquire(env)?;
+/// // ... external strategy calls ...
+/// ```
+pub(crate) struct ReentrancyGuard<'a> {
+    env: &'a Env,
+}
+
+impl<'a> ReentrancyGuard<'a> {
+    /// Takes the guard.
+    ///
+    /// # Errors
+    /// * `Reentrancy` - If the guard is already held by an outer call
+    pub(crate) fn acquire(env: &'a Env) -> Result<Self, SavingsError> {
+        let storage = env.storage().temporary();
+        if storage.has(&DataKey::ReentrancyGuard) {
+            return Err(SavingsError::Reentrancy);
+        }
+        storage.set(&DataKey::ReentrancyGuard, &true);
+        Ok(ReentrancyGuard { env })
+    }
+}
+
+impl Drop for ReentrancyGuard<'_> {
+    fn drop(&mut self) {
+        self.env
+            .storage()
+            .temporary()
+            .remove(&DataKey::ReentrancyGuard);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NesteraContract;
+
+    #[test]
+    fn test_guard_rejects_nested_acquire_and_releases_on_drop() {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+
+        env.as_contract(&contract_id, || {
+            let guard = ReentrancyGuard::acquire(&env).unwrap();
+            assert_eq!(
+                ReentrancyGuard::acquire(&env).err(),
+                Some(SavingsError::Reentrancy)
+            );
+            drop(guard);
+
+            assert!(ReentrancyGuard::acquire(&env).is_ok());
+            // The temporary guard above was dropped at the end of the statement
+            assert!(!env.storage().temporary().has(&DataKey::ReentrancyGuard));
+        });
+    }
+
+    #[test]
+    fn test_guard_released_on_error_path() {
+        fn guarded(env: &Env) -> Result<(), SavingsError> {
+            let _guard = ReentrancyGuard::acquire(env)?;
+            Err(SavingsError::InvalidAmount)
+        }
+
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(guarded(&env), Err(SavingsError::InvalidAmount));
+            assert!(ReentrancyGuard::acquire(&env).is_ok());
+        });
+    }
+}
@@ -1,4 +1,4 @@
-use super::storage_types::{RewardsConfig, RewardsDataKey};
+use super::storage_types::{BonusWindow, RewardsConfig, RewardsDataKey};
 use crate::errors::SavingsError;
 use soroban_sdk::{Address, Env};
 
@@ -53,9 +53,95 @@ pub fn get_rewards_config(env: &Env) -> Result<RewardsConfig, SavingsError> {
         .ok_or(SavingsError::InternalError) // Consider adding ConfigNotInitialized to errors.rs
 }
 
+/// Fetches the rewards configuration, or a disabled default if none is stored.
+///
+/// The default has `enabled: false` and every rate, bonus and limit at 0, so
+/// read paths can treat "not configured" the same as "rewards off". Use
+/// `get_rewards_config` to tell the two apart.
+pub fn get_rewards_config_or_default(env: &Env) -> RewardsConfig {
+    get_rewards_config(env).unwrap_or(RewardsConfig {
+        points_per_token: 0,
+        streak_bonus_bps: 0,
+        long_lock_bonus_bps: 0,
+        goal_completion_bonus: 0,
+        enabled: false,
+        min_deposit_for_rewards: 0,
+        action_cooldown_seconds: 0,
+        max_daily_points: 0,
+        max_streak_multiplier: 0,
+        streak_window_secs: 0,
+        streak_threshold: 0,
+        maturity_bonus_bps: 0,
+    })
+}
+
+/// Sets the goal completion bonus as basis points of the goal target.
+/// When non-zero it replaces the flat `goal_completion_bonus`; 0 restores it.
+pub fn set_goal_completion_bonus_bps(
+    env: &Env,
+    admin: Address,
+    bps: u32,
+) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    if bps > 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::GoalBonusBps, &bps);
+    Ok(())
+}
+
+/// Returns the goal completion bonus in basis points of target (0 = flat bonus).
+pub fn get_goal_completion_bonus_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::GoalBonusBps)
+        .unwrap_or(0)
+}
+
+/// Opens a promotional window in which deposits earn an extra
+/// `multiplier_bps` of their base points. Replaces any existing window.
+pub fn set_bonus_window(
+    env: &Env,
+    admin: Address,
+    start: u64,
+    end: u64,
+    multiplier_bps: u32,
+) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    if end <= start {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+    if multiplier_bps > 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+
+    let window = BonusWindow {
+        start,
+        end,
+        multiplier_bps,
+    };
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::BonusWindow, &window);
+    Ok(())
+}
+
+/// Returns the configured promotional window, if any.
+pub fn get_bonus_window(env: &Env) -> Option<BonusWindow> {
+    env.storage().instance().get(&RewardsDataKey::BonusWindow)
+}
+
 /// Validates that bonus rates are within 0-100% (0-10000 BPS).
 fn validate_config(config: &RewardsConfig) -> Result<(), SavingsError> {
-    if config.streak_bonus_bps > 10_000 || config.long_lock_bonus_bps > 10_000 {
+    if config.streak_bonus_bps > 10_000
+        || config.long_lock_bonus_bps > 10_000
+        || config.maturity_bonus_bps > 10_000
+    {
         return Err(SavingsError::InvalidFeeBps);
     }
 
@@ -21,6 +21,7 @@ pub struct BonusAwarded {
 pub struct PointsRedeemed {
     pub user: Address,
     pub amount: u128,
+    pub redemption_type: Symbol, // e.g., "generic", "rate_bst"
 }
 
 #[contracttype]
@@ -56,10 +57,11 @@ pub fn emit_bonus_awarded(env: &Env, user: Address, amount: u128, bonus_type: Sy
 }
 
 /// Emits a PointsRedeemed event.
-pub fn emit_points_redeemed(env: &Env, user: Address, amount: u128) {
+pub fn emit_points_redeemed(env: &Env, user: Address, amount: u128, redemption_type: Symbol) {
     let event = PointsRedeemed {
         user: user.clone(),
         amount,
+        redemption_type,
     };
     env.events().publish(
         (symbol_short!("rewards"), symbol_short!("redeem"), user),
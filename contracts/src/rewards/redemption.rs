@@ -3,7 +3,17 @@
 use crate::errors::SavingsError;
 use crate::rewards::events::emit_points_redeemed;
 use crate::rewards::storage::{get_user_rewards, save_user_rewards};
-use soroban_sdk::{Address, Env};
+use crate::rewards::storage_types::{RateBoost, RewardsDataKey};
+use soroban_sdk::{symbol_short, Address, Env};
+
+/// Points required per basis point of lock-rate boost per day.
+pub const POINTS_PER_BOOST_BPS_DAY: u128 = 10;
+
+/// Highest lock-rate boost a single redemption can buy.
+pub const MAX_RATE_BOOST_BPS: u32 = 200;
+
+/// Longest a redeemed rate boost can last.
+pub const MAX_RATE_BOOST_DAYS: u64 = 365;
 
 /// Redeem points for protocol benefits (fee discounts, boost multiplier, etc.)
 ///
@@ -44,7 +54,73 @@ pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), Savin
     save_user_rewards(env, user.clone(), &rewards);
 
     // Emit redemption event
-    emit_points_redeemed(env, user, amount);
+    emit_points_redeemed(env, user, amount, symbol_short!("generic"));
 
     Ok(())
 }
+
+/// Redeem points for a temporary boost on the rate of newly created locks.
+///
+/// The boost is `points / (duration_days * POINTS_PER_BOOST_BPS_DAY)` basis
+/// points and replaces any boost the user already holds.
+///
+/// # Errors
+/// * `InvalidAmount` - If `points` is zero, `duration_days` is outside
+///   `1..=MAX_RATE_BOOST_DAYS`, or the points buy less than 1 bps
+/// * `AmountExceedsLimit` - If the boost would exceed `MAX_RATE_BOOST_BPS`
+/// * `InsufficientBalance` - If the user has fewer than `points`
+pub fn redeem_for_rate_boost(
+    env: &Env,
+    user: Address,
+    points: u128,
+    duration_days: u64,
+) -> Result<u32, SavingsError> {
+    if points == 0 || duration_days == 0 || duration_days > MAX_RATE_BOOST_DAYS {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let boost_bps = points / (duration_days as u128 * POINTS_PER_BOOST_BPS_DAY);
+    if boost_bps == 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if boost_bps > MAX_RATE_BOOST_BPS as u128 {
+        return Err(SavingsError::AmountExceedsLimit);
+    }
+    let boost_bps = boost_bps as u32;
+
+    let mut rewards = get_user_rewards(env, user.clone());
+    if rewards.total_points < points {
+        return Err(SavingsError::InsufficientBalance);
+    }
+    rewards.total_points -= points;
+    save_user_rewards(env, user.clone(), &rewards);
+
+    let expires_at = env
+        .ledger()
+        .timestamp()
+        .checked_add(duration_days * 86_400)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(
+        &RewardsDataKey::RateBoostBps(user.clone()),
+        &RateBoost {
+            boost_bps,
+            expires_at,
+        },
+    );
+
+    emit_points_redeemed(env, user, points, symbol_short!("rate_bst"));
+
+    Ok(boost_bps)
+}
+
+/// Returns the user's rate boost in bps, or 0 if none is active.
+pub fn active_rate_boost_bps(env: &Env, user: &Address) -> u32 {
+    let boost: Option<RateBoost> = env
+        .storage()
+        .persistent()
+        .get(&RewardsDataKey::RateBoostBps(user.clone()));
+    match boost {
+        Some(boost) if env.ledger().timestamp() < boost.expires_at => boost.boost_bps,
+        _ => 0,
+    }
+}
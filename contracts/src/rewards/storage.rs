@@ -1,5 +1,5 @@
-use super::storage_types::{RewardsDataKey, UserRewards};
-use crate::errors::SavingsError;
+use super::storage_types::{RewardsConfig, RewardsDataKey, UserRewards};
+use crate::errors::{overflow, SavingsError};
 use crate::rewards::config::get_rewards_config;
 use crate::rewards::events::{emit_bonus_awarded, emit_points_awarded, emit_streak_updated};
 use soroban_sdk::{Address, Env, Symbol};
@@ -11,6 +11,34 @@ pub const STREAK_WINDOW_SECS: u64 = 7 * 24 * 60 * 60;
 /// Minimum streak length required before streak bonus points are applied.
 pub const STREAK_BONUS_THRESHOLD: u32 = 3;
 
+/// Returns the configured streak window, or `STREAK_WINDOW_SECS` when unset.
+fn streak_window(config: Option<&RewardsConfig>) -> u64 {
+    match config {
+        Some(config) if config.streak_window_secs > 0 => config.streak_window_secs,
+        _ => STREAK_WINDOW_SECS,
+    }
+}
+
+/// Extra points from the promotional window when `now` falls inside it.
+fn bonus_window_points(env: &Env, base_points: u128, now: u64) -> Result<u128, SavingsError> {
+    match super::config::get_bonus_window(env) {
+        Some(window) if now >= window.start && now < window.end => Ok(base_points
+            .checked_mul(window.multiplier_bps as u128)
+            .ok_or(SavingsError::Overflow)?
+            / 10_000u128),
+        _ => Ok(0),
+    }
+}
+
+/// Returns the configured streak bonus threshold, or `STREAK_BONUS_THRESHOLD` when unset.
+fn streak_threshold(config: &RewardsConfig) -> u32 {
+    if config.streak_threshold > 0 {
+        config.streak_threshold
+    } else {
+        STREAK_BONUS_THRESHOLD
+    }
+}
+
 /// Fetches user rewards or returns a default empty state
 pub fn get_user_rewards(env: &Env, user: Address) -> UserRewards {
     let key = RewardsDataKey::UserLedger(user);
@@ -37,7 +65,9 @@ pub fn get_user_rewards(env: &Env, user: Address) -> UserRewards {
 
 /// Force-saves the user rewards state
 pub fn save_user_rewards(env: &Env, user: Address, rewards: &UserRewards) {
-    let key = RewardsDataKey::UserLedger(user);
+    let key = RewardsDataKey::UserLedger(user.clone());
+    let previous = env.storage().persistent().get(&key);
+    crate::governance::checkpoint_voting_power(env, &user, previous, rewards);
     env.storage().persistent().set(&key, rewards);
     env.storage().persistent().extend_ttl(&key, 17280, 17280);
 }
@@ -88,8 +118,11 @@ pub fn reset_streak(env: &Env, user: Address) {
 ///
 /// Rules:
 /// - First tracked action starts streak at 1.
-/// - If elapsed time is <= STREAK_WINDOW_SECS, streak increments.
-/// - If elapsed time is > STREAK_WINDOW_SECS, streak resets to 1.
+/// - If elapsed time is <= the streak window, streak increments.
+/// - If elapsed time is > the streak window, streak resets to 1.
+///
+/// The window is `streak_window_secs` from the rewards config, falling back
+/// to STREAK_WINDOW_SECS.
 ///
 /// Note: last_action_timestamp==0 with current_streak>0 means the previous action was at
 /// ledger time 0; we must use elapsed logic, not treat it as "first action".
@@ -97,27 +130,109 @@ pub fn update_streak(env: &Env, user: Address) -> Result<u32, SavingsError> {
     let mut rewards = get_user_rewards(env, user.clone());
     let now = env.ledger().timestamp();
 
-    let is_first_ever = rewards.last_action_timestamp == 0 && rewards.current_streak == 0;
+    let window = streak_window(get_rewards_config(env).ok().as_ref());
 
-    rewards.current_streak = if is_first_ever {
-        1
-    } else {
-        let elapsed = now.saturating_sub(rewards.last_action_timestamp);
-        if elapsed <= STREAK_WINDOW_SECS {
-            rewards
-                .current_streak
-                .checked_add(1)
-                .ok_or(SavingsError::Overflow)?
-        } else {
-            1
-        }
-    };
+    rewards.current_streak = next_streak(&rewards, now, window)?;
     rewards.last_action_timestamp = now;
     save_user_rewards(env, user.clone(), &rewards);
     emit_streak_updated(env, user, rewards.current_streak);
     Ok(rewards.current_streak)
 }
 
+/// Computes the streak an action at `now` would produce, per `update_streak`'s rules.
+fn next_streak(rewards: &UserRewards, now: u64, window: u64) -> Result<u32, SavingsError> {
+    let is_first_ever = rewards.last_action_timestamp == 0 && rewards.current_streak == 0;
+
+    if is_first_ever {
+        return Ok(1);
+    }
+    let elapsed = now.saturating_sub(rewards.last_action_timestamp);
+    if elapsed <= window {
+        rewards
+            .current_streak
+            .checked_add(1)
+            .ok_or(SavingsError::Overflow)
+    } else {
+        Ok(1)
+    }
+}
+
+/// Streak bonus on `base_points`, applied once `streak` reaches the streak threshold.
+fn calculate_streak_bonus(
+    config: &RewardsConfig,
+    base_points: u128,
+    streak: u32,
+) -> Result<u128, SavingsError> {
+    if streak >= streak_threshold(config) && config.streak_bonus_bps > 0 {
+        let effective_bonus_bps = config.streak_bonus_bps.min(config.max_streak_multiplier);
+        Ok(base_points
+            .checked_mul(effective_bonus_bps as u128)
+            .ok_or(SavingsError::Overflow)?
+            / 10_000u128)
+    } else {
+        Ok(0)
+    }
+}
+
+/// Simulates `award_deposit_points` for `amount` without touching state.
+///
+/// Returns (base_points, streak_bonus_points) after the same eligibility
+/// checks and daily cap; (0, 0) when the deposit would earn nothing.
+pub fn preview_deposit_rewards(
+    env: &Env,
+    user: Address,
+    amount: i128,
+) -> Result<(u128, u128), SavingsError> {
+    if amount <= 0 {
+        return Ok((0, 0));
+    }
+
+    let config = match get_rewards_config(env) {
+        Ok(config) if config.enabled => config,
+        _ => return Ok((0, 0)),
+    };
+
+    if amount < config.min_deposit_for_rewards {
+        return Ok((0, 0));
+    }
+
+    let user_rewards = get_user_rewards(env, user);
+    let now = env.ledger().timestamp();
+    let current_day = now / 86400;
+
+    let is_first_action = user_rewards.lifetime_deposited == 0;
+    if !is_first_action
+        && now.saturating_sub(user_rewards.last_action_timestamp) < config.action_cooldown_seconds
+    {
+        return Ok((0, 0));
+    }
+
+    let daily_points_earned = if current_day > user_rewards.last_reward_day {
+        0
+    } else {
+        user_rewards.daily_points_earned
+    };
+    if daily_points_earned >= config.max_daily_points {
+        return Ok((0, 0));
+    }
+
+    let streak = next_streak(&user_rewards, now, streak_window(Some(&config)))?;
+    let base_points = (amount as u128)
+        .checked_mul(config.points_per_token as u128)
+        .ok_or(SavingsError::Overflow)?;
+    let streak_bonus_points = calculate_streak_bonus(&config, base_points, streak)?;
+    let bonus_points = streak_bonus_points
+        .checked_add(bonus_window_points(env, base_points, now)?)
+        .ok_or(SavingsError::Overflow)?;
+
+    // Same daily cap as the real award, taken from base points first
+    let remaining_daily = config.max_daily_points.saturating_sub(daily_points_earned);
+    let capped_base = base_points.min(remaining_daily);
+    let capped_bonus = bonus_points.min(remaining_daily - capped_base);
+
+    Ok((capped_base, capped_bonus))
+}
+
 pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<(), SavingsError> {
     if amount <= 0 {
         return Ok(());
@@ -138,9 +253,10 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
     let now = env.ledger().timestamp();
     let current_day = now / 86400;
 
-    // ANTI-FARMING: Check action cooldown (skip for first ever action)
-    let is_first_action =
-        user_rewards.last_action_timestamp == 0 && user_rewards.current_streak == 0;
+    // ANTI-FARMING: Check action cooldown (skip for first rewarded deposit).
+    // `initialize_user_rewards` stamps `last_action_timestamp` at registration, so
+    // the timestamp alone cannot tell us whether a deposit was ever rewarded.
+    let is_first_action = user_rewards.lifetime_deposited == 0;
     if !is_first_action
         && now.saturating_sub(user_rewards.last_action_timestamp) < config.action_cooldown_seconds
     {
@@ -159,28 +275,27 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
     }
 
     // 2. Update streak first (time-window boundary handling)
+    // Only the streak fields are refreshed so the daily bucket reset above is kept.
     let streak = update_streak(env, user.clone())?;
-    user_rewards = get_user_rewards(env, user.clone()); // Refresh after streak update
+    let refreshed = get_user_rewards(env, user.clone());
+    user_rewards.current_streak = refreshed.current_streak;
+    user_rewards.last_action_timestamp = refreshed.last_action_timestamp;
 
     // 3. Calculate Base Points
     let base_points = (amount as u128)
         .checked_mul(config.points_per_token as u128)
-        .ok_or(SavingsError::Overflow)?;
+        .ok_or_else(|| overflow(env, "points_mul"))?;
 
     // 4. Optional streak bonus with max multiplier cap
-    let streak_bonus_points = if streak >= STREAK_BONUS_THRESHOLD && config.streak_bonus_bps > 0 {
-        let effective_bonus_bps = config.streak_bonus_bps.min(config.max_streak_multiplier);
-        base_points
-            .checked_mul(effective_bonus_bps as u128)
-            .ok_or(SavingsError::Overflow)?
-            / 10_000u128
-    } else {
-        0
-    };
+    let streak_bonus_points = calculate_streak_bonus(&config, base_points, streak)?;
+
+    // 5. Promotional window bonus
+    let promo_points = bonus_window_points(env, base_points, now)?;
 
     let total_points_awarded = base_points
         .checked_add(streak_bonus_points)
-        .ok_or(SavingsError::Overflow)?;
+        .and_then(|points| points.checked_add(promo_points))
+        .ok_or_else(|| overflow(env, "points_add"))?;
 
     // ANTI-FARMING: Cap to remaining daily allowance
     let remaining_daily = config
@@ -192,23 +307,23 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         return Ok(()); // Nothing to award after capping
     }
 
-    // 5. Update State
+    // 6. Update State
     user_rewards.total_points = user_rewards
         .total_points
         .checked_add(capped_points)
-        .ok_or(SavingsError::Overflow)?;
+        .ok_or_else(|| overflow(env, "points_add"))?;
 
     user_rewards.daily_points_earned = user_rewards
         .daily_points_earned
         .checked_add(capped_points)
-        .ok_or(SavingsError::Overflow)?;
+        .ok_or_else(|| overflow(env, "daily_points_add"))?;
 
     user_rewards.lifetime_deposited = user_rewards
         .lifetime_deposited
         .checked_add(amount)
-        .ok_or(SavingsError::Overflow)?;
+        .ok_or_else(|| overflow(env, "deposited_add"))?;
 
-    // 6. Save and Emit Event
+    // 7. Save and Emit Event
     save_user_rewards(env, user.clone(), &user_rewards);
 
     // Track user for ranking leaderboard
@@ -216,9 +331,15 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
 
     emit_points_awarded(env, user.clone(), capped_points);
 
-    if streak_bonus_points > 0 && capped_points > base_points {
-        let actual_bonus = capped_points.saturating_sub(base_points);
-        emit_bonus_awarded(env, user, actual_bonus, Symbol::new(env, "streak"));
+    // Capping trims the promo bonus before the streak bonus
+    let actual_bonus = capped_points.saturating_sub(base_points);
+    let actual_streak = streak_bonus_points.min(actual_bonus);
+    let actual_promo = actual_bonus - actual_streak;
+    if actual_streak > 0 {
+        emit_bonus_awarded(env, user.clone(), actual_streak, Symbol::new(env, "streak"));
+    }
+    if actual_promo > 0 {
+        emit_bonus_awarded(env, user, actual_promo, Symbol::new(env, "promo"));
     }
 
     Ok(())
@@ -231,48 +352,111 @@ pub fn award_long_lock_bonus(
     amount: i128,
     duration: u64,
 ) -> Result<u128, SavingsError> {
-    if amount <= 0 || duration <= LONG_LOCK_BONUS_THRESHOLD_SECS {
-        return Ok(0);
-    }
-
     let config = match get_rewards_config(env) {
         Ok(config) if config.enabled => config,
         _ => return Ok(0),
     };
 
-    if config.long_lock_bonus_bps == 0 || config.points_per_token == 0 {
+    let bonus_points = long_lock_bonus_points(&config, amount, duration)?;
+    if bonus_points == 0 {
+        return Ok(0);
+    }
+
+    add_points(env, user.clone(), bonus_points)?;
+    emit_bonus_awarded(env, user, bonus_points, Symbol::new(env, "lock"));
+    Ok(bonus_points)
+}
+
+/// Long-lock bonus for locking `amount` for `duration` under `config`.
+pub(crate) fn long_lock_bonus_points(
+    config: &RewardsConfig,
+    amount: i128,
+    duration: u64,
+) -> Result<u128, SavingsError> {
+    if amount <= 0 || duration <= LONG_LOCK_BONUS_THRESHOLD_SECS {
+        return Ok(0);
+    }
+    bps_of_base_points(config, amount, config.long_lock_bonus_bps)
+}
+
+/// `bonus_bps` of the base points `amount` earns under `config`.
+fn bps_of_base_points(
+    config: &RewardsConfig,
+    amount: i128,
+    bonus_bps: u32,
+) -> Result<u128, SavingsError> {
+    if bonus_bps == 0 || config.points_per_token == 0 {
         return Ok(0);
     }
 
     let base_points = (amount as u128)
         .checked_mul(config.points_per_token as u128)
         .ok_or(SavingsError::Overflow)?;
-    let bonus_points = base_points
-        .checked_mul(config.long_lock_bonus_bps as u128)
+    Ok(base_points
+        .checked_mul(bonus_bps as u128)
         .ok_or(SavingsError::Overflow)?
-        / 10_000u128;
+        / 10_000u128)
+}
 
+/// Awards the maturity bonus when a lock is withdrawn at or after maturity.
+///
+/// The bonus is `maturity_bonus_bps` of the points the principal would earn
+/// as a deposit. Locks closed early through other paths earn nothing.
+pub fn award_maturity_bonus(env: &Env, user: Address, amount: i128) -> Result<u128, SavingsError> {
+    let config = match get_rewards_config(env) {
+        Ok(config) if config.enabled => config,
+        _ => return Ok(0),
+    };
+
+    let bonus_points = maturity_bonus_points(&config, amount)?;
     if bonus_points == 0 {
         return Ok(0);
     }
 
     add_points(env, user.clone(), bonus_points)?;
-    emit_bonus_awarded(env, user, bonus_points, Symbol::new(env, "lock"));
+    emit_bonus_awarded(env, user, bonus_points, Symbol::new(env, "maturity"));
     Ok(bonus_points)
 }
 
-/// Awards a fixed goal completion bonus when a goal reaches its target.
-pub fn award_goal_completion_bonus(env: &Env, user: Address) -> Result<u128, SavingsError> {
+/// Maturity bonus for withdrawing a lock of `amount` under `config`.
+pub(crate) fn maturity_bonus_points(
+    config: &RewardsConfig,
+    amount: i128,
+) -> Result<u128, SavingsError> {
+    if amount <= 0 {
+        return Ok(0);
+    }
+    bps_of_base_points(config, amount, config.maturity_bonus_bps)
+}
+
+/// Awards the goal completion bonus when a goal reaches its target.
+///
+/// Uses `goal_completion_bonus_bps` of `target_amount` when that is set,
+/// otherwise the flat `goal_completion_bonus`.
+pub fn award_goal_completion_bonus(
+    env: &Env,
+    user: Address,
+    target_amount: i128,
+) -> Result<u128, SavingsError> {
     let config = match get_rewards_config(env) {
         Ok(config) if config.enabled => config,
         _ => return Ok(0),
     };
 
-    if config.goal_completion_bonus == 0 {
+    let bonus_bps = super::config::get_goal_completion_bonus_bps(env);
+    let bonus_points = if bonus_bps > 0 {
+        (target_amount.max(0) as u128)
+            .checked_mul(bonus_bps as u128)
+            .ok_or(SavingsError::Overflow)?
+            / 10_000u128
+    } else {
+        config.goal_completion_bonus as u128
+    };
+
+    if bonus_points == 0 {
         return Ok(0);
     }
 
-    let bonus_points = config.goal_completion_bonus as u128;
     add_points(env, user.clone(), bonus_points)?;
     emit_bonus_awarded(env, user, bonus_points, Symbol::new(env, "goal"));
     Ok(bonus_points)
@@ -315,6 +499,9 @@ mod tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         }
     }
 
@@ -353,6 +540,38 @@ mod tests {
         assert_eq!(rewards.current_streak, 1);
     }
 
+    #[test]
+    fn test_configured_streak_window_resets_streak() {
+        let mut config = default_rewards_config();
+        config.streak_window_secs = 24 * 60 * 60;
+        let (env, client, _) = setup_env_with_rewards(config);
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        create_plan_deposit(&client, &user, 100);
+        env.ledger().with_mut(|li| li.timestamp += 2 * 24 * 60 * 60);
+        create_plan_deposit(&client, &user, 100);
+
+        let rewards = client.get_user_rewards(&user);
+        assert_eq!(rewards.current_streak, 1);
+    }
+
+    #[test]
+    fn test_default_streak_window_keeps_streak_after_two_days() {
+        let (env, client, _) = setup_env_with_rewards(default_rewards_config());
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        create_plan_deposit(&client, &user, 100);
+        env.ledger().with_mut(|li| li.timestamp += 2 * 24 * 60 * 60);
+        create_plan_deposit(&client, &user, 100);
+
+        let rewards = client.get_user_rewards(&user);
+        assert_eq!(rewards.current_streak, 2);
+    }
+
     #[test]
     fn test_streak_bonus_config_applied_when_enabled() {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
@@ -420,6 +639,75 @@ mod tests {
         assert_eq!(rewards.total_points, 3_200);
     }
 
+    #[test]
+    fn test_preview_deposit_rewards_matches_award_below_threshold() {
+        let (env, client, _) = setup_env_with_rewards(default_rewards_config());
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        let preview = client.preview_deposit_rewards(&user, &100);
+        assert_eq!(preview, (1_000, 0));
+        let before = client.get_user_rewards(&user);
+        assert_eq!(before.current_streak, 0);
+
+        create_plan_deposit(&client, &user, 100);
+        let after = client.get_user_rewards(&user);
+        assert_eq!(
+            after.total_points - before.total_points,
+            preview.0 + preview.1
+        );
+    }
+
+    #[test]
+    fn test_preview_deposit_rewards_matches_award_with_streak_bonus() {
+        let (env, client, _) = setup_env_with_rewards(default_rewards_config());
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        create_plan_deposit(&client, &user, 100);
+        env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+        create_plan_deposit(&client, &user, 100);
+        env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+
+        // Third deposit crosses STREAK_BONUS_THRESHOLD: 20% of 2500 base
+        let preview = client.preview_deposit_rewards(&user, &250);
+        assert_eq!(preview, (2_500, 500));
+        let before = client.get_user_rewards(&user);
+        assert_eq!(before.current_streak, 2);
+
+        create_plan_deposit(&client, &user, 250);
+        let after = client.get_user_rewards(&user);
+        assert_eq!(
+            after.total_points - before.total_points,
+            preview.0 + preview.1
+        );
+        assert_eq!(after.current_streak, 3);
+    }
+
+    #[test]
+    fn test_preview_deposit_rewards_respects_daily_cap() {
+        let mut config = default_rewards_config();
+        config.max_daily_points = 1_500;
+        let (env, client, _) = setup_env_with_rewards(config);
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        create_plan_deposit(&client, &user, 100);
+        let preview = client.preview_deposit_rewards(&user, &100);
+        assert_eq!(preview, (500, 0));
+
+        let before = client.get_user_rewards(&user);
+        create_plan_deposit(&client, &user, 100);
+        let after = client.get_user_rewards(&user);
+        assert_eq!(
+            after.total_points - before.total_points,
+            preview.0 + preview.1
+        );
+    }
+
     #[test]
     fn test_update_streak_entrypoint_reset_after_window() {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
@@ -444,4 +732,34 @@ mod tests {
         env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
         assert_eq!(client.update_streak(&user), 2);
     }
+
+    #[test]
+    fn test_bonus_window_multiplies_points_only_inside_window() {
+        let (env, client, admin) = setup_env_with_rewards(default_rewards_config());
+        let inside = Address::generate(&env);
+        let outside = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&inside);
+        client.initialize_user(&outside);
+        client.set_bonus_window(&admin, &1_000, &2_000, &10_000);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_500);
+        create_plan_deposit(&client, &inside, 100);
+        assert_eq!(client.get_user_rewards(&inside).total_points, 2_000);
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        create_plan_deposit(&client, &outside, 100);
+        assert_eq!(client.get_user_rewards(&outside).total_points, 1_000);
+    }
+
+    #[test]
+    fn test_bonus_window_rejects_end_before_start() {
+        let (_env, client, admin) = setup_env_with_rewards(default_rewards_config());
+
+        assert_eq!(
+            client.try_set_bonus_window(&admin, &2_000, &2_000, &10_000),
+            Err(Ok(crate::errors::SavingsError::InvalidTimestamp))
+        );
+        assert_eq!(client.get_bonus_window(), None);
+    }
 }
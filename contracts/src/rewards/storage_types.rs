@@ -14,6 +14,12 @@ pub struct RewardsConfig {
     pub action_cooldown_seconds: u64,  // Cooldown between rewardable actions
     pub max_daily_points: u128,        // Daily points cap per user
     pub max_streak_multiplier: u32,    // Maximum streak multiplier (in bps)
+
+    // Streak tuning (0 = use the STREAK_WINDOW_SECS / STREAK_BONUS_THRESHOLD defaults)
+    pub streak_window_secs: u64, // Max gap between deposits to keep a streak
+    pub streak_threshold: u32,   // Streak length before the streak bonus applies
+
+    pub maturity_bonus_bps: u32, // Bonus for withdrawing a lock at maturity
 }
 
 #[contracttype]
@@ -29,9 +35,29 @@ pub struct UserRewards {
     pub last_reward_day: u64,      // Last day rewards were earned (ledger day)
 }
 
+/// A temporary lock-rate boost bought with points
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateBoost {
+    pub boost_bps: u32,  // Added to the lock rate of new locks
+    pub expires_at: u64, // Ledger timestamp after which the boost no longer applies
+}
+
+/// A promotional window during which deposits earn extra points
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusWindow {
+    pub start: u64,          // First timestamp inside the window
+    pub end: u64,            // Window closes at this timestamp (exclusive)
+    pub multiplier_bps: u32, // Extra points as bps of base points
+}
+
 #[contracttype]
 pub enum RewardsDataKey {
     Config,
     UserLedger(Address),
-    AllUsers, // Tracks all users with rewards for ranking
+    AllUsers,              // Tracks all users with rewards for ranking
+    GoalBonusBps,          // Goal bonus as bps of target; 0 keeps the flat bonus
+    RateBoostBps(Address), // Active lock-rate boost redeemed by a user
+    BonusWindow,           // Promotional deposit-points window
 }
@@ -0,0 +1,304 @@
+use crate::errors::SavingsError;
+use crate::storage_types::{FeeAccounting, ProtocolStats, StatsKey};
+use crate::ttl;
+use soroban_sdk::{Address, Env};
+
+// Running counters backing `get_protocol_stats`. They are updated on every
+// user registration, deposit, withdrawal and fee accrual so the view never
+// has to scan per-user storage.
+
+/// Increments the registered user counter.
+pub(crate) fn record_new_user(env: &Env) {
+    let total: u64 = env
+        .storage()
+        .instance()
+        .get(&StatsKey::TotalUsers)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&StatsKey::TotalUsers, &total.saturating_add(1));
+}
+
+/// Adds funds entering any savings plan to the total value locked.
+///
+/// # Errors
+/// * `Overflow` - If the counter would overflow
+pub(crate) fn record_deposit(env: &Env, amount: i128) -> Result<(), SavingsError> {
+    let tvl = get_total_value_locked(env)
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&StatsKey::TotalValueLocked, &tvl);
+    Ok(())
+}
+
+/// Removes funds leaving any savings plan from the total value locked.
+///
+/// Floors at zero so balances that predate the counter can still be
+/// withdrawn.
+pub(crate) fn record_withdrawal(env: &Env, amount: i128) {
+    let tvl = get_total_value_locked(env).saturating_sub(amount).max(0);
+    env.storage()
+        .instance()
+        .set(&StatsKey::TotalValueLocked, &tvl);
+}
+
+/// Adds a fee credited to the fee recipient to the lifetime fee total.
+///
+/// # Errors
+/// * `Overflow` - If the counter would overflow
+pub(crate) fn record_fee(env: &Env, amount: i128) -> Result<(), SavingsError> {
+    let total: i128 = env
+        .storage()
+        .instance()
+        .get(&StatsKey::TotalFeesCollected)
+        .unwrap_or(0);
+    let total = total.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&StatsKey::TotalFeesCollected, &total);
+    Ok(())
+}
+
+/// Adds accrued fees withdrawn by a fee recipient to the lifetime total.
+///
+/// # Errors
+/// * `Overflow` - If the counter would overflow
+pub(crate) fn record_fee_withdrawal(env: &Env, amount: i128) -> Result<(), SavingsError> {
+    let total = get_total_fees_withdrawn(env)
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&StatsKey::TotalFeesWithdrawn, &total);
+    Ok(())
+}
+
+/// Adds a minted amount to the global and per-user mint totals.
+///
+/// # Errors
+/// * `Overflow` - If either counter would overflow
+pub(crate) fn record_mint(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    let total = get_total_minted(env)
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    let user_total = get_minted_by_user(env, user)
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+
+    env.storage().instance().set(&StatsKey::TotalMinted, &total);
+    let user_key = StatsKey::MintedByUser(user.clone());
+    env.storage().persistent().set(&user_key, &user_total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&user_key, ttl::low_threshold(env), ttl::extend_to(env));
+    Ok(())
+}
+
+/// Returns the lifetime sum of all minted amounts.
+pub fn get_total_minted(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StatsKey::TotalMinted)
+        .unwrap_or(0)
+}
+
+/// Returns the lifetime sum of amounts minted to `user`.
+pub fn get_minted_by_user(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StatsKey::MintedByUser(user.clone()))
+        .unwrap_or(0)
+}
+
+fn get_total_fees_collected(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StatsKey::TotalFeesCollected)
+        .unwrap_or(0)
+}
+
+fn get_total_fees_withdrawn(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StatsKey::TotalFeesWithdrawn)
+        .unwrap_or(0)
+}
+
+fn get_total_value_locked(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StatsKey::TotalValueLocked)
+        .unwrap_or(0)
+}
+
+/// Returns protocol-wide totals from the running counters.
+///
+/// # Arguments
+/// * `env` - The contract environment
+///
+/// # Returns
+/// A `ProtocolStats` snapshot
+pub fn get_protocol_stats(env: &Env) -> ProtocolStats {
+    ProtocolStats {
+        total_users: env
+            .storage()
+            .instance()
+            .get(&StatsKey::TotalUsers)
+            .unwrap_or(0),
+        total_value_locked: get_total_value_locked(env),
+        total_proposals: crate::governance::get_proposal_count(env),
+        total_fees_collected: get_total_fees_collected(env),
+    }
+}
+
+/// Returns lifetime fees collected and withdrawn, and what is still owed to
+/// fee recipients.
+///
+/// # Arguments
+/// * `env` - The contract environment
+///
+/// # Returns
+/// A `FeeAccounting` snapshot
+pub fn get_fee_accounting(env: &Env) -> FeeAccounting {
+    let total_collected = get_total_fees_collected(env);
+    let total_withdrawn = get_total_fees_withdrawn(env);
+    FeeAccounting {
+        total_collected,
+        total_withdrawn,
+        outstanding: total_collected.saturating_sub(total_withdrawn),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
+    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Symbol};
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_stats_start_at_zero() {
+        let (_env, client, _admin) = setup();
+        let stats = client.get_protocol_stats();
+        assert_eq!(stats.total_users, 0);
+        assert_eq!(stats.total_value_locked, 0);
+        assert_eq!(stats.total_proposals, 0);
+        assert_eq!(stats.total_fees_collected, 0);
+    }
+
+    #[test]
+    fn test_stats_track_users_deposits_and_withdrawals() {
+        let (env, client, _admin) = setup();
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.initialize_user(&alice);
+        client.initialize_user(&bob);
+
+        client.deposit_flexi(&alice, &1_000);
+        client.create_lock_save(&bob, &2_000, &86_400, &false);
+        let goal_id = client.create_goal_save(&bob, &Symbol::new(&env, "car"), &10_000, &500);
+
+        let stats = client.get_protocol_stats();
+        assert_eq!(stats.total_users, 2);
+        assert_eq!(stats.total_value_locked, 3_500);
+
+        client.withdraw_flexi(&alice, &400);
+        client.break_goal_save(&bob, &goal_id);
+
+        let stats = client.get_protocol_stats();
+        assert_eq!(stats.total_users, 2);
+        assert_eq!(stats.total_value_locked, 2_600);
+    }
+
+    #[test]
+    fn test_stats_count_fees_and_proposals() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        client.initialize_user(&user);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_protocol_fee_bps(&100).is_ok()); // 1%
+
+        client.deposit_flexi(&user, &10_000);
+        client.withdraw_flexi(&user, &5_000);
+
+        let stats = client.get_protocol_stats();
+        // 100 on deposit, 50 on withdrawal; only the net deposit is locked
+        assert_eq!(stats.total_fees_collected, 150);
+        assert_eq!(stats.total_value_locked, 4_900);
+
+        // Withdrawn fees stay in the lifetime total
+        client.withdraw_all_protocol_fees(&treasury, &treasury);
+        assert_eq!(client.get_protocol_stats().total_fees_collected, 150);
+
+        client.init_voting_config(&admin, &5000, &10, &5, &0, &10_000);
+        client.create_savings_plan(&user, &PlanType::Flexi, &1_000);
+        client.create_proposal(
+            &user,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &String::from_str(&env, "first"),
+        );
+        client.create_proposal(
+            &user,
+            &String::from_str(&env, "Title"),
+            &String::from_str(&env, "general"),
+            &String::from_str(&env, "second"),
+        );
+        assert_eq!(client.get_protocol_stats().total_proposals, 2);
+    }
+
+    #[test]
+    fn test_fee_accounting_aggregates_breaks_and_harvests() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        client.initialize_user(&user);
+        client.initialize_config(&admin, &treasury, &1_000u32); // 10% harvest fee
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&1_000).is_ok()); // 10%
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &2_000);
+        client.break_goal_save(&user, &goal_id);
+        // 10% of the 2_000 broken goal
+        assert_eq!(client.get_fee_accounting().total_collected, 200);
+
+        let strategy = env.register(MockStrategy, ());
+        client.register_strategy(&admin, &strategy, &1u32);
+        client.route_lock_to_strategy(&admin, &1u64, &strategy, &10_000i128);
+        MockStrategyClient::new(&env, &strategy).add_yield(&1_000i128);
+        client.harvest_strategy(&admin, &strategy);
+
+        let accounting = client.get_fee_accounting();
+        assert_eq!(accounting.total_collected, 300);
+        assert_eq!(accounting.total_withdrawn, 0);
+        assert_eq!(accounting.outstanding, 300);
+
+        client.withdraw_protocol_fees(&treasury, &treasury, &120);
+        let accounting = client.get_fee_accounting();
+        assert_eq!(accounting.total_collected, 300);
+        assert_eq!(accounting.total_withdrawn, 120);
+        assert_eq!(
+            accounting.outstanding,
+            accounting.total_collected - accounting.total_withdrawn
+        );
+        assert_eq!(
+            accounting.outstanding,
+            client.get_protocol_fee_balance(&treasury)
+        );
+    }
+}
@@ -67,6 +67,10 @@ pub struct GroupSave {
     pub start_time: u64,
     pub end_time: u64,
     pub is_completed: bool,
+    /// Pays each member's share into Flexi as soon as the target is reached
+    pub auto_distribute: bool,
+    /// Maximum number of members, including the creator (0 = unlimited)
+    pub max_members: u32,
 }
 
 /// Represents a Lock Save plan with fixed duration and maturity
@@ -80,6 +84,8 @@ pub struct LockSave {
     pub start_time: u64,
     pub maturity_time: u64,
     pub is_withdrawn: bool,
+    /// Compounds interest yearly instead of accruing simple interest
+    pub compound: bool,
 }
 
 /// Custom error types for the savings contract
@@ -112,9 +118,27 @@ pub struct GoalSave {
     pub start_time: u64,
     pub is_completed: bool,
     pub is_withdrawn: bool,
+    /// Seconds between scheduled contributions from Flexi (0 = no schedule)
+    pub contribution_interval: u64,
+    /// Amount moved from Flexi on each scheduled contribution
+    pub contribution_amount: i128,
+    /// Time the next scheduled contribution is due
+    pub next_contribution_time: u64,
 }
 
-/// Represents an automated recurring deposit schedule for Flexi Save
+/// Plan an AutoSave schedule deposits into on each execution
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AutoSaveTarget {
+    /// The user's Flexi Save balance
+    Flexi,
+    /// A Goal Save owned by the user (goal ID)
+    Goal(u64),
+    /// Adds to the principal of an active Lock Save owned by the user (lock ID)
+    LockTopUp(u64),
+}
+
+/// Represents an automated recurring deposit schedule
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AutoSave {
@@ -124,6 +148,21 @@ pub struct AutoSave {
     pub interval_seconds: u64,
     pub next_execution_time: u64,
     pub is_active: bool,
+    pub target: AutoSaveTarget,
+    /// Scheduled time of the first execution
+    pub start_time: u64,
+    /// Number of deposits made so far; the next one is due at
+    /// `start_time + interval_seconds * executions_done`
+    pub executions_done: u64,
+}
+
+/// Admin overrides for the TTL thresholds in `ttl.rs` (all values in ledgers)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub low_threshold: u32,
+    pub extend_to: u32,
+    pub extend_archived: u32,
 }
 
 /// Storage keys for the contract's persistent data
@@ -131,10 +170,18 @@ pub struct AutoSave {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Admin,
+    /// Proposed admin awaiting `accept_admin`
+    PendingAdmin,
     Initialized,
     AdminPublicKey,
     /// Global pause flag for emergency control
     Paused,
+    /// Timestamp of a user's most recent Flexi deposit
+    LastFlexiDeposit(Address),
+    /// Bounded log of a user's recent deposits and withdrawals
+    UserHistory(Address),
+    /// Deadline (timestamp) after which an unfinished goal can be swept to Flexi
+    GoalDeadline(u64),
     /// Treasury address for protocol fee collection
     Treasury,
     /// Protocol fee in basis points (100 = 1%)
@@ -147,10 +194,12 @@ pub enum DataKey {
     WithdrawalFee,
     /// Protocol fee configuration
     PlatformFee,
-    /// Early break fee (basis points) for goal saves
+    /// Early break fee (basis points) shared by every plan type without its own fee
     EarlyBreakFeeBps,
     /// Fee recipient for protocol/treasury fees
     FeeRecipient,
+    /// Protocol fees accrued to a recipient, kept apart from their `TotalBalance`
+    FeeAccrued(Address),
     /// Track total principal deposited in a strategy (deposits - withdrawals)
     StrategyTotalPrincipal(Address),
     /// Track accumulated yield designated for Nestera users from a strategy
@@ -196,6 +245,92 @@ pub enum DataKey {
     LockRate(u64),
     /// Maps (plan_type, plan_id) to disabled status
     DisabledStrategy(PlanType, u64),
+    /// IDs of public groups that can still be discovered and joined
+    PublicGroupIndex,
+    /// Marks a (user, nonce) mint payload as already consumed
+    UsedNonce(Address, u64),
+    /// Set while a strategy-interacting call is in progress (temporary storage)
+    ReentrancyGuard,
+    /// Timestamp a user last left a group
+    GroupLeaveTime(u64, Address),
+    /// (day, amount) a user has withdrawn in their latest withdrawal day
+    DailyWithdrawn(Address),
+    /// Number of plans a user holds that have not been withdrawn or left
+    OpenPlanCount(Address),
+}
+
+/// Storage keys for admin-tunable protocol settings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    /// Timestamp at which a timed pause lifts itself (0 = manual unpause only)
+    AutoUnpauseAt,
+    /// Per-operation pause flag, keyed by a `config::PAUSE_OP_*` code
+    PausedOps(u32),
+    /// Emergency exit mode: deposits blocked, withdrawal fees waived
+    EmergencyMode,
+    /// Admin-set TTL thresholds overriding the `ttl.rs` constants
+    TtlConfig,
+    /// Seconds a Flexi withdrawal is locked after the user's last deposit
+    FlexiWithdrawCooldown,
+    /// Maximum open plans per user (0 = unlimited)
+    MaxPlansPerUser,
+    /// Sorted list of durations (days) that have a `LockRate` configured
+    LockRateTiers,
+    /// Maximum number of schedules accepted by a single `execute_due_autosaves` call
+    MaxAutoSaveBatchSize,
+    /// Strategy that new Lock Save deposits are routed into, if any
+    DefaultLockStrategy,
+    /// Strategy that Group Save contributions are routed into, if any
+    DefaultGroupStrategy,
+    /// Extra seconds a signed payload stays valid past its expiry
+    SignatureGraceSecs,
+    /// Deposit fee tiers as (threshold, bps), sorted ascending by threshold
+    FeeTiers,
+    /// Upper bound on the absolute fee charged per transaction (0 = uncapped)
+    MaxFeeAbsolute,
+    /// Whether first deposits register unknown users automatically
+    AutoInitUsers,
+    /// Shortest Lock Save duration accepted, in seconds (0 = no bound)
+    MinLockDuration,
+    /// Longest Lock Save duration accepted, in seconds (0 = no bound)
+    MaxLockDuration,
+    /// Seconds a user must wait after leaving a group before rejoining it (0 = none)
+    GroupJoinCooldown,
+    /// Most a user may withdraw per day (0 = no limit)
+    DailyWithdrawLimit,
+    /// Early break fee (basis points) for Goal Saves, overriding `EarlyBreakFeeBps`
+    GoalBreakFeeBps,
+    /// Early break fee (basis points) for Lock Saves, overriding `EarlyBreakFeeBps`
+    LockBreakFeeBps,
+    /// Early break fee (basis points) for leaving a Group Save, overriding `EarlyBreakFeeBps`
+    GroupBreakFeeBps,
+}
+
+/// Storage keys for the protocol-wide running counters in `stats.rs`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StatsKey {
+    /// Number of users registered through `initialize_user`
+    TotalUsers,
+    /// Running sum of funds held across all savings plans
+    TotalValueLocked,
+    /// Lifetime sum of fees credited to the fee recipient
+    TotalFeesCollected,
+    /// Lifetime sum of accrued fees withdrawn by fee recipients
+    TotalFeesWithdrawn,
+    /// Lifetime sum of amounts minted through signed payloads
+    TotalMinted,
+    /// Lifetime sum of amounts minted to a user
+    MintedByUser(Address),
+}
+
+/// Storage keys for per-member Group Save accrual state
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GroupKey {
+    /// Contribution-weighted average time a member's funds entered the group
+    MemberSince(u64, Address),
 }
 
 /// Payload structure that the admin signs off-chain
@@ -211,6 +346,11 @@ pub struct MintPayload {
     pub timestamp: u64,
     /// Expiry duration in seconds (signature valid for timestamp + expiry_duration)
     pub expiry_duration: u64,
+    /// Per-user nonce; each signed payload can only be minted once
+    pub nonce: u64,
+    /// Whether the payload is redeemable through `mint_to_flexi` (true) or
+    /// through `mint` and `batch_mint` (false)
+    pub to_flexi: bool,
 }
 
 // View-specific structures (used by views.rs module)
@@ -223,6 +363,10 @@ pub struct LockSaveView {
     pub locked_until: u64,
     pub interest_rate: u32,
     pub is_withdrawn: bool,
+    /// Seconds until `locked_until` (0 once matured)
+    pub time_remaining: u64,
+    /// Yield the lock pays on top of its balance if withdrawn at maturity
+    pub projected_yield: i128,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -236,6 +380,10 @@ pub struct GoalSaveView {
     pub is_completed: bool,
     pub contribution_type: u32,
     pub goal_name: Symbol,
+    /// Funding progress in basis points, capped at 10_000
+    pub progress_bps: u32,
+    /// Amount still needed to reach the target (0 once reached)
+    pub remaining: i128,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -250,4 +398,100 @@ pub struct GroupSaveView {
     pub is_public: bool,
     pub contribution_type: u32,
     pub group_id: u64,
+    /// Group's pooled amount toward its target in bps (capped at 10_000)
+    pub progress_bps: u32,
+    /// Spots taken, i.e. members currently in the group
+    pub member_count: u32,
+}
+
+/// One deposit or withdrawal in a user's history log
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct HistoryEntry {
+    /// Operation kind, e.g. `flexi_dep` or `goal_wd`
+    pub kind: Symbol,
+    /// Amount moved by the operation
+    pub amount: i128,
+    /// Ledger timestamp of the operation
+    pub timestamp: u64,
+    /// Plan the operation touched (0 for Flexi)
+    pub plan_id: u64,
+}
+
+/// Breakdown of a plan payout, returned to wallets for display
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WithdrawalReceipt {
+    /// Plan balance before fees
+    pub gross: i128,
+    /// Fee deducted and routed to the fee recipient
+    pub fee: i128,
+    /// Amount credited to the user (`gross - fee`)
+    pub net: i128,
+    /// ID of the plan that was paid out
+    pub plan_id: u64,
+    /// Kind of plan that was paid out (e.g. `goal`)
+    pub plan_kind: Symbol,
+}
+
+/// Aggregated snapshot of a user's holdings across every savings product
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UserPortfolio {
+    /// Current Flexi Save balance
+    pub flexi_balance: i128,
+    /// Sum of principals across the user's active (not withdrawn) Lock Saves
+    pub lock_total: i128,
+    /// Sum of current balances across the user's active (not withdrawn) Goal Saves
+    pub goal_total: i128,
+    /// Sum of the user's contributions across every group they belong to
+    pub group_total: i128,
+    /// Total reward points accumulated by the user
+    pub reward_points: u128,
+    /// Overall balance tracked on the `User` record
+    pub total_balance: i128,
+}
+
+/// Which setup steps have been completed on the contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InitStatus {
+    /// `initialize` (or `bootstrap`) has run
+    pub initialized: bool,
+    /// Treasury and protocol fee config has been stored
+    pub config_initialized: bool,
+    /// Rewards config has been stored
+    pub rewards_config_initialized: bool,
+    /// Governance voting config has been stored
+    pub voting_config_initialized: bool,
+    /// Governance has been activated
+    pub governance_active: bool,
+    /// The contract is globally paused
+    pub paused: bool,
+}
+
+/// Protocol-wide totals maintained as running counters
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProtocolStats {
+    /// Users registered through `initialize_user`
+    pub total_users: u64,
+    /// Funds currently held across all savings plans
+    pub total_value_locked: i128,
+    /// Proposals created, including action proposals
+    pub total_proposals: u64,
+    /// Lifetime fees credited to the fee recipient
+    pub total_fees_collected: i128,
+}
+
+/// Protocol fee totals for reconciling the treasury
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeAccounting {
+    /// Lifetime fees credited from deposits, withdrawals, breaks and harvests
+    pub total_collected: i128,
+    /// Lifetime fees withdrawn by fee recipients
+    pub total_withdrawn: i128,
+    /// Fees credited but not yet withdrawn
+    pub outstanding: i128,
 }
@@ -0,0 +1,353 @@
+use crate::errors::SavingsError;
+use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
+use crate::{NesteraContract, NesteraContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+
+fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+    let env = Env::default();
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+    env.mock_all_auths();
+    client.initialize(&admin, &admin_pk);
+    (env, client, admin)
+}
+
+/// Helper: deploy a mock strategy and register it with Nestera.
+fn deploy_strategy(env: &Env, client: &NesteraContractClient, admin: &Address) -> Address {
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(admin, &strategy, &1u32);
+    strategy
+}
+
+#[test]
+fn test_route_60_40_split_records_two_positions() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 6_000u32),
+        (strat_b.clone(), 4_000u32),
+    ];
+    let shares = client.route_lock_to_strategies(&admin, &1u64, &allocations, &1_000i128);
+    assert_eq!(shares, 1_000);
+
+    let positions = client.get_lock_strategy_positions(&1u64).unwrap();
+    assert_eq!(positions.len(), 2);
+
+    let first = positions.get(0).unwrap();
+    assert_eq!(first.strategy, strat_a);
+    assert_eq!(first.principal_deposited, 600);
+    assert_eq!(first.strategy_shares, 600);
+
+    let second = positions.get(1).unwrap();
+    assert_eq!(second.strategy, strat_b);
+    assert_eq!(second.principal_deposited, 400);
+    assert_eq!(second.strategy_shares, 400);
+
+    assert_eq!(client.get_strategy_principal(&strat_a), 600);
+    assert_eq!(client.get_strategy_principal(&strat_b), 400);
+    assert_eq!(
+        MockStrategyClient::new(&env, &strat_a).strategy_balance(&caller),
+        600
+    );
+    assert_eq!(
+        MockStrategyClient::new(&env, &strat_b).strategy_balance(&caller),
+        400
+    );
+}
+
+#[test]
+fn test_multi_strategy_routing_requires_admin() {
+    let (env, client, admin) = setup();
+    let outsider = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+    let allocations = vec![&env, (strat_a, 5_000u32), (strat_b, 5_000u32)];
+
+    assert_eq!(
+        client.try_route_lock_to_strategies(&outsider, &1u64, &allocations, &1_000i128),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_route_group_to_strategies(&outsider, &1u64, &allocations, &1_000i128),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert!(client.get_lock_strategy_positions(&1u64).is_none());
+    assert!(client.get_group_strategy_positions(&1u64).is_none());
+}
+
+#[test]
+fn test_route_rounding_dust_goes_to_last_strategy() {
+    let (env, client, admin) = setup();
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+    let strat_c = deploy_strategy(&env, &client, &admin);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 3_333u32),
+        (strat_b.clone(), 3_333u32),
+        (strat_c.clone(), 3_334u32),
+    ];
+    client.route_group_to_strategies(&admin, &7u64, &allocations, &100i128);
+
+    let positions = client.get_group_strategy_positions(&7u64).unwrap();
+    let total: i128 = positions.iter().map(|p| p.principal_deposited).sum();
+    assert_eq!(total, 100);
+    assert_eq!(positions.get(2).unwrap().principal_deposited, 34);
+}
+
+#[test]
+fn test_route_again_merges_into_existing_positions() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+    let strat_c = deploy_strategy(&env, &client, &admin);
+
+    let first = vec![
+        &env,
+        (strat_a.clone(), 6_000u32),
+        (strat_b.clone(), 4_000u32),
+    ];
+    client.route_lock_to_strategies(&admin, &1u64, &first, &1_000i128);
+    let second = vec![
+        &env,
+        (strat_a.clone(), 5_000u32),
+        (strat_c.clone(), 5_000u32),
+    ];
+    client.route_lock_to_strategies(&admin, &1u64, &second, &200i128);
+
+    let positions = client.get_lock_strategy_positions(&1u64).unwrap();
+    assert_eq!(positions.len(), 3);
+    let a = positions.get(0).unwrap();
+    assert_eq!(
+        (a.strategy, a.principal_deposited, a.strategy_shares),
+        (strat_a, 700, 700)
+    );
+    assert_eq!(positions.get(1).unwrap().principal_deposited, 400);
+    let c = positions.get(2).unwrap();
+    assert_eq!(
+        (c.strategy, c.principal_deposited, c.strategy_shares),
+        (strat_c, 100, 100)
+    );
+
+    assert_eq!(
+        client.withdraw_lock_strategy(&admin, &1u64, &caller, &0),
+        1_200
+    );
+}
+
+#[test]
+fn test_route_weights_not_summing_to_10000_rejected() {
+    let (env, client, admin) = setup();
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 6_000u32),
+        (strat_b.clone(), 3_999u32),
+    ];
+    let result = client.try_route_lock_to_strategies(&admin, &1u64, &allocations, &1_000i128);
+    assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+
+    assert!(client.get_lock_strategy_positions(&1u64).is_none());
+    assert_eq!(client.get_strategy_principal(&strat_a), 0);
+}
+
+#[test]
+fn test_withdraw_iterates_all_sub_positions() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 6_000u32),
+        (strat_b.clone(), 4_000u32),
+    ];
+    client.route_lock_to_strategies(&admin, &1u64, &allocations, &1_000i128);
+
+    let returned = client.withdraw_lock_strategy(&admin, &1u64, &caller, &0);
+    assert_eq!(returned, 1_000);
+
+    let positions = client.get_lock_strategy_positions(&1u64).unwrap();
+    for position in positions.iter() {
+        assert_eq!(position.principal_deposited, 0);
+    }
+    assert_eq!(client.get_strategy_principal(&strat_a), 0);
+    assert_eq!(client.get_strategy_principal(&strat_b), 0);
+}
+
+#[test]
+fn test_harvest_iterates_all_sub_positions() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 5_000u32),
+        (strat_b.clone(), 5_000u32),
+    ];
+    client.route_lock_to_strategies(&admin, &1u64, &allocations, &1_000i128);
+
+    MockStrategyClient::new(&env, &strat_a).add_yield(&30i128);
+    MockStrategyClient::new(&env, &strat_b).add_yield(&20i128);
+
+    let harvested = client.harvest_lock_strategies(&caller, &1u64);
+    assert_eq!(harvested, 50);
+}
+
+// ========== Rebalancing Tests ==========
+
+#[test]
+fn test_rebalance_moves_principal() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &700i128);
+    client.route_lock_to_strategy(&admin, &2u64, &strat_a, &300i128);
+
+    client.rebalance_strategy(&admin, &strat_a, &strat_b, &300i128);
+
+    assert_eq!(client.get_strategy_principal(&strat_a), 700);
+    assert_eq!(client.get_strategy_principal(&strat_b), 300);
+    assert_eq!(
+        MockStrategyClient::new(&env, &strat_a).strategy_balance(&caller),
+        700
+    );
+    assert_eq!(
+        MockStrategyClient::new(&env, &strat_b).strategy_balance(&caller),
+        300
+    );
+
+    let kept = client.get_lock_strategy_position(&1u64).unwrap();
+    assert_eq!(kept.strategy, strat_a);
+    assert_eq!(kept.principal_deposited, 700);
+    let moved = client.get_lock_strategy_position(&2u64).unwrap();
+    assert_eq!(moved.strategy, strat_b);
+    assert_eq!(moved.principal_deposited, 300);
+    assert_eq!(moved.strategy_shares, 300);
+}
+
+#[test]
+fn test_rebalance_migrates_sub_positions() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+    let strat_c = deploy_strategy(&env, &client, &admin);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 6_000u32),
+        (strat_b.clone(), 4_000u32),
+    ];
+    client.route_lock_to_strategies(&admin, &1u64, &allocations, &1_000i128);
+
+    client.rebalance_strategy(&admin, &strat_a, &strat_c, &600i128);
+
+    let positions = client.get_lock_strategy_positions(&1u64).unwrap();
+    let first = positions.get(0).unwrap();
+    assert_eq!(first.strategy, strat_c);
+    assert_eq!(first.principal_deposited, 600);
+    assert_eq!(first.strategy_shares, 600);
+    assert_eq!(positions.get(1).unwrap().strategy, strat_b);
+
+    // Withdrawing now pulls the moved principal from the new strategy
+    assert_eq!(
+        client.withdraw_lock_strategy(&admin, &1u64, &caller, &0),
+        1_000
+    );
+    assert_eq!(client.get_strategy_principal(&strat_c), 0);
+}
+
+#[test]
+fn test_rebalance_carries_unclaimed_yield_to_new_strategy() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize_config(&admin, &treasury, &0u32);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &1_000i128);
+    MockStrategyClient::new(&env, &strat_a).add_yield(&50i128);
+    client.harvest_strategy(&caller, &strat_a);
+    let position_key = crate::strategy::routing::StrategyPositionKey::Lock(1);
+    assert_eq!(client.get_pending_strategy_yield(&position_key), 50);
+
+    client.rebalance_strategy(&admin, &strat_a, &strat_b, &1_000i128);
+
+    assert_eq!(client.get_pending_strategy_yield(&position_key), 50);
+    assert_eq!(client.get_strategy_yield(&strat_a), 0);
+    assert_eq!(client.get_strategy_yield(&strat_b), 50);
+}
+
+#[test]
+fn test_rebalance_that_would_split_a_position_fails() {
+    let (env, client, admin) = setup();
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &1_000i128);
+
+    let result = client.try_rebalance_strategy(&admin, &strat_a, &strat_b, &300i128);
+    assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+    assert_eq!(client.get_strategy_principal(&strat_a), 1_000);
+    assert_eq!(
+        client.get_lock_strategy_position(&1u64).unwrap().strategy,
+        strat_a
+    );
+}
+
+#[test]
+fn test_rebalance_into_disabled_strategy_fails() {
+    let (env, client, admin) = setup();
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &1_000i128);
+    client.disable_strategy(&admin, &strat_b);
+
+    let result = client.try_rebalance_strategy(&admin, &strat_a, &strat_b, &300i128);
+    assert_eq!(result, Err(Ok(SavingsError::StrategyDisabled)));
+    assert_eq!(client.get_strategy_principal(&strat_a), 1_000);
+    assert_eq!(client.get_strategy_principal(&strat_b), 0);
+}
+
+#[test]
+fn test_rebalance_more_than_principal_fails() {
+    let (env, client, admin) = setup();
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &1_000i128);
+
+    let result = client.try_rebalance_strategy(&admin, &strat_a, &strat_b, &1_001i128);
+    assert_eq!(result, Err(Ok(SavingsError::InsufficientBalance)));
+}
+
+#[test]
+fn test_rebalance_non_admin_fails() {
+    let (env, client, admin) = setup();
+    let caller = Address::generate(&env);
+    let strat_a = deploy_strategy(&env, &client, &admin);
+    let strat_b = deploy_strategy(&env, &client, &admin);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &1_000i128);
+
+    let result = client.try_rebalance_strategy(&caller, &strat_a, &strat_b, &300i128);
+    assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+}
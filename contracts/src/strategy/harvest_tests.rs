@@ -9,7 +9,8 @@
 /// 6. Public API functions return defaults before any activity
 use crate::errors::SavingsError;
 use crate::storage_types::DataKey;
-use crate::strategy::routing::{self};
+use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
+use crate::strategy::routing::{self, StrategyPositionKey};
 use crate::{NesteraContract, NesteraContractClient};
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
 
@@ -400,3 +401,185 @@ fn test_harvest_twice_no_double_counting() {
         );
     });
 }
+
+#[test]
+fn test_harvest_treasury_fee_kept_apart_from_savings() {
+    let (env, client, admin, treasury, _contract_id) = setup_with_treasury();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+
+    // Treasury is also a saver
+    client.initialize_user(&treasury);
+    client.deposit_flexi(&treasury, &5_000);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &10_000i128);
+    MockStrategyClient::new(&env, &strategy).add_yield(&1_000i128);
+
+    let harvested = client.harvest_strategy(&admin, &strategy);
+    assert_eq!(harvested, 1_000);
+
+    // 10% fee accrues as protocol fees, not savings
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 100);
+    assert_eq!(client.get_strategy_yield(&strategy), 900);
+    assert_eq!(client.get_flexi_balance(&treasury), 5_000);
+    assert_eq!(client.get_user(&treasury).total_balance, 5_000);
+}
+
+#[test]
+fn test_harvest_fee_overflow_returns_error_without_partial_writes() {
+    let (env, client, admin, treasury, contract_id) = setup_with_treasury();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &10_000i128);
+    MockStrategyClient::new(&env, &strategy).add_yield(&1_000i128);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeAccrued(treasury.clone()), &(i128::MAX - 10));
+    });
+
+    assert_eq!(
+        client.try_harvest_strategy(&admin, &strategy),
+        Err(Ok(SavingsError::Overflow))
+    );
+    assert_eq!(client.get_protocol_fee_balance(&treasury), i128::MAX - 10);
+    assert_eq!(client.get_strategy_yield(&strategy), 0);
+}
+
+// ========== Harvest-All Tests ==========
+
+#[test]
+fn test_harvest_all_strategies_harvests_each() {
+    let (env, client, admin, treasury, _contract_id) = setup_with_treasury();
+    let strat_a = env.register(MockStrategy, ());
+    let strat_b = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strat_a, &1u32);
+    client.register_strategy(&admin, &strat_b, &2u32);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &10_000i128);
+    client.route_lock_to_strategy(&admin, &2u64, &strat_b, &5_000i128);
+    MockStrategyClient::new(&env, &strat_a).add_yield(&1_000i128);
+    MockStrategyClient::new(&env, &strat_b).add_yield(&500i128);
+
+    let results = client.harvest_all_strategies();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap(), (strat_a.clone(), 1_000));
+    assert_eq!(results.get(1).unwrap(), (strat_b.clone(), 500));
+
+    // 10% fee from each strategy accumulates for the treasury
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 150);
+    assert_eq!(client.get_strategy_yield(&strat_a), 900);
+    assert_eq!(client.get_strategy_yield(&strat_b), 450);
+}
+
+#[test]
+fn test_harvest_all_strategies_skips_failing_strategy() {
+    let (env, client, admin, treasury, _contract_id) = setup_with_treasury();
+    let strat_a = env.register(MockStrategy, ());
+    let strat_b = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strat_a, &1u32);
+    client.register_strategy(&admin, &strat_b, &1u32);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strat_a, &10_000i128);
+    client.route_lock_to_strategy(&admin, &2u64, &strat_b, &10_000i128);
+    MockStrategyClient::new(&env, &strat_a).add_yield(&1_000i128);
+    MockStrategyClient::new(&env, &strat_b).add_yield(&1_000i128);
+    MockStrategyClient::new(&env, &strat_a).set_failing(&true);
+
+    let results = client.harvest_all_strategies();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results.get(0).unwrap(), (strat_b.clone(), 1_000));
+
+    assert_eq!(client.get_strategy_yield(&strat_a), 0);
+    assert_eq!(client.get_strategy_yield(&strat_b), 900);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 100);
+}
+
+#[test]
+fn test_harvest_failing_strategy_returns_error() {
+    let (env, client, admin, _treasury, _contract_id) = setup_with_treasury();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    MockStrategyClient::new(&env, &strategy).set_failing(&true);
+
+    let result = client.try_harvest_strategy(&admin, &strategy);
+    assert_eq!(result, Err(Ok(SavingsError::StrategyCallFailed)));
+}
+
+// ========== Yield Claim Tests ==========
+
+#[test]
+fn test_claim_strategy_yield_is_pro_rata_to_principal() {
+    let (env, client, admin, _treasury, _contract_id) = setup_with_treasury();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.set_default_lock_strategy(&admin, &Some(strategy.clone()));
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize_user(&alice);
+    client.initialize_user(&bob);
+    let alice_lock = client.create_lock_save(&alice, &3_000, &86_400, &false);
+    let bob_lock = client.create_lock_save(&bob, &1_000, &86_400, &false);
+
+    MockStrategyClient::new(&env, &strategy).add_yield(&1_000i128);
+    client.harvest_strategy(&admin, &strategy);
+    assert_eq!(client.get_strategy_yield(&strategy), 900);
+
+    let alice_key = StrategyPositionKey::Lock(alice_lock);
+    let bob_key = StrategyPositionKey::Lock(bob_lock);
+    assert_eq!(client.get_pending_strategy_yield(&alice_key), 675);
+
+    let alice_claim = client.claim_strategy_yield(&alice, &alice_key);
+    let bob_claim = client.claim_strategy_yield(&bob, &bob_key);
+    assert_eq!(alice_claim, 675);
+    assert_eq!(bob_claim, 225);
+    assert_eq!(alice_claim + bob_claim, 900);
+    assert_eq!(client.get_strategy_yield(&strategy), 0);
+    assert_eq!(client.get_flexi_balance(&alice), 675);
+    assert_eq!(client.get_flexi_balance(&bob), 225);
+
+    // The same harvest cannot be claimed twice
+    assert_eq!(client.claim_strategy_yield(&alice, &alice_key), 0);
+    assert_eq!(client.get_flexi_balance(&alice), 675);
+}
+
+#[test]
+fn test_claim_strategy_yield_only_pays_new_harvests() {
+    let (env, client, admin, _treasury, _contract_id) = setup_with_treasury();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.set_default_lock_strategy(&admin, &Some(strategy.clone()));
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.initialize_user(&alice);
+    client.initialize_user(&bob);
+    let alice_lock = client.create_lock_save(&alice, &1_000, &86_400, &false);
+    let alice_key = StrategyPositionKey::Lock(alice_lock);
+
+    MockStrategyClient::new(&env, &strategy).add_yield(&1_000i128);
+    client.harvest_strategy(&admin, &strategy);
+    assert_eq!(client.claim_strategy_yield(&alice, &alice_key), 900);
+
+    // Bob joins after the first harvest and only shares in the second
+    let bob_lock = client.create_lock_save(&bob, &1_000, &86_400, &false);
+    let bob_key = StrategyPositionKey::Lock(bob_lock);
+    assert_eq!(client.get_pending_strategy_yield(&bob_key), 0);
+
+    MockStrategyClient::new(&env, &strategy).add_yield(&200i128);
+    client.harvest_strategy(&admin, &strategy);
+    assert_eq!(client.claim_strategy_yield(&bob, &bob_key), 90);
+    assert_eq!(client.claim_strategy_yield(&alice, &alice_key), 90);
+
+    // Only the owner can claim, and group positions pay out on withdrawal
+    assert_eq!(
+        client.try_claim_strategy_yield(&bob, &alice_key),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_claim_strategy_yield(&bob, &StrategyPositionKey::Group(1)),
+        Err(Ok(SavingsError::InvalidPlanConfig))
+    );
+}
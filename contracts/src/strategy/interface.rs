@@ -61,4 +61,13 @@ pub trait YieldStrategy {
     /// # Returns
     /// The total balance (principal + accrued yield) denominated in the deposit token.
     fn strategy_balance(env: Env, addr: Address) -> i128;
+
+    /// Returns the strategy's current annual percentage yield.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// The APY in basis points (e.g. 500 = 5%).
+    fn strategy_apy(env: Env) -> u32;
 }
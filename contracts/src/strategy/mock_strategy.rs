@@ -0,0 +1,112 @@
+//! Minimal in-memory yield strategy used by the routing tests.
+//!
+//! Tracks a single balance for whoever deposits into it and lets tests
+//! simulate accrued yield with `add_yield`. No tokens are moved.
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum MockStrategyKey {
+    Balance,
+    PendingYield,
+    Failing,
+    Apy,
+    WithdrawLoss,
+}
+
+#[contract]
+pub struct MockStrategy;
+
+#[contractimpl]
+impl MockStrategy {
+    pub fn strategy_deposit(env: Env, _from: Address, amount: i128) -> i128 {
+        let balance = read(&env, &MockStrategyKey::Balance);
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::Balance, &(balance + amount));
+        amount
+    }
+
+    pub fn strategy_withdraw(env: Env, _to: Address, amount: i128) -> i128 {
+        let balance = read(&env, &MockStrategyKey::Balance);
+        if amount > balance {
+            panic!("insufficient strategy balance");
+        }
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::Balance, &(balance - amount));
+        (amount - read(&env, &MockStrategyKey::WithdrawLoss)).max(0)
+    }
+
+    pub fn strategy_harvest(env: Env, _to: Address) -> i128 {
+        fail_if_set(&env);
+        let pending = read(&env, &MockStrategyKey::PendingYield);
+        let balance = read(&env, &MockStrategyKey::Balance);
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::Balance, &(balance - pending));
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::PendingYield, &0_i128);
+        pending
+    }
+
+    pub fn strategy_balance(env: Env, _addr: Address) -> i128 {
+        fail_if_set(&env);
+        read(&env, &MockStrategyKey::Balance)
+    }
+
+    pub fn strategy_apy(env: Env) -> u32 {
+        fail_if_set(&env);
+        env.storage()
+            .instance()
+            .get(&MockStrategyKey::Apy)
+            .unwrap_or(0)
+    }
+
+    /// Test helper: sets the APY (bps) reported by `strategy_apy`.
+    pub fn set_apy(env: Env, apy: u32) {
+        env.storage().instance().set(&MockStrategyKey::Apy, &apy);
+    }
+
+    /// Test helper: makes `strategy_balance`, `strategy_harvest` and `strategy_apy` panic.
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::Failing, &failing);
+    }
+
+    /// Test helper: makes `strategy_withdraw` return `loss` less than requested.
+    pub fn set_withdraw_loss(env: Env, loss: i128) {
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::WithdrawLoss, &loss);
+    }
+
+    /// Test helper: simulates `amount` of yield accruing in the strategy.
+    pub fn add_yield(env: Env, amount: i128) {
+        let balance = read(&env, &MockStrategyKey::Balance);
+        let pending = read(&env, &MockStrategyKey::PendingYield);
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::Balance, &(balance + amount));
+        env.storage()
+            .instance()
+            .set(&MockStrategyKey::PendingYield, &(pending + amount));
+    }
+}
+
+fn read(env: &Env, key: &MockStrategyKey) -> i128 {
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+fn fail_if_set(env: &Env) {
+    let failing: bool = env
+        .storage()
+        .instance()
+        .get(&MockStrategyKey::Failing)
+        .unwrap_or(false);
+    if failing {
+        panic!("strategy unavailable");
+    }
+}
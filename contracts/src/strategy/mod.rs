@@ -2,9 +2,15 @@ pub mod interface;
 pub mod registry;
 pub mod routing;
 
+#[cfg(test)]
+mod allocation_tests;
 #[cfg(test)]
 mod harvest_tests;
 #[cfg(test)]
+pub(crate) mod mock_strategy;
+#[cfg(test)]
+mod reentrancy_tests;
+#[cfg(test)]
 mod tests;
 #[cfg(test)]
 mod withdraw_tests;
@@ -1,5 +1,6 @@
 use crate::errors::SavingsError;
 use crate::governance;
+use crate::storage_types::DataKey;
 use crate::ttl;
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
@@ -13,6 +14,8 @@ pub struct StrategyInfo {
     pub enabled: bool,
     /// Risk level indicator (0 = lowest risk, 255 = highest risk)
     pub risk_level: u32,
+    /// Maximum total principal routed into this strategy (0 = uncapped)
+    pub max_allocation: i128,
 }
 
 /// Storage keys for the strategy registry.
@@ -56,7 +59,21 @@ pub fn register_strategy(
     risk_level: u32,
 ) -> Result<(), SavingsError> {
     require_admin_or_governance(env, &caller)?;
+    store_strategy(env, strategy_address, risk_level)
+}
 
+/// Writes a new strategy entry into the registry without any caller checks.
+///
+/// Shared by the admin entrypoint and governance proposal execution, which
+/// has already been authorized by a passed vote.
+///
+/// # Errors
+/// * `StrategyAlreadyRegistered` - If the strategy address is already registered
+pub(crate) fn store_strategy(
+    env: &Env,
+    strategy_address: Address,
+    risk_level: u32,
+) -> Result<(), SavingsError> {
     let info_key = StrategyKey::Info(strategy_address.clone());
 
     // Prevent duplicate registration
@@ -68,6 +85,7 @@ pub fn register_strategy(
         address: strategy_address.clone(),
         enabled: true,
         risk_level,
+        max_allocation: 0,
     };
 
     // Store strategy info
@@ -86,10 +104,10 @@ pub fn register_strategy(
     // Extend TTL
     env.storage()
         .persistent()
-        .extend_ttl(&info_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
+        .extend_ttl(&info_key, ttl::low_threshold(env), ttl::extend_to(env));
     env.storage()
         .persistent()
-        .extend_ttl(&list_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
+        .extend_ttl(&list_key, ttl::low_threshold(env), ttl::extend_to(env));
 
     env.events().publish(
         (symbol_short!("strat"), symbol_short!("register")),
@@ -99,6 +117,75 @@ pub fn register_strategy(
     Ok(())
 }
 
+/// Deregisters a strategy once all principal has been withdrawn from it.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `caller` - Admin or governance caller
+/// * `strategy_address` - Address of the strategy to remove
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not admin / governance is not active
+/// * `StrategyNotFound` - If the strategy is not registered
+/// * `StrategyHasFunds` - If principal is still routed into the strategy
+pub fn deregister_strategy(
+    env: &Env,
+    caller: Address,
+    strategy_address: Address,
+) -> Result<(), SavingsError> {
+    require_admin_or_governance(env, &caller)?;
+    remove_strategy(env, strategy_address)
+}
+
+/// Removes a strategy from the registry without any caller checks.
+///
+/// Deletes the strategy's `StrategyInfo` entry and drops it from the list of
+/// all strategies. Shared by the admin entrypoint and governance proposal
+/// execution.
+///
+/// # Errors
+/// * `StrategyNotFound` - If the strategy is not registered
+/// * `StrategyHasFunds` - If principal is still routed into the strategy
+pub(crate) fn remove_strategy(env: &Env, strategy_address: Address) -> Result<(), SavingsError> {
+    let info_key = StrategyKey::Info(strategy_address.clone());
+    if !env.storage().persistent().has(&info_key) {
+        return Err(SavingsError::StrategyNotFound);
+    }
+    let principal: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StrategyTotalPrincipal(strategy_address.clone()))
+        .unwrap_or(0);
+    if principal > 0 {
+        return Err(SavingsError::StrategyHasFunds);
+    }
+    env.storage().persistent().remove(&info_key);
+
+    let list_key = StrategyKey::AllStrategies;
+    let list: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+    let mut remaining = Vec::new(env);
+    for addr in list.iter() {
+        if addr != strategy_address {
+            remaining.push_back(addr);
+        }
+    }
+    env.storage().persistent().set(&list_key, &remaining);
+    env.storage()
+        .persistent()
+        .extend_ttl(&list_key, ttl::low_threshold(env), ttl::extend_to(env));
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("dereg")),
+        strategy_address,
+    );
+
+    Ok(())
+}
+
 /// Disables a previously registered strategy.
 ///
 /// Disabled strategies will not accept new deposits but existing positions
@@ -131,7 +218,7 @@ pub fn disable_strategy(
 
     env.storage()
         .persistent()
-        .extend_ttl(&info_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
+        .extend_ttl(&info_key, ttl::low_threshold(env), ttl::extend_to(env));
 
     env.events().publish(
         (symbol_short!("strat"), symbol_short!("disable")),
@@ -141,6 +228,110 @@ pub fn disable_strategy(
     Ok(())
 }
 
+/// Enables or disables a registered strategy without deregistering it.
+///
+/// While disabled, new deposits are rejected with `StrategyDisabled`;
+/// existing positions can still be withdrawn and harvested.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not admin / governance is not active
+/// * `StrategyNotFound` - If the strategy is not registered
+pub fn set_strategy_enabled(
+    env: &Env,
+    caller: Address,
+    strategy_address: Address,
+    enabled: bool,
+) -> Result<(), SavingsError> {
+    require_admin_or_governance(env, &caller)?;
+
+    let info_key = StrategyKey::Info(strategy_address.clone());
+    let mut info: StrategyInfo = env
+        .storage()
+        .persistent()
+        .get(&info_key)
+        .ok_or(SavingsError::StrategyNotFound)?;
+
+    info.enabled = enabled;
+    env.storage().persistent().set(&info_key, &info);
+
+    env.storage()
+        .persistent()
+        .extend_ttl(&info_key, ttl::low_threshold(env), ttl::extend_to(env));
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("toggle")),
+        (strategy_address, enabled),
+    );
+
+    Ok(())
+}
+
+/// Sets the maximum total principal that may be routed into a strategy.
+///
+/// A cap of 0 removes the limit. Lowering the cap below the current
+/// principal does not unwind positions; it only blocks new deposits.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not admin / governance is not active
+/// * `InvalidAmount` - If `cap` is negative
+/// * `StrategyNotFound` - If the strategy is not registered
+pub fn set_strategy_cap(
+    env: &Env,
+    caller: Address,
+    strategy_address: Address,
+    cap: i128,
+) -> Result<(), SavingsError> {
+    require_admin_or_governance(env, &caller)?;
+
+    if cap < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let info_key = StrategyKey::Info(strategy_address.clone());
+    let mut info: StrategyInfo = env
+        .storage()
+        .persistent()
+        .get(&info_key)
+        .ok_or(SavingsError::StrategyNotFound)?;
+
+    info.max_allocation = cap;
+    env.storage().persistent().set(&info_key, &info);
+
+    env.storage()
+        .persistent()
+        .extend_ttl(&info_key, ttl::low_threshold(env), ttl::extend_to(env));
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("cap")),
+        (strategy_address, cap),
+    );
+
+    Ok(())
+}
+
+/// Checks that adding `amount` to `current_principal` stays within the
+/// strategy's `max_allocation`.
+///
+/// # Errors
+/// * `StrategyCapExceeded` - If the cap would be exceeded
+/// * `Overflow` - If the new principal overflows
+pub(crate) fn ensure_within_cap(
+    info: &StrategyInfo,
+    current_principal: i128,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    if info.max_allocation == 0 {
+        return Ok(());
+    }
+    let new_principal = current_principal
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    if new_principal > info.max_allocation {
+        return Err(SavingsError::StrategyCapExceeded);
+    }
+    Ok(())
+}
+
 /// Retrieves information about a registered strategy.
 ///
 /// # Arguments
@@ -1,9 +1,10 @@
 use crate::errors::SavingsError;
-use crate::storage_types::DataKey;
+use crate::reentrancy::ReentrancyGuard;
+use crate::storage_types::{DataKey, User};
 use crate::strategy::interface::YieldStrategyClient;
 use crate::strategy::registry::{self, StrategyKey};
 use crate::ttl;
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
 /// Tracks a deposit routed to a yield strategy.
 #[contracttype]
@@ -27,6 +28,34 @@ pub enum StrategyPositionKey {
     Group(u64),
 }
 
+/// Storage key for plans whose funds are split across several strategies.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StrategyAllocationKey {
+    /// All sub-positions recorded for a plan's position key
+    Positions(StrategyPositionKey),
+    /// Position keys that have routed funds into a strategy
+    ByStrategy(Address),
+    /// Principal the protocol paid out for positions the strategy failed to return
+    Shortfall(Address),
+}
+
+/// Storage keys for distributing harvested strategy yield to positions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StrategyYieldKey {
+    /// Cumulative user yield per unit of principal, scaled by `YIELD_INDEX_SCALE`
+    Index(Address),
+    /// Yield index a single-strategy position has been paid up to
+    Checkpoint(StrategyPositionKey),
+}
+
+/// Total weight, in basis points, that a multi-strategy allocation must sum to.
+pub const TOTAL_ALLOCATION_BPS: u32 = 10_000;
+
+/// Fixed-point scale of the per-principal yield index.
+pub const YIELD_INDEX_SCALE: i128 = 1_000_000_000_000;
+
 /// Routes eligible deposit funds to a registered yield strategy.
 ///
 /// Follows the Checks-Effects-Interactions (CEI) pattern:
@@ -50,12 +79,14 @@ pub enum StrategyPositionKey {
 /// * `StrategyNotFound` - Strategy not registered
 /// * `StrategyDisabled` - Strategy is disabled
 /// * `InvalidAmount` - amount <= 0
+/// * `Reentrancy` - Called while another strategy call is in progress
 pub fn route_to_strategy(
     env: &Env,
     strategy_address: Address,
     position_key: StrategyPositionKey,
     amount: i128,
 ) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
     // --- CHECKS ---
     if amount <= 0 {
         return Err(SavingsError::InvalidAmount);
@@ -65,6 +96,9 @@ pub fn route_to_strategy(
     if !info.enabled {
         return Err(SavingsError::StrategyDisabled);
     }
+    let principal_key = DataKey::StrategyTotalPrincipal(strategy_address.clone());
+    let current_principal: i128 = env.storage().persistent().get(&principal_key).unwrap_or(0);
+    registry::ensure_within_cap(&info, current_principal, amount)?;
 
     // --- EFFECTS (state update BEFORE external call) ---
     // Optimistically record the position; Soroban atomically reverts on failure.
@@ -74,6 +108,11 @@ pub fn route_to_strategy(
         strategy_shares: 0, // placeholder, updated after call
     };
     env.storage().persistent().set(&position_key, &position);
+    index_position(env, &strategy_address, &position_key);
+    env.storage().persistent().set(
+        &StrategyYieldKey::Checkpoint(position_key.clone()),
+        &yield_index(env, &strategy_address),
+    );
 
     // --- INTERACTIONS (external call) ---
     let client = YieldStrategyClient::new(env, &strategy_address);
@@ -90,20 +129,22 @@ pub fn route_to_strategy(
         .set(&position_key, &final_position);
 
     // Update global strategy principal
-    let principal_key = DataKey::StrategyTotalPrincipal(strategy_address.clone());
-    let current_principal: i128 = env.storage().persistent().get(&principal_key).unwrap_or(0);
     env.storage().persistent().set(
         &principal_key,
         &current_principal.checked_add(amount).unwrap(),
     );
-    env.storage()
-        .persistent()
-        .extend_ttl(&principal_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
+    env.storage().persistent().extend_ttl(
+        &principal_key,
+        ttl::low_threshold(env),
+        ttl::extend_to(env),
+    );
 
     // Extend TTL
-    env.storage()
-        .persistent()
-        .extend_ttl(&position_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
+    env.storage().persistent().extend_ttl(
+        &position_key,
+        ttl::low_threshold(env),
+        ttl::extend_to(env),
+    );
 
     // Emit event
     env.events().publish(
@@ -114,31 +155,640 @@ pub fn route_to_strategy(
     Ok(shares)
 }
 
+/// Records that `position_key` holds funds in `strategy` so emergency
+/// actions on the strategy can find every affected position.
+fn index_position(env: &Env, strategy: &Address, position_key: &StrategyPositionKey) {
+    let index_key = StrategyAllocationKey::ByStrategy(strategy.clone());
+    let mut keys: Vec<StrategyPositionKey> = env
+        .storage()
+        .persistent()
+        .get(&index_key)
+        .unwrap_or(Vec::new(env));
+    if !keys.contains(position_key) {
+        keys.push_back(position_key.clone());
+        env.storage().persistent().set(&index_key, &keys);
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&index_key, ttl::low_threshold(env), ttl::extend_to(env));
+}
+
+/// Adds `amount` to a plan's single-strategy position, opening one if the
+/// plan has no funded position yet.
+///
+/// # Errors
+/// * `InvalidPlanConfig` - If the plan is already funded in a different strategy
+/// * Any error from `route_to_strategy`
+pub(crate) fn add_to_position(
+    env: &Env,
+    strategy_address: Address,
+    position_key: StrategyPositionKey,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    let existing = get_position(env, position_key.clone()).filter(|p| p.principal_deposited > 0);
+    if let Some(previous) = &existing {
+        if previous.strategy != strategy_address {
+            return Err(SavingsError::InvalidPlanConfig);
+        }
+    }
+
+    let checkpoint_key = StrategyYieldKey::Checkpoint(position_key.clone());
+    let previous_checkpoint: i128 = env.storage().persistent().get(&checkpoint_key).unwrap_or(0);
+
+    let shares = route_to_strategy(env, strategy_address, position_key.clone(), amount)?;
+
+    if let Some(previous) = existing {
+        let mut position: StrategyPosition = env
+            .storage()
+            .persistent()
+            .get(&position_key)
+            .ok_or(SavingsError::InternalError)?;
+        position.principal_deposited = position
+            .principal_deposited
+            .checked_add(previous.principal_deposited)
+            .ok_or(SavingsError::Overflow)?;
+        position.strategy_shares = position
+            .strategy_shares
+            .checked_add(previous.strategy_shares)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&position_key, &position);
+
+        // Move the checkpoint so the unclaimed yield of the previous principal
+        // is preserved and the new principal only earns from here on
+        let index = yield_index(env, &position.strategy);
+        let unpaid = index
+            .checked_sub(previous_checkpoint)
+            .and_then(|delta| delta.checked_mul(previous.principal_deposited))
+            .ok_or(SavingsError::Overflow)?
+            / position.principal_deposited;
+        env.storage()
+            .persistent()
+            .set(&checkpoint_key, &(index - unpaid));
+    }
+
+    Ok(shares)
+}
+
+/// Moves `principal` of a plan's single-strategy position, with a matching
+/// slice of its shares, onto `to_key`. The new position keeps the original
+/// yield checkpoint, so unclaimed yield is split pro rata.
+///
+/// No-op if `from_key` has no funded position.
+pub(crate) fn split_position(
+    env: &Env,
+    from_key: StrategyPositionKey,
+    to_key: StrategyPositionKey,
+    principal: i128,
+) -> Result<(), SavingsError> {
+    let mut position = match get_position(env, from_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return Ok(()),
+    };
+    let moved = principal.min(position.principal_deposited);
+    let moved_shares = position
+        .strategy_shares
+        .checked_mul(moved)
+        .ok_or(SavingsError::Overflow)?
+        / position.principal_deposited;
+
+    let split = StrategyPosition {
+        strategy: position.strategy.clone(),
+        principal_deposited: moved,
+        strategy_shares: moved_shares,
+    };
+    position.principal_deposited -= moved;
+    position.strategy_shares -= moved_shares;
+    env.storage().persistent().set(&from_key, &position);
+    env.storage().persistent().set(&to_key, &split);
+    index_position(env, &split.strategy, &to_key);
+
+    let checkpoint: i128 = env
+        .storage()
+        .persistent()
+        .get(&StrategyYieldKey::Checkpoint(from_key))
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&StrategyYieldKey::Checkpoint(to_key.clone()), &checkpoint);
+    env.storage()
+        .persistent()
+        .extend_ttl(&to_key, ttl::low_threshold(env), ttl::extend_to(env));
+
+    Ok(())
+}
+
+/// Folds the single-strategy position at `from_key` into the one at
+/// `into_key`, preserving each side's unclaimed yield.
+///
+/// No-op if `from_key` has no funded position.
+///
+/// # Errors
+/// * `InvalidPlanConfig` - If both positions are funded in different strategies
+pub(crate) fn merge_position(
+    env: &Env,
+    from_key: StrategyPositionKey,
+    into_key: StrategyPositionKey,
+) -> Result<(), SavingsError> {
+    let from = match get_position(env, from_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return Ok(()),
+    };
+    let into = get_position(env, into_key.clone()).filter(|p| p.principal_deposited > 0);
+    if let Some(into) = &into {
+        if into.strategy != from.strategy {
+            return Err(SavingsError::InvalidPlanConfig);
+        }
+    }
+
+    let index = yield_index(env, &from.strategy);
+    let unpaid = get_pending_yield(env, from_key.clone())
+        .checked_add(get_pending_yield(env, into_key.clone()))
+        .ok_or(SavingsError::Overflow)?;
+    let merged = match into {
+        Some(into) => StrategyPosition {
+            strategy: into.strategy,
+            principal_deposited: into
+                .principal_deposited
+                .checked_add(from.principal_deposited)
+                .ok_or(SavingsError::Overflow)?,
+            strategy_shares: into
+                .strategy_shares
+                .checked_add(from.strategy_shares)
+                .ok_or(SavingsError::Overflow)?,
+        },
+        None => from.clone(),
+    };
+    let checkpoint = index
+        - unpaid
+            .checked_mul(YIELD_INDEX_SCALE)
+            .ok_or(SavingsError::Overflow)?
+            / merged.principal_deposited;
+
+    env.storage().persistent().set(&into_key, &merged);
+    env.storage()
+        .persistent()
+        .set(&StrategyYieldKey::Checkpoint(into_key.clone()), &checkpoint);
+    index_position(env, &merged.strategy, &into_key);
+    env.storage().persistent().set(
+        &from_key,
+        &StrategyPosition {
+            strategy: from.strategy,
+            principal_deposited: 0,
+            strategy_shares: 0,
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&into_key, ttl::low_threshold(env), ttl::extend_to(env));
+
+    Ok(())
+}
+
 /// Retrieves the strategy position for a plan, if any.
 pub fn get_position(env: &Env, position_key: StrategyPositionKey) -> Option<StrategyPosition> {
     env.storage().persistent().get(&position_key)
 }
 
+/// Splits a deposit across several registered yield strategies.
+///
+/// Each allocation is a `(strategy, weight_bps)` pair and the weights must sum
+/// to exactly `TOTAL_ALLOCATION_BPS`. Every strategy receives
+/// `amount * weight_bps / 10_000`; any rounding dust goes to the last
+/// allocation so the full amount is always routed. All strategies are
+/// validated before any state is written or any external call is made.
+///
+/// Routing again for a plan that already has sub-positions merges into
+/// them: a strategy already held gets the new principal and shares added to
+/// its sub-position, and any other strategy is appended.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `allocations` - `(strategy_address, weight_bps)` pairs
+/// * `position_key` - Storage key for this position (Lock or Group)
+/// * `amount` - Total amount to deposit across the strategies
+///
+/// # Returns
+/// The total number of strategy shares received.
+///
+/// # Errors
+/// * `InvalidAmount` - amount <= 0, no allocations, or weights do not sum to 10_000
+/// * `StrategyNotFound` - A strategy is not registered
+/// * `StrategyDisabled` - A strategy is disabled
+/// * `Reentrancy` - Called while another strategy call is in progress
+pub fn route_to_strategies(
+    env: &Env,
+    allocations: Vec<(Address, u32)>,
+    position_key: StrategyPositionKey,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
+    // --- CHECKS ---
+    if amount <= 0 || allocations.is_empty() {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let mut total_weight: u32 = 0;
+    for (strategy_address, weight_bps) in allocations.iter() {
+        total_weight = total_weight
+            .checked_add(weight_bps)
+            .ok_or(SavingsError::InvalidAmount)?;
+        let info = registry::get_strategy(env, strategy_address)?;
+        if !info.enabled {
+            return Err(SavingsError::StrategyDisabled);
+        }
+    }
+    if total_weight != TOTAL_ALLOCATION_BPS {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    // Compute each strategy's share of the deposit
+    let last_index = allocations.len() - 1;
+    let mut remaining = amount;
+    let mut positions: Vec<StrategyPosition> = Vec::new(env);
+    for (i, (strategy_address, weight_bps)) in allocations.iter().enumerate() {
+        let portion = if i as u32 == last_index {
+            remaining
+        } else {
+            amount
+                .checked_mul(weight_bps as i128)
+                .ok_or(SavingsError::Overflow)?
+                / TOTAL_ALLOCATION_BPS as i128
+        };
+        remaining = remaining
+            .checked_sub(portion)
+            .ok_or(SavingsError::Underflow)?;
+        positions.push_back(StrategyPosition {
+            strategy: strategy_address,
+            principal_deposited: portion,
+            strategy_shares: 0,
+        });
+    }
+
+    // --- EFFECTS (state update BEFORE external calls) ---
+    let allocation_key = StrategyAllocationKey::Positions(position_key.clone());
+    let mut merged: Vec<StrategyPosition> = env
+        .storage()
+        .persistent()
+        .get(&allocation_key)
+        .unwrap_or(Vec::new(env));
+    for position in positions.iter() {
+        match merged.iter().position(|p| p.strategy == position.strategy) {
+            Some(i) => {
+                let mut existing = merged.get(i as u32).ok_or(SavingsError::InternalError)?;
+                existing.principal_deposited = existing
+                    .principal_deposited
+                    .checked_add(position.principal_deposited)
+                    .ok_or(SavingsError::Overflow)?;
+                merged.set(i as u32, existing);
+            }
+            None => merged.push_back(position.clone()),
+        }
+        index_position(env, &position.strategy, &position_key);
+    }
+    env.storage().persistent().set(&allocation_key, &merged);
+
+    for position in positions.iter() {
+        if position.principal_deposited == 0 {
+            continue;
+        }
+        let principal_key = DataKey::StrategyTotalPrincipal(position.strategy.clone());
+        let current_principal: i128 = env.storage().persistent().get(&principal_key).unwrap_or(0);
+        let info = registry::get_strategy(env, position.strategy.clone())?;
+        registry::ensure_within_cap(&info, current_principal, position.principal_deposited)?;
+        env.storage().persistent().set(
+            &principal_key,
+            &current_principal
+                .checked_add(position.principal_deposited)
+                .ok_or(SavingsError::Overflow)?,
+        );
+        env.storage().persistent().extend_ttl(
+            &principal_key,
+            ttl::low_threshold(env),
+            ttl::extend_to(env),
+        );
+    }
+
+    // --- INTERACTIONS (external calls) ---
+    let nestera_addr = env.current_contract_address();
+    let mut total_shares: i128 = 0;
+    for position in positions.iter() {
+        if position.principal_deposited > 0 {
+            let client = YieldStrategyClient::new(env, &position.strategy);
+            let shares = client.strategy_deposit(&nestera_addr, &position.principal_deposited);
+            total_shares = total_shares
+                .checked_add(shares)
+                .ok_or(SavingsError::Overflow)?;
+            if let Some(i) = merged.iter().position(|p| p.strategy == position.strategy) {
+                let mut existing = merged.get(i as u32).ok_or(SavingsError::InternalError)?;
+                existing.strategy_shares = existing
+                    .strategy_shares
+                    .checked_add(shares)
+                    .ok_or(SavingsError::Overflow)?;
+                merged.set(i as u32, existing);
+            }
+
+            env.events().publish(
+                (symbol_short!("strat"), symbol_short!("deposit")),
+                (
+                    position.strategy.clone(),
+                    position.principal_deposited,
+                    shares,
+                ),
+            );
+        }
+    }
+
+    // Update shares after successful calls
+    env.storage().persistent().set(&allocation_key, &merged);
+    env.storage().persistent().extend_ttl(
+        &allocation_key,
+        ttl::low_threshold(env),
+        ttl::extend_to(env),
+    );
+
+    Ok(total_shares)
+}
+
+/// Retrieves the multi-strategy sub-positions for a plan, if any.
+pub fn get_positions(
+    env: &Env,
+    position_key: StrategyPositionKey,
+) -> Option<Vec<StrategyPosition>> {
+    env.storage()
+        .persistent()
+        .get(&StrategyAllocationKey::Positions(position_key))
+}
+
+/// Queries a registered strategy for its current APY.
+///
+/// # Returns
+/// The APY reported by the strategy, in basis points.
+///
+/// # Errors
+/// * `StrategyNotFound` - Strategy not registered
+/// * `StrategyCallFailed` - The strategy's `strategy_apy` call failed
+pub fn get_strategy_apy(env: &Env, strategy_address: Address) -> Result<u32, SavingsError> {
+    registry::get_strategy(env, strategy_address.clone())?;
+
+    let client = YieldStrategyClient::new(env, &strategy_address);
+    match client.try_strategy_apy() {
+        Ok(Ok(apy)) => Ok(apy),
+        _ => Err(SavingsError::StrategyCallFailed),
+    }
+}
+
+/// Returns the enabled strategy reporting the highest APY.
+///
+/// Disabled strategies and strategies whose APY call reverts are skipped.
+/// Ties go to the strategy registered first.
+pub fn get_best_strategy(env: &Env) -> Option<Address> {
+    let mut best: Option<(Address, u32)> = None;
+    for strategy_address in registry::get_all_strategies(env).iter() {
+        let enabled = registry::get_strategy(env, strategy_address.clone())
+            .map(|info| info.enabled)
+            .unwrap_or(false);
+        if !enabled {
+            continue;
+        }
+        if let Ok(apy) = get_strategy_apy(env, strategy_address.clone()) {
+            let is_better = match &best {
+                Some((_, best_apy)) => apy > *best_apy,
+                None => true,
+            };
+            if is_better {
+                best = Some((strategy_address, apy));
+            }
+        }
+    }
+    best.map(|(strategy_address, _)| strategy_address)
+}
+
 /// Withdraws funds from a strategy position.
 ///
+/// If the plan's funds were split with `route_to_strategies`, every
+/// sub-position is withdrawn and the amounts returned are summed.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `position_key` - The position to withdraw from
 /// * `to` - The recipient address
+/// * `min_out` - Least total the strategies must return; 0 accepts any amount
 ///
 /// # Returns
 /// The amount of tokens received from the strategy.
+///
+/// # Errors
+/// * `AmountBelowMinimum` - If the strategies return less than `min_out`
 pub fn withdraw_from_strategy(
     env: &Env,
     position_key: StrategyPositionKey,
     to: Address,
+    min_out: i128,
+) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
+    let allocation_key = StrategyAllocationKey::Positions(position_key.clone());
+    if let Some(positions) = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<StrategyPosition>>(&allocation_key)
+    {
+        // Update state for every sub-position BEFORE any external withdraw
+        let mut updated: Vec<StrategyPosition> = Vec::new(env);
+        let mut amounts: Vec<i128> = Vec::new(env);
+        for mut position in positions.iter() {
+            let withdraw_amount = prepare_withdrawal(env, &mut position)?;
+            amounts.push_back(withdraw_amount);
+            updated.push_back(position);
+        }
+        env.storage().persistent().set(&allocation_key, &updated);
+
+        let mut total_returned: i128 = 0;
+        for (position, withdraw_amount) in updated.iter().zip(amounts.iter()) {
+            if withdraw_amount == 0 {
+                continue;
+            }
+            let returned = execute_withdrawal(env, position.strategy, &to, withdraw_amount);
+            total_returned = total_returned
+                .checked_add(returned)
+                .ok_or(SavingsError::Overflow)?;
+        }
+        if total_returned < min_out {
+            return Err(SavingsError::AmountBelowMinimum);
+        }
+        return Ok(total_returned);
+    }
+
+    let mut position: StrategyPosition = env
+        .storage()
+        .persistent()
+        .get(&position_key)
+        .ok_or(SavingsError::StrategyNotFound)?;
+
+    let withdraw_amount = prepare_withdrawal(env, &mut position)?;
+    if withdraw_amount == 0 {
+        return if min_out > 0 {
+            Err(SavingsError::AmountBelowMinimum)
+        } else {
+            Ok(0)
+        };
+    }
+
+    // Update state BEFORE external call
+    env.storage().persistent().set(&position_key, &position);
+
+    let returned = execute_withdrawal(env, position.strategy, &to, withdraw_amount);
+    if returned < min_out {
+        return Err(SavingsError::AmountBelowMinimum);
+    }
+    Ok(returned)
+}
+
+/// Closes a plan's single-strategy position without letting a broken
+/// strategy block the plan's payout.
+///
+/// The position is zeroed and its principal removed from the strategy's
+/// total before the strategy is called. If the strategy is deregistered,
+/// reverts, or returns less than the principal, the difference is added to
+/// the strategy's `Shortfall` record instead of failing, so the caller pays
+/// the plan from contract funds and the admin can recover the strategy's
+/// balance later with `emergency_withdraw_strategy`.
+///
+/// # Returns
+/// The amount the strategy actually returned (0 if the position is empty)
+///
+/// # Errors
+/// * `Reentrancy` - Called while another strategy call is in progress
+pub(crate) fn try_withdraw_from_strategy(
+    env: &Env,
+    position_key: StrategyPositionKey,
+    to: Address,
+) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
+    let mut position = match get_position(env, position_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return Ok(0),
+    };
+    let principal = position.principal_deposited;
+
+    // --- EFFECTS ---
+    position.principal_deposited = 0;
+    position.strategy_shares = 0;
+    env.storage().persistent().set(&position_key, &position);
+    let principal_key = DataKey::StrategyTotalPrincipal(position.strategy.clone());
+    let current_principal: i128 = env.storage().persistent().get(&principal_key).unwrap_or(0);
+    env.storage().persistent().set(
+        &principal_key,
+        &current_principal.saturating_sub(principal).max(0),
+    );
+
+    // --- INTERACTIONS ---
+    let mut returned = 0;
+    if env
+        .storage()
+        .persistent()
+        .has(&StrategyKey::Info(position.strategy.clone()))
+    {
+        let client = YieldStrategyClient::new(env, &position.strategy);
+        let available = match client.try_strategy_balance(&env.current_contract_address()) {
+            Ok(Ok(balance)) => balance.min(principal),
+            _ => 0,
+        };
+        if available > 0 {
+            if let Ok(Ok(amount)) = client.try_strategy_withdraw(&to, &available) {
+                returned = amount.max(0);
+                env.events().publish(
+                    (symbol_short!("strat"), symbol_short!("withdraw")),
+                    (position.strategy.clone(), available, returned),
+                );
+            }
+        }
+    }
+
+    let shortfall = principal - returned.min(principal);
+    if shortfall > 0 {
+        let shortfall_key = StrategyAllocationKey::Shortfall(position.strategy.clone());
+        let recorded: i128 = env.storage().persistent().get(&shortfall_key).unwrap_or(0);
+        env.storage().persistent().set(
+            &shortfall_key,
+            &recorded
+                .checked_add(shortfall)
+                .ok_or(SavingsError::Overflow)?,
+        );
+        env.events().publish(
+            (symbol_short!("strat"), symbol_short!("shortfall")),
+            (position.strategy, position_key, shortfall),
+        );
+    }
+
+    Ok(returned)
+}
+
+/// Returns the principal the protocol has covered for a strategy that failed
+/// to return it.
+pub fn get_shortfall(env: &Env, strategy_address: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyAllocationKey::Shortfall(strategy_address))
+        .unwrap_or(0)
+}
+
+/// Withdraws up to `amount` of principal from a plan's single-strategy
+/// position, leaving the remainder routed.
+///
+/// # Returns
+/// The amount returned by the strategy (0 if the position is empty)
+///
+/// # Errors
+/// * `StrategyNotFound` - If the plan has no position or the strategy is gone
+/// * `InsufficientBalance` - If the strategy reports no balance
+/// * `Reentrancy` - Called while another strategy call is in progress
+pub(crate) fn withdraw_amount_from_strategy(
+    env: &Env,
+    position_key: StrategyPositionKey,
+    amount: i128,
+    to: Address,
 ) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
     let mut position: StrategyPosition = env
         .storage()
         .persistent()
         .get(&position_key)
         .ok_or(SavingsError::StrategyNotFound)?;
 
+    let requested = amount.min(position.principal_deposited);
+    if requested <= 0 {
+        return Ok(0);
+    }
+
+    let mut slice = StrategyPosition {
+        strategy: position.strategy.clone(),
+        principal_deposited: requested,
+        strategy_shares: 0,
+    };
+    let withdraw_amount = prepare_withdrawal(env, &mut slice)?;
+
+    // Update state BEFORE external call
+    let remaining = position.principal_deposited - withdraw_amount;
+    position.strategy_shares = position
+        .strategy_shares
+        .checked_mul(remaining)
+        .ok_or(SavingsError::Overflow)?
+        / position.principal_deposited;
+    position.principal_deposited = remaining;
+    env.storage().persistent().set(&position_key, &position);
+
+    Ok(execute_withdrawal(
+        env,
+        position.strategy,
+        &to,
+        withdraw_amount,
+    ))
+}
+
+/// Computes the amount to withdraw from a single position and applies the
+/// resulting accounting changes to the position and the strategy's global
+/// principal. Returns 0 without touching state if the position is empty.
+fn prepare_withdrawal(env: &Env, position: &mut StrategyPosition) -> Result<i128, SavingsError> {
     if position.principal_deposited == 0 {
         return Ok(0);
     }
@@ -157,13 +807,11 @@ pub fn withdraw_from_strategy(
         return Err(SavingsError::InsufficientBalance);
     }
 
-    // Update state BEFORE external call
     position.principal_deposited = position
         .principal_deposited
         .checked_sub(withdraw_amount)
         .ok_or(SavingsError::Underflow)?;
     position.strategy_shares = 0;
-    env.storage().persistent().set(&position_key, &position);
 
     // Update global strategy principal
     let principal_key = DataKey::StrategyTotalPrincipal(position.strategy.clone());
@@ -176,20 +824,423 @@ pub fn withdraw_from_strategy(
         env.storage().persistent().set(&principal_key, &0_i128);
     }
 
-    // Call strategy withdraw
-    let returned = client.strategy_withdraw(&to, &withdraw_amount);
+    Ok(withdraw_amount)
+}
+
+/// Calls the strategy's withdraw entry point and emits the withdraw event.
+fn execute_withdrawal(env: &Env, strategy: Address, to: &Address, amount: i128) -> i128 {
+    let client = YieldStrategyClient::new(env, &strategy);
+    let returned = client.strategy_withdraw(to, &amount);
 
     env.events().publish(
         (symbol_short!("strat"), symbol_short!("withdraw")),
-        (position.strategy, withdraw_amount, returned),
+        (strategy, amount, returned),
+    );
+
+    returned
+}
+
+/// Moves principal from one registered strategy to another.
+///
+/// Positions move whole: every indexed position (single-strategy or
+/// sub-position) funded in `from_strategy` that fits in the remaining amount
+/// is re-pointed at `to_strategy`, in index order, and `amount` must be
+/// covered exactly. A single-strategy position keeps its unclaimed yield,
+/// which moves with it into `to_strategy`'s yield pool. The new strategy's
+/// shares are split across the moved positions by principal.
+///
+/// All state is updated before the external calls; the withdrawal is sent to
+/// the Nestera contract and immediately deposited into `to_strategy`. If
+/// either call fails the whole operation reverts.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The contract admin
+/// * `from_strategy` - Strategy to pull principal from (may be disabled)
+/// * `to_strategy` - Strategy to deposit into (must be enabled)
+/// * `amount` - Principal to move
+///
+/// # Errors
+/// * `Unauthorized` - Caller is not the admin
+/// * `InvalidAmount` - amount <= 0, both strategies are the same, or no set
+///   of whole positions adds up to `amount`
+/// * `StrategyNotFound` - Either strategy is not registered
+/// * `StrategyDisabled` - `to_strategy` is disabled
+/// * `InsufficientBalance` - `from_strategy` holds less principal than `amount`
+/// * `Reentrancy` - Called while another strategy call is in progress
+pub fn rebalance_strategy(
+    env: &Env,
+    admin: Address,
+    from_strategy: Address,
+    to_strategy: Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
+    // --- CHECKS ---
+    crate::config::require_admin(env, &admin)?;
+
+    if amount <= 0 || from_strategy == to_strategy {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    registry::get_strategy(env, from_strategy.clone())?;
+    let to_info = registry::get_strategy(env, to_strategy.clone())?;
+    if !to_info.enabled {
+        return Err(SavingsError::StrategyDisabled);
+    }
+
+    let from_key = DataKey::StrategyTotalPrincipal(from_strategy.clone());
+    let to_key = DataKey::StrategyTotalPrincipal(to_strategy.clone());
+    let from_principal: i128 = env.storage().persistent().get(&from_key).unwrap_or(0);
+    if from_principal < amount {
+        return Err(SavingsError::InsufficientBalance);
+    }
+    let to_principal: i128 = env.storage().persistent().get(&to_key).unwrap_or(0);
+    registry::ensure_within_cap(&to_info, to_principal, amount)?;
+
+    // Pick whole positions to move; `None` is a plan's single-strategy
+    // position, `Some(i)` its i-th sub-position
+    let from_index_key = StrategyAllocationKey::ByStrategy(from_strategy.clone());
+    let indexed: Vec<StrategyPositionKey> = env
+        .storage()
+        .persistent()
+        .get(&from_index_key)
+        .unwrap_or(Vec::new(env));
+    let mut remaining = amount;
+    let mut moves: Vec<(StrategyPositionKey, Option<u32>, i128)> = Vec::new(env);
+    for position_key in indexed.iter() {
+        if let Some(position) = get_position(env, position_key.clone()) {
+            if position.strategy == from_strategy
+                && position.principal_deposited > 0
+                && position.principal_deposited <= remaining
+            {
+                remaining -= position.principal_deposited;
+                moves.push_back((position_key.clone(), None, position.principal_deposited));
+            }
+        }
+        if let Some(positions) = get_positions(env, position_key.clone()) {
+            for (i, position) in positions.iter().enumerate() {
+                if position.strategy == from_strategy
+                    && position.principal_deposited > 0
+                    && position.principal_deposited <= remaining
+                {
+                    remaining -= position.principal_deposited;
+                    moves.push_back((
+                        position_key.clone(),
+                        Some(i as u32),
+                        position.principal_deposited,
+                    ));
+                }
+            }
+        }
+    }
+    if remaining != 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    // --- EFFECTS ---
+    env.storage()
+        .persistent()
+        .set(&from_key, &(from_principal - amount));
+    env.storage().persistent().set(
+        &to_key,
+        &to_principal
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?,
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&from_key, ttl::low_threshold(env), ttl::extend_to(env));
+    env.storage()
+        .persistent()
+        .extend_ttl(&to_key, ttl::low_threshold(env), ttl::extend_to(env));
+
+    let to_index = yield_index(env, &to_strategy);
+    for (position_key, sub_index, principal) in moves.iter() {
+        match sub_index {
+            None => {
+                // Carry the position's unclaimed yield over to the new pool
+                let pending = get_pending_yield(env, position_key.clone());
+                let from_yield_key = DataKey::StrategyYield(from_strategy.clone());
+                let from_pool: i128 = env.storage().persistent().get(&from_yield_key).unwrap_or(0);
+                let carried = pending.min(from_pool);
+                if carried > 0 {
+                    let to_yield_key = DataKey::StrategyYield(to_strategy.clone());
+                    let to_pool: i128 = env.storage().persistent().get(&to_yield_key).unwrap_or(0);
+                    env.storage()
+                        .persistent()
+                        .set(&from_yield_key, &(from_pool - carried));
+                    env.storage().persistent().set(
+                        &to_yield_key,
+                        &to_pool.checked_add(carried).ok_or(SavingsError::Overflow)?,
+                    );
+                }
+                let checkpoint = to_index
+                    - carried
+                        .checked_mul(YIELD_INDEX_SCALE)
+                        .ok_or(SavingsError::Overflow)?
+                        / principal;
+                env.storage().persistent().set(
+                    &StrategyYieldKey::Checkpoint(position_key.clone()),
+                    &checkpoint,
+                );
+                env.storage().persistent().set(
+                    &position_key,
+                    &StrategyPosition {
+                        strategy: to_strategy.clone(),
+                        principal_deposited: principal,
+                        strategy_shares: 0, // placeholder, updated after call
+                    },
+                );
+            }
+            Some(i) => {
+                let allocation_key = StrategyAllocationKey::Positions(position_key.clone());
+                let mut positions: Vec<StrategyPosition> = env
+                    .storage()
+                    .persistent()
+                    .get(&allocation_key)
+                    .ok_or(SavingsError::InternalError)?;
+                let mut position = positions.get(i).ok_or(SavingsError::InternalError)?;
+                position.strategy = to_strategy.clone();
+                position.strategy_shares = 0; // placeholder, updated after call
+                positions.set(i, position);
+                env.storage().persistent().set(&allocation_key, &positions);
+            }
+        }
+        index_position(env, &to_strategy, &position_key);
+    }
+
+    // Drop index entries that no longer hold anything in `from_strategy`
+    let mut still_indexed: Vec<StrategyPositionKey> = Vec::new(env);
+    for position_key in indexed.iter() {
+        let single = get_position(env, position_key.clone())
+            .is_some_and(|p| p.strategy == from_strategy && p.principal_deposited > 0);
+        let sub = get_positions(env, position_key.clone()).is_some_and(|positions| {
+            positions
+                .iter()
+                .any(|p| p.strategy == from_strategy && p.principal_deposited > 0)
+        });
+        if single || sub {
+            still_indexed.push_back(position_key);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&from_index_key, &still_indexed);
+
+    // --- INTERACTIONS ---
+    let nestera_addr = env.current_contract_address();
+    YieldStrategyClient::new(env, &from_strategy).strategy_withdraw(&nestera_addr, &amount);
+    let shares =
+        YieldStrategyClient::new(env, &to_strategy).strategy_deposit(&nestera_addr, &amount);
+
+    // Split the new shares across the moved positions; dust goes to the last
+    let mut shares_left = shares;
+    let last = moves.len() - 1;
+    for (n, (position_key, sub_index, principal)) in moves.iter().enumerate() {
+        let position_shares = if n as u32 == last {
+            shares_left
+        } else {
+            shares
+                .checked_mul(principal)
+                .ok_or(SavingsError::Overflow)?
+                / amount
+        };
+        shares_left -= position_shares;
+        match sub_index {
+            None => {
+                env.storage().persistent().set(
+                    &position_key,
+                    &StrategyPosition {
+                        strategy: to_strategy.clone(),
+                        principal_deposited: principal,
+                        strategy_shares: position_shares,
+                    },
+                );
+            }
+            Some(i) => {
+                let allocation_key = StrategyAllocationKey::Positions(position_key);
+                let mut positions: Vec<StrategyPosition> = env
+                    .storage()
+                    .persistent()
+                    .get(&allocation_key)
+                    .ok_or(SavingsError::InternalError)?;
+                let mut position = positions.get(i).ok_or(SavingsError::InternalError)?;
+                position.strategy_shares = position_shares;
+                positions.set(i, position);
+                env.storage().persistent().set(&allocation_key, &positions);
+            }
+        }
+    }
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("rebal")),
+        (from_strategy, to_strategy, amount),
+    );
+
+    Ok(())
+}
+
+/// Pulls the strategy's entire balance out, ignoring recorded principal.
+///
+/// Unlike `withdraw_from_strategy`, which caps at the recorded principal,
+/// this withdraws whatever `strategy_balance` reports and then zeroes
+/// `StrategyTotalPrincipal` and every position routed into the strategy.
+/// Intended for a misbehaving strategy; it works even if the strategy is disabled.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The contract admin
+/// * `strategy_address` - Strategy to drain
+/// * `to` - Recipient of the withdrawn funds
+///
+/// # Returns
+/// The amount returned by the strategy.
+///
+/// # Errors
+/// * `Unauthorized` - Caller is not the admin
+/// * `StrategyNotFound` - Strategy is not registered
+/// * `Reentrancy` - Called while another strategy call is in progress
+pub fn emergency_withdraw_strategy(
+    env: &Env,
+    admin: Address,
+    strategy_address: Address,
+    to: Address,
+) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
+    // --- CHECKS ---
+    crate::config::require_admin(env, &admin)?;
+    registry::get_strategy(env, strategy_address.clone())?;
+
+    let client = YieldStrategyClient::new(env, &strategy_address);
+    let balance = client.strategy_balance(&env.current_contract_address());
+
+    // --- EFFECTS ---
+    env.storage().persistent().set(
+        &DataKey::StrategyTotalPrincipal(strategy_address.clone()),
+        &0_i128,
+    );
+
+    let index_key = StrategyAllocationKey::ByStrategy(strategy_address.clone());
+    let keys: Vec<StrategyPositionKey> = env
+        .storage()
+        .persistent()
+        .get(&index_key)
+        .unwrap_or(Vec::new(env));
+    for position_key in keys.iter() {
+        if let Some(mut position) = get_position(env, position_key.clone()) {
+            if position.strategy == strategy_address {
+                position.principal_deposited = 0;
+                position.strategy_shares = 0;
+                env.storage().persistent().set(&position_key, &position);
+            }
+        }
+
+        if let Some(positions) = get_positions(env, position_key.clone()) {
+            let mut updated: Vec<StrategyPosition> = Vec::new(env);
+            for mut position in positions.iter() {
+                if position.strategy == strategy_address {
+                    position.principal_deposited = 0;
+                    position.strategy_shares = 0;
+                }
+                updated.push_back(position);
+            }
+            env.storage()
+                .persistent()
+                .set(&StrategyAllocationKey::Positions(position_key), &updated);
+        }
+    }
+    env.storage().persistent().remove(&index_key);
+
+    // --- INTERACTIONS ---
+    let returned = if balance > 0 {
+        client.strategy_withdraw(&to, &balance)
+    } else {
+        0
+    };
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("emgcy")),
+        (strategy_address, to, returned),
     );
 
     Ok(returned)
 }
 
+/// Overwrites a position's recorded principal with a known `actual_amount`.
+///
+/// Accounting cleanup after an incident where the strategy can no longer be
+/// reached (deregistered or broken), so no external call is made. The
+/// difference is applied to the strategy's `StrategyTotalPrincipal` and the
+/// position's shares are scaled to match.
+///
+/// # Returns
+/// The applied delta (`actual_amount - recorded principal`).
+///
+/// # Errors
+/// * `Unauthorized` - Caller is not the admin
+/// * `InvalidAmount` - `actual_amount` is negative
+/// * `StrategyNotFound` - The plan has no single-strategy position
+pub fn force_reconcile_position(
+    env: &Env,
+    admin: Address,
+    position_key: StrategyPositionKey,
+    actual_amount: i128,
+) -> Result<i128, SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    if actual_amount < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let mut position =
+        get_position(env, position_key.clone()).ok_or(SavingsError::StrategyNotFound)?;
+    let delta = actual_amount
+        .checked_sub(position.principal_deposited)
+        .ok_or(SavingsError::Underflow)?;
+
+    position.strategy_shares = if position.principal_deposited > 0 {
+        position
+            .strategy_shares
+            .checked_mul(actual_amount)
+            .ok_or(SavingsError::Overflow)?
+            / position.principal_deposited
+    } else {
+        0
+    };
+    position.principal_deposited = actual_amount;
+    env.storage().persistent().set(&position_key, &position);
+
+    let principal_key = DataKey::StrategyTotalPrincipal(position.strategy.clone());
+    let current_principal: i128 = env.storage().persistent().get(&principal_key).unwrap_or(0);
+    let new_principal = current_principal
+        .checked_add(delta)
+        .ok_or(SavingsError::Overflow)?
+        .max(0);
+    env.storage()
+        .persistent()
+        .set(&principal_key, &new_principal);
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("reconcile")),
+        (position.strategy, position_key, delta),
+    );
+
+    Ok(delta)
+}
+
 /// Harvests yield from a given strategy, calculates profit,
 /// allocates protocol fee to treasury, and credits the rest to users.
+///
+/// All accounting is written after the strategy calls succeed, so an `Err`
+/// leaves Nestera's state untouched.
+///
+/// # Errors
+/// * `StrategyNotFound` - Strategy not registered
+/// * `StrategyCallFailed` - The strategy's balance or harvest call failed
+/// * `Reentrancy` - Called while another strategy call is in progress
 pub fn harvest_strategy(env: &Env, strategy_address: Address) -> Result<i128, SavingsError> {
+    let _guard = ReentrancyGuard::acquire(env)?;
     // Check if strategy exists
     let info_key = StrategyKey::Info(strategy_address.clone());
     if !env.storage().persistent().has(&info_key) {
@@ -200,7 +1251,10 @@ pub fn harvest_strategy(env: &Env, strategy_address: Address) -> Result<i128, Sa
     let nestera_addr = env.current_contract_address();
 
     // 1. Determine current balance
-    let strategy_balance = client.strategy_balance(&nestera_addr);
+    let strategy_balance = match client.try_strategy_balance(&nestera_addr) {
+        Ok(Ok(balance)) => balance,
+        _ => return Err(SavingsError::StrategyCallFailed),
+    };
 
     // 2. Retrieve recorded principal
     let principal_key = DataKey::StrategyTotalPrincipal(strategy_address.clone());
@@ -213,7 +1267,10 @@ pub fn harvest_strategy(env: &Env, strategy_address: Address) -> Result<i128, Sa
     let profit = strategy_balance - principal;
 
     // 4. Call strategy harvest
-    let harvested = client.strategy_harvest(&nestera_addr);
+    let harvested = match client.try_strategy_harvest(&nestera_addr) {
+        Ok(Ok(amount)) => amount,
+        _ => return Err(SavingsError::StrategyCallFailed),
+    };
 
     // Safety check - we can only distribute what we actually harvested
     let actual_yield = profit.min(harvested);
@@ -238,30 +1295,64 @@ pub fn harvest_strategy(env: &Env, strategy_address: Address) -> Result<i128, Sa
         .checked_sub(treasury_fee)
         .ok_or(SavingsError::Underflow)?;
 
-    // 6. Update accounting records
+    // 6. Compute every new balance before writing any of them
+    let treasury_balance_key = DataKey::FeeAccrued(config.treasury.clone());
+    let new_treasury = env
+        .storage()
+        .persistent()
+        .get::<_, i128>(&treasury_balance_key)
+        .unwrap_or(0)
+        .checked_add(treasury_fee)
+        .ok_or(SavingsError::Overflow)?;
+
+    let yield_key = DataKey::StrategyYield(strategy_address.clone());
+    let new_yield = env
+        .storage()
+        .persistent()
+        .get::<_, i128>(&yield_key)
+        .unwrap_or(0)
+        .checked_add(user_yield)
+        .ok_or(SavingsError::Overflow)?;
+
+    let new_index = if user_yield > 0 && principal > 0 {
+        let index_delta = user_yield
+            .checked_mul(YIELD_INDEX_SCALE)
+            .ok_or(SavingsError::Overflow)?
+            / principal;
+        Some(
+            yield_index(env, &strategy_address)
+                .checked_add(index_delta)
+                .ok_or(SavingsError::Overflow)?,
+        )
+    } else {
+        None
+    };
+
+    // 7. Update accounting records
     if treasury_fee > 0 {
-        let treasury_balance_key = DataKey::TotalBalance(config.treasury.clone());
-        let current_treasury: i128 = env
-            .storage()
+        crate::stats::record_fee(env, treasury_fee)?;
+        env.storage()
             .persistent()
-            .get(&treasury_balance_key)
-            .unwrap_or(0);
-        env.storage().persistent().set(
-            &treasury_balance_key,
-            &(current_treasury.checked_add(treasury_fee).unwrap()),
-        );
+            .set(&treasury_balance_key, &new_treasury);
     }
 
     if user_yield > 0 {
-        let yield_key = DataKey::StrategyYield(strategy_address.clone());
-        let current_yield: i128 = env.storage().persistent().get(&yield_key).unwrap_or(0);
-        env.storage().persistent().set(
+        env.storage().persistent().set(&yield_key, &new_yield);
+        env.storage().persistent().extend_ttl(
             &yield_key,
-            &(current_yield.checked_add(user_yield).unwrap()),
+            ttl::low_threshold(env),
+            ttl::extend_to(env),
+        );
+    }
+
+    if let Some(index) = new_index {
+        let index_key = StrategyYieldKey::Index(strategy_address.clone());
+        env.storage().persistent().set(&index_key, &index);
+        env.storage().persistent().extend_ttl(
+            &index_key,
+            ttl::low_threshold(env),
+            ttl::extend_to(env),
         );
-        env.storage()
-            .persistent()
-            .extend_ttl(&yield_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
     }
 
     env.events().publish(
@@ -271,3 +1362,187 @@ pub fn harvest_strategy(env: &Env, strategy_address: Address) -> Result<i128, Sa
 
     Ok(actual_yield)
 }
+
+/// Returns a strategy's cumulative user yield per unit of principal.
+fn yield_index(env: &Env, strategy: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StrategyYieldKey::Index(strategy.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns the harvested yield a single-strategy position has not yet been paid.
+///
+/// Each harvest advances the strategy's index by the user yield per unit of
+/// principal, so a position's pending yield is its principal times the index
+/// growth since its checkpoint.
+pub fn get_pending_yield(env: &Env, position_key: StrategyPositionKey) -> i128 {
+    let position = match get_position(env, position_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return 0,
+    };
+    let checkpoint: i128 = env
+        .storage()
+        .persistent()
+        .get(&StrategyYieldKey::Checkpoint(position_key))
+        .unwrap_or(0);
+    yield_index(env, &position.strategy)
+        .saturating_sub(checkpoint)
+        .saturating_mul(position.principal_deposited)
+        / YIELD_INDEX_SCALE
+}
+
+/// Pays out the pending yield earned by `principal` of a position, removing
+/// it from the strategy's `StrategyYield` pool.
+///
+/// Paying the full principal moves the checkpoint to the current index so the
+/// same harvest cannot be paid twice. A partial payout leaves the checkpoint
+/// in place; the caller withdraws that principal, so the remainder keeps
+/// exactly its own share.
+///
+/// # Returns
+/// The yield paid (0 if the position is unfunded).
+pub(crate) fn take_position_yield(
+    env: &Env,
+    position_key: StrategyPositionKey,
+    principal: i128,
+) -> Result<i128, SavingsError> {
+    let position = match get_position(env, position_key.clone()) {
+        Some(position) if position.principal_deposited > 0 => position,
+        _ => return Ok(0),
+    };
+    let principal = principal.min(position.principal_deposited);
+    if principal <= 0 {
+        return Ok(0);
+    }
+
+    let pending = get_pending_yield(env, position_key.clone());
+    let yield_key = DataKey::StrategyYield(position.strategy.clone());
+    let pool: i128 = env.storage().persistent().get(&yield_key).unwrap_or(0);
+    let share = (pending
+        .checked_mul(principal)
+        .ok_or(SavingsError::Overflow)?
+        / position.principal_deposited)
+        .min(pool);
+
+    if share > 0 {
+        env.storage().persistent().set(&yield_key, &(pool - share));
+    }
+    if principal == position.principal_deposited {
+        env.storage().persistent().set(
+            &StrategyYieldKey::Checkpoint(position_key),
+            &yield_index(env, &position.strategy),
+        );
+    }
+
+    Ok(share)
+}
+
+/// Claims a lock's pro-rata share of its strategy's harvested yield into the
+/// owner's Flexi balance, leaving the principal in the strategy.
+///
+/// Group positions are not claimable here; their yield is paid to members
+/// as they withdraw.
+///
+/// # Returns
+/// The yield credited (0 if nothing is pending).
+///
+/// # Errors
+/// * `InvalidPlanConfig` - `position_key` is not a lock position
+/// * `PlanNotFound` - The lock does not exist
+/// * `Unauthorized` - `user` does not own the lock
+pub fn claim_strategy_yield(
+    env: &Env,
+    user: Address,
+    position_key: StrategyPositionKey,
+) -> Result<i128, SavingsError> {
+    let lock_id = match position_key {
+        StrategyPositionKey::Lock(lock_id) => lock_id,
+        StrategyPositionKey::Group(_) => return Err(SavingsError::InvalidPlanConfig),
+    };
+    let lock_save = crate::lock::get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    let principal = get_position(env, position_key.clone())
+        .map(|position| position.principal_deposited)
+        .unwrap_or(0);
+    let claimed = take_position_yield(env, position_key.clone(), principal)?;
+    if claimed == 0 {
+        return Ok(0);
+    }
+
+    crate::flexi::credit_flexi_balance(env, &user, claimed)?;
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(claimed)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+    ttl::extend_user_ttl(env, &user);
+
+    env.events().publish(
+        (symbol_short!("strat"), symbol_short!("claim")),
+        (user, position_key, claimed),
+    );
+
+    Ok(claimed)
+}
+
+/// Harvests every strategy in the registry in a single call.
+///
+/// Strategies whose harvest fails are skipped so one misbehaving strategy
+/// cannot block the rest of the batch. Treasury fees and user yield
+/// accumulate per strategy exactly as with `harvest_strategy`.
+///
+/// # Returns
+/// `(strategy, actual_yield)` for each strategy that was harvested successfully.
+pub fn harvest_all_strategies(env: &Env) -> Vec<(Address, i128)> {
+    let mut results = Vec::new(env);
+    for strategy_address in registry::get_all_strategies(env).iter() {
+        if let Ok(amount) = harvest_strategy(env, strategy_address.clone()) {
+            results.push_back((strategy_address, amount));
+        }
+    }
+    results
+}
+
+/// Harvests yield from every strategy backing a plan's position.
+///
+/// Iterates the sub-positions recorded by `route_to_strategies` (or the single
+/// position recorded by `route_to_strategy`) and harvests each distinct
+/// strategy once.
+///
+/// # Returns
+/// The total yield harvested across all strategies.
+///
+/// # Errors
+/// * `StrategyNotFound` - No position exists or a strategy is no longer registered
+pub fn harvest_position_strategies(
+    env: &Env,
+    position_key: StrategyPositionKey,
+) -> Result<i128, SavingsError> {
+    let positions = match get_positions(env, position_key.clone()) {
+        Some(positions) => positions,
+        None => {
+            let position = get_position(env, position_key).ok_or(SavingsError::StrategyNotFound)?;
+            Vec::from_array(env, [position])
+        }
+    };
+
+    let mut harvested: Vec<Address> = Vec::new(env);
+    let mut total: i128 = 0;
+    for position in positions.iter() {
+        if harvested.contains(&position.strategy) {
+            continue;
+        }
+        let amount = harvest_strategy(env, position.strategy.clone())?;
+        total = total.checked_add(amount).ok_or(SavingsError::Overflow)?;
+        harvested.push_back(position.strategy);
+    }
+
+    Ok(total)
+}
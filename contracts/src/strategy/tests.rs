@@ -1,4 +1,5 @@
 use crate::errors::SavingsError;
+use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
 use crate::strategy::registry::{self, StrategyInfo};
 use crate::strategy::routing::{self, StrategyPositionKey};
 use crate::{NesteraContract, NesteraContractClient};
@@ -144,6 +145,78 @@ fn test_route_to_disabled_strategy_fails() {
     });
 }
 
+#[test]
+fn test_toggle_strategy_blocks_deposits_but_not_withdrawals() {
+    let (env, client, admin, _) = setup();
+    let to = Address::generate(&env);
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &1_000);
+
+    client.set_strategy_enabled(&admin, &strategy, &false);
+    assert!(!client.get_strategy(&strategy).enabled);
+    assert_eq!(
+        client.try_route_lock_to_strategy(&admin, &2u64, &strategy, &500),
+        Err(Ok(SavingsError::StrategyDisabled))
+    );
+    assert_eq!(client.withdraw_lock_strategy(&admin, &1u64, &to, &0), 1_000);
+    assert_eq!(client.get_strategy_principal(&strategy), 0);
+
+    client.set_strategy_enabled(&admin, &strategy, &true);
+    assert!(client.get_strategy(&strategy).enabled);
+    client.route_lock_to_strategy(&admin, &2u64, &strategy, &500);
+    assert_eq!(client.get_strategy_principal(&strategy), 500);
+
+    assert_eq!(
+        client.try_set_strategy_enabled(&admin, &Address::generate(&env), &true),
+        Err(Ok(SavingsError::StrategyNotFound))
+    );
+}
+
+#[test]
+fn test_lock_strategy_entrypoints_require_admin() {
+    let (env, client, admin, _) = setup();
+    let outsider = Address::generate(&env);
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &1_000);
+
+    assert_eq!(
+        client.try_route_lock_to_strategy(&outsider, &1u64, &strategy, &1),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_withdraw_lock_strategy(&outsider, &1u64, &outsider, &0),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(
+        client
+            .get_lock_strategy_position(&1u64)
+            .unwrap()
+            .principal_deposited,
+        1_000
+    );
+}
+
+#[test]
+fn test_group_strategy_entrypoints_require_admin() {
+    let (env, client, admin, _) = setup();
+    let outsider = Address::generate(&env);
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.route_group_to_strategy(&admin, &1u64, &strategy, &1_000);
+
+    assert_eq!(
+        client.try_route_group_to_strategy(&outsider, &1u64, &strategy, &1),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_withdraw_group_strategy(&outsider, &1u64, &outsider, &0),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(client.get_strategy_principal(&strategy), 1_000);
+}
+
 #[test]
 fn test_get_position_none_when_empty() {
     let (env, _client, _admin, contract_id) = setup();
@@ -153,3 +226,138 @@ fn test_get_position_none_when_empty() {
         assert!(pos.is_none());
     });
 }
+
+// ========== Allocation Cap Tests ==========
+
+#[test]
+fn test_strategy_cap_limits_routed_principal() {
+    let (env, client, admin, _) = setup();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    assert_eq!(client.get_strategy(&strategy).max_allocation, 0);
+
+    client.set_strategy_cap(&admin, &strategy, &1_000);
+    assert_eq!(client.get_strategy(&strategy).max_allocation, 1_000);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &600);
+    client.route_lock_to_strategy(&admin, &2u64, &strategy, &400);
+    assert_eq!(client.get_strategy_principal(&strategy), 1_000);
+
+    assert_eq!(
+        client.try_route_lock_to_strategy(&admin, &3u64, &strategy, &1),
+        Err(Ok(SavingsError::StrategyCapExceeded))
+    );
+
+    client.set_strategy_cap(&admin, &strategy, &1_500);
+    client.route_lock_to_strategy(&admin, &3u64, &strategy, &500);
+    assert_eq!(client.get_strategy_principal(&strategy), 1_500);
+}
+
+#[test]
+fn test_set_strategy_cap_validation() {
+    let (env, client, admin, _) = setup();
+    let strategy = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_strategy_cap(&admin, &strategy, &100),
+        Err(Ok(SavingsError::StrategyNotFound))
+    );
+
+    client.register_strategy(&admin, &strategy, &1u32);
+    assert_eq!(
+        client.try_set_strategy_cap(&admin, &strategy, &-1),
+        Err(Ok(SavingsError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_strategy_cap(&outsider, &strategy, &100),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+}
+
+// ========== Deregistration Tests ==========
+
+#[test]
+fn test_deregister_strategy_requires_no_funds() {
+    let (env, client, admin, _) = setup();
+    let caller = Address::generate(&env);
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &500);
+
+    assert_eq!(
+        client.try_deregister_strategy(&admin, &strategy),
+        Err(Ok(SavingsError::StrategyHasFunds))
+    );
+    assert_eq!(client.get_all_strategies().len(), 1);
+
+    client.withdraw_lock_strategy(&admin, &1u64, &caller, &0);
+    assert_eq!(client.get_strategy_principal(&strategy), 0);
+
+    client.deregister_strategy(&admin, &strategy);
+    assert_eq!(client.get_all_strategies().len(), 0);
+    assert_eq!(
+        client.try_get_strategy(&strategy),
+        Err(Ok(SavingsError::StrategyNotFound))
+    );
+}
+
+// ========== APY Tests ==========
+
+/// Helper: deploy a mock strategy reporting `apy` and register it.
+fn deploy_with_apy(
+    env: &Env,
+    client: &NesteraContractClient,
+    admin: &Address,
+    apy: u32,
+) -> Address {
+    let strategy = env.register(MockStrategy, ());
+    MockStrategyClient::new(env, &strategy).set_apy(&apy);
+    client.register_strategy(admin, &strategy, &1u32);
+    strategy
+}
+
+#[test]
+fn test_get_strategy_apy() {
+    let (env, client, admin, _) = setup();
+    let strategy = deploy_with_apy(&env, &client, &admin, 750);
+
+    assert_eq!(client.get_strategy_apy(&strategy), 750);
+}
+
+#[test]
+fn test_get_strategy_apy_unregistered_fails() {
+    let (env, client, _admin, _) = setup();
+    let strategy = env.register(MockStrategy, ());
+
+    let result = client.try_get_strategy_apy(&strategy);
+    assert_eq!(result, Err(Ok(SavingsError::StrategyNotFound)));
+}
+
+#[test]
+fn test_get_best_strategy_selects_highest_apy() {
+    let (env, client, admin, _) = setup();
+    deploy_with_apy(&env, &client, &admin, 300);
+    let best = deploy_with_apy(&env, &client, &admin, 900);
+    deploy_with_apy(&env, &client, &admin, 600);
+
+    assert_eq!(client.get_best_strategy(), Some(best));
+}
+
+#[test]
+fn test_get_best_strategy_skips_reverting_and_disabled() {
+    let (env, client, admin, _) = setup();
+    let expected = deploy_with_apy(&env, &client, &admin, 400);
+    let reverting = deploy_with_apy(&env, &client, &admin, 2_000);
+    MockStrategyClient::new(&env, &reverting).set_failing(&true);
+    let disabled = deploy_with_apy(&env, &client, &admin, 1_500);
+    client.disable_strategy(&admin, &disabled);
+
+    assert_eq!(client.get_best_strategy(), Some(expected));
+}
+
+#[test]
+fn test_get_best_strategy_none_when_empty() {
+    let (_env, client, _admin, _) = setup();
+    assert_eq!(client.get_best_strategy(), None);
+}
@@ -1,9 +1,10 @@
 use crate::errors::SavingsError;
 use crate::storage_types::DataKey;
+use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
 use crate::strategy::registry;
 use crate::strategy::routing::{self, StrategyPosition, StrategyPositionKey};
 use crate::{NesteraContract, NesteraContractClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
 
 fn setup() -> (Env, NesteraContractClient<'static>, Address, Address) {
     let env = Env::default();
@@ -49,6 +50,7 @@ fn test_withdraw_from_no_position_returns_error() {
             &env,
             StrategyPositionKey::Lock(99),
             Address::generate(&env),
+            0,
         );
         assert_eq!(result, Err(SavingsError::StrategyNotFound));
     });
@@ -74,6 +76,7 @@ fn test_withdraw_from_zero_principal_returns_zero() {
             &env,
             StrategyPositionKey::Lock(5),
             Address::generate(&env),
+            0,
         );
         // Should short-circuit and return Ok(0) before cross-contract call
         assert_eq!(result, Ok(0));
@@ -99,6 +102,7 @@ fn test_withdraw_strategy_not_registered_errors() {
             &env,
             StrategyPositionKey::Lock(6),
             Address::generate(&env),
+            0,
         );
         // Strategy not in registry → StrategyNotFound
         assert_eq!(result, Err(SavingsError::StrategyNotFound));
@@ -160,3 +164,171 @@ fn test_principal_partial_deduction() {
         );
     });
 }
+
+// ========== Slippage Tests ==========
+
+#[test]
+fn test_withdraw_reverts_when_strategy_returns_below_min_out() {
+    let (env, client, admin, _contract_id) = setup();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    let recipient = Address::generate(&env);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &1_000i128);
+    MockStrategyClient::new(&env, &strategy).set_withdraw_loss(&100i128);
+
+    let result = client.try_withdraw_lock_strategy(&admin, &1u64, &recipient, &901i128);
+    assert_eq!(result, Err(Ok(SavingsError::AmountBelowMinimum)));
+    // The revert leaves the position routed
+    assert_eq!(client.get_strategy_principal(&strategy), 1_000);
+}
+
+#[test]
+fn test_withdraw_succeeds_when_min_out_is_met() {
+    let (env, client, admin, _contract_id) = setup();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    let recipient = Address::generate(&env);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &1_000i128);
+    client.route_group_to_strategy(&admin, &2u64, &strategy, &1_000i128);
+    MockStrategyClient::new(&env, &strategy).set_withdraw_loss(&100i128);
+
+    assert_eq!(
+        client.withdraw_lock_strategy(&admin, &1u64, &recipient, &900i128),
+        900
+    );
+    assert_eq!(
+        client.withdraw_group_strategy(&admin, &2u64, &recipient, &500i128),
+        900
+    );
+    assert_eq!(client.get_strategy_principal(&strategy), 0);
+}
+
+#[test]
+fn test_slippage_checked_withdraw_rejects_non_admin() {
+    let (env, client, admin, _contract_id) = setup();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    let attacker = Address::generate(&env);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &1_000i128);
+    client.route_group_to_strategy(&admin, &2u64, &strategy, &1_000i128);
+
+    // A satisfiable min_out does not let an outsider pull plan funds
+    assert_eq!(
+        client.try_withdraw_lock_strategy(&attacker, &1u64, &attacker, &1_000i128),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_withdraw_group_strategy(&attacker, &2u64, &attacker, &1_000i128),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+    assert_eq!(client.get_strategy_principal(&strategy), 2_000);
+}
+
+// ========== Emergency Withdraw Tests ==========
+
+#[test]
+fn test_emergency_withdraw_takes_full_balance_above_principal() {
+    let (env, client, admin, _contract_id) = setup();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    let recipient = Address::generate(&env);
+
+    client.route_lock_to_strategy(&admin, &1u64, &strategy, &1_000i128);
+    client.route_group_to_strategy(&admin, &2u64, &strategy, &500i128);
+    MockStrategyClient::new(&env, &strategy).add_yield(&250i128);
+
+    // Actual balance (1_750) exceeds recorded principal (1_500)
+    let withdrawn = client.emergency_withdraw_strategy(&admin, &strategy, &recipient);
+    assert_eq!(withdrawn, 1_750);
+
+    assert_eq!(client.get_strategy_principal(&strategy), 0);
+    assert_eq!(
+        MockStrategyClient::new(&env, &strategy).strategy_balance(&recipient),
+        0
+    );
+    let lock_position = client.get_lock_strategy_position(&1u64).unwrap();
+    assert_eq!(lock_position.principal_deposited, 0);
+    assert_eq!(lock_position.strategy_shares, 0);
+    let group_position = client.get_group_strategy_position(&2u64).unwrap();
+    assert_eq!(group_position.principal_deposited, 0);
+}
+
+#[test]
+fn test_emergency_withdraw_zeroes_only_matching_sub_positions() {
+    let (env, client, admin, _contract_id) = setup();
+    let strat_a = env.register(MockStrategy, ());
+    let strat_b = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strat_a, &1u32);
+    client.register_strategy(&admin, &strat_b, &1u32);
+
+    let allocations = vec![
+        &env,
+        (strat_a.clone(), 5_000u32),
+        (strat_b.clone(), 5_000u32),
+    ];
+    client.route_lock_to_strategies(&admin, &1u64, &allocations, &1_000i128);
+
+    client.emergency_withdraw_strategy(&admin, &strat_a, &admin);
+
+    let positions = client.get_lock_strategy_positions(&1u64).unwrap();
+    assert_eq!(positions.get(0).unwrap().principal_deposited, 0);
+    assert_eq!(positions.get(1).unwrap().principal_deposited, 500);
+    assert_eq!(client.get_strategy_principal(&strat_a), 0);
+    assert_eq!(client.get_strategy_principal(&strat_b), 500);
+}
+
+#[test]
+fn test_emergency_withdraw_requires_admin() {
+    let (env, client, admin, _contract_id) = setup();
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    let attacker = Address::generate(&env);
+
+    let result = client.try_emergency_withdraw_strategy(&attacker, &strategy, &attacker);
+    assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+}
+
+#[test]
+fn test_force_reconcile_lowers_global_principal_by_delta() {
+    let (env, client, admin, contract_id) = setup();
+    let strategy = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        seed_position(
+            &env,
+            strategy.clone(),
+            StrategyPositionKey::Lock(1),
+            1_000,
+            true,
+        );
+    });
+
+    let delta = client.force_reconcile_position(&admin, &StrategyPositionKey::Lock(1), &600i128);
+    assert_eq!(delta, -400);
+    assert_eq!(client.get_strategy_principal(&strategy), 600);
+    let position = client.get_lock_strategy_position(&1u64).unwrap();
+    assert_eq!(position.principal_deposited, 600);
+}
+
+#[test]
+fn test_force_reconcile_requires_admin() {
+    let (env, client, _admin, contract_id) = setup();
+    let strategy = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        seed_position(
+            &env,
+            strategy.clone(),
+            StrategyPositionKey::Lock(1),
+            1_000,
+            true,
+        );
+    });
+    let attacker = Address::generate(&env);
+
+    let result =
+        client.try_force_reconcile_position(&attacker, &StrategyPositionKey::Lock(1), &0i128);
+    assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+    assert_eq!(client.get_strategy_principal(&strategy), 1_000);
+}
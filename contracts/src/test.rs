@@ -1,6 +1,10 @@
 #![cfg(test)]
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
 
+use crate::strategy::mock_strategy::{MockStrategy, MockStrategyClient};
 use crate::{NesteraContract, NesteraContractClient, SavingsError};
 
 fn setup() -> (Env, NesteraContractClient<'static>, Address) {
@@ -302,3 +306,620 @@ fn test_error_user_not_found() {
     let result = client.try_break_group_save(&non_existent_user, &group_id);
     assert_eq!(result.unwrap_err(), Ok(SavingsError::UserNotFound));
 }
+
+fn create_auto_group(
+    env: &Env,
+    client: &NesteraContractClient<'static>,
+    creator: &Address,
+    target: i128,
+) -> u64 {
+    let group_id = client.create_group_save(
+        creator,
+        &String::from_str(env, "Auto Group"),
+        &String::from_str(env, "Pays out on target"),
+        &String::from_str(env, "savings"),
+        &target,
+        &1,
+        &1,
+        &true,
+        &1,
+        &1_000_000_000,
+    );
+    client.set_group_auto_distribute(creator, &group_id, &true);
+    group_id
+}
+
+#[test]
+fn test_group_auto_distributes_proportionally_on_target() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let group_id = create_auto_group(&env, &client, &creator, 1_000);
+    client.join_group_save(&member, &group_id);
+
+    client.contribute_to_group_save(&creator, &group_id, &400);
+    assert_eq!(client.get_flexi_balance(&creator), 0);
+    client.contribute_to_group_save(&member, &group_id, &600);
+
+    // No time has passed, so the pool is exactly the contributions
+    assert_eq!(client.get_flexi_balance(&creator), 400);
+    assert_eq!(client.get_flexi_balance(&member), 600);
+    let creator_plan = client.get_savings_plan(&creator, &group_id).unwrap();
+    assert!(creator_plan.is_completed && creator_plan.is_withdrawn);
+
+    // The group has paid out and accepts no further contributions
+    assert_eq!(
+        client.try_contribute_to_group_save(&member, &group_id, &10),
+        Err(Ok(SavingsError::PlanCompleted))
+    );
+}
+
+#[test]
+fn test_group_auto_distribution_accrues_per_member() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let group_id = create_auto_group(&env, &client, &creator, 1_000);
+    client.join_group_save(&member, &group_id);
+    client.contribute_to_group_save(&creator, &group_id, &333);
+
+    // One year at the default 5% group rate accrues only on the creator's 333
+    env.ledger()
+        .with_mut(|li| li.timestamp += 365 * 24 * 60 * 60);
+    client.contribute_to_group_save(&member, &group_id, &667);
+
+    assert_eq!(client.get_flexi_balance(&creator), 349);
+    assert_eq!(client.get_flexi_balance(&member), 667);
+}
+
+#[test]
+fn test_group_auto_distribution_dust_goes_to_creator() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.set_default_group_strategy(&admin, &Some(strategy.clone()));
+
+    let group_id = create_auto_group(&env, &client, &creator, 1_000);
+    client.join_group_save(&member, &group_id);
+    client.contribute_to_group_save(&creator, &group_id, &333);
+    MockStrategyClient::new(&env, &strategy).add_yield(&100);
+    client.contribute_to_group_save(&member, &group_id, &667);
+
+    // 99 of the yield reaches users after the protocol's cut:
+    // 99 * 333 / 1_000 = 32, 99 * 667 / 1_000 = 66, dust 1
+    assert_eq!(client.get_flexi_balance(&member), 733);
+    assert_eq!(client.get_flexi_balance(&creator), 366);
+}
+
+#[test]
+fn test_group_without_auto_distribute_keeps_funds() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Manual Group"),
+        &String::from_str(&env, "No auto payout"),
+        &String::from_str(&env, "savings"),
+        &500,
+        &1,
+        &1,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    assert_eq!(
+        client.try_set_group_auto_distribute(&outsider, &group_id, &true),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+
+    client.contribute_to_group_save(&creator, &group_id, &500);
+    let creator_plan = client.get_savings_plan(&creator, &group_id).unwrap();
+    assert!(creator_plan.is_completed && !creator_plan.is_withdrawn);
+    assert_eq!(client.get_flexi_balance(&creator), 0);
+}
+
+#[test]
+fn test_close_group_after_last_member_leaves() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Empty Group"),
+        &String::from_str(&env, "Everyone leaves"),
+        &String::from_str(&env, "savings"),
+        &1000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.break_group_save(&creator, &group_id);
+
+    client.close_group(&creator, &group_id);
+
+    env.as_contract(&client.address, || {
+        assert!(!crate::group::group_exists(&env, group_id));
+        assert!(crate::group::get_user_groups(&env, &creator).is_empty());
+    });
+}
+
+#[test]
+fn test_close_group_with_active_members_rejected() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Active Group"),
+        &String::from_str(&env, "Still running"),
+        &String::from_str(&env, "savings"),
+        &1000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.join_group_save(&member, &group_id);
+
+    assert_eq!(
+        client.try_close_group(&creator, &group_id),
+        Err(Ok(SavingsError::GroupNotEmpty))
+    );
+}
+
+#[test]
+fn test_close_ended_group_refunds_members_to_flexi() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Lapsed Group"),
+        &String::from_str(&env, "Never reached target"),
+        &String::from_str(&env, "savings"),
+        &1000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000,
+    );
+    client.join_group_save(&member, &group_id);
+    client.contribute_to_group_save(&member, &group_id, &300);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.close_group(&creator, &group_id);
+
+    assert_eq!(client.get_flexi_balance(&member), 300);
+    assert_eq!(client.get_flexi_balance(&creator), 0);
+    assert!(client.get_savings_plan(&member, &group_id).is_none());
+}
+
+#[test]
+fn test_group_membership_tracks_join_and_leave() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Members"),
+        &String::from_str(&env, "Membership checks"),
+        &String::from_str(&env, "savings"),
+        &1000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.join_group_save(&member, &group_id);
+
+    assert!(client.is_group_member(&group_id, &creator));
+    assert!(client.is_group_member(&group_id, &member));
+    assert!(!client.is_group_member(&group_id, &outsider));
+    assert_eq!(client.get_member_count(&group_id), 2);
+
+    client.break_group_save(&member, &group_id);
+    assert!(!client.is_group_member(&group_id, &member));
+    assert_eq!(client.get_member_count(&group_id), 1);
+}
+
+#[test]
+fn test_rejoin_blocked_during_join_cooldown() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+    client.set_group_join_cooldown(&admin, &3_600);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Churn"),
+        &String::from_str(&env, "Join cooldown"),
+        &String::from_str(&env, "savings"),
+        &1000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.join_group_save(&member, &group_id);
+    client.break_group_save(&member, &group_id);
+
+    assert_eq!(
+        client.try_join_group_save(&member, &group_id),
+        Err(Ok(SavingsError::TooEarly))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    client.join_group_save(&member, &group_id);
+    assert!(client.is_group_member(&group_id, &member));
+}
+
+#[test]
+fn test_join_rejected_once_group_is_full() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    let late = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+    client.initialize_user(&late);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Capped Group"),
+        &String::from_str(&env, "Two seats"),
+        &String::from_str(&env, "savings"),
+        &1000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.set_group_max_members(&creator, &group_id, &2);
+    assert_eq!(client.list_public_groups(&0, &10).len(), 1);
+
+    client.join_group_save(&member, &group_id);
+    assert_eq!(client.list_public_groups(&0, &10).len(), 0);
+
+    assert_eq!(
+        client.try_join_group_save(&late, &group_id),
+        Err(Ok(SavingsError::GroupFull))
+    );
+}
+
+fn create_typed_group(
+    env: &Env,
+    client: &NesteraContractClient<'_>,
+    creator: &Address,
+    contribution_type: u32,
+) -> u64 {
+    client.create_group_save(
+        creator,
+        &String::from_str(env, "Typed Group"),
+        &String::from_str(env, "Contribution rules"),
+        &String::from_str(env, "savings"),
+        &10_000,
+        &contribution_type,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    )
+}
+
+#[test]
+fn test_fixed_group_accepts_only_whole_contributions() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    let group_id = create_typed_group(&env, &client, &creator, 0);
+
+    assert_eq!(
+        client.try_contribute_to_group_save(&creator, &group_id, &150),
+        Err(Ok(SavingsError::InvalidContribution))
+    );
+    client.contribute_to_group_save(&creator, &group_id, &100);
+    client.contribute_to_group_save(&creator, &group_id, &300);
+}
+
+#[test]
+fn test_flexible_group_rejects_below_minimum() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    let group_id = create_typed_group(&env, &client, &creator, 1);
+
+    assert_eq!(
+        client.try_contribute_to_group_save(&creator, &group_id, &99),
+        Err(Ok(SavingsError::InvalidContribution))
+    );
+    client.contribute_to_group_save(&creator, &group_id, &150);
+}
+
+#[test]
+fn test_group_contributions_route_to_default_strategy() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.set_default_group_strategy(&admin, &Some(strategy.clone()));
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Routed Group"),
+        &String::from_str(&env, "Earns strategy yield"),
+        &String::from_str(&env, "savings"),
+        &10_000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.join_group_save(&member, &group_id);
+    client.contribute_to_group_save(&creator, &group_id, &600);
+    client.contribute_to_group_save(&member, &group_id, &400);
+
+    let position = client.get_group_strategy_position(&group_id).unwrap();
+    assert_eq!(position.strategy, strategy);
+    assert_eq!(position.principal_deposited, 1_000);
+    let strategy_client = MockStrategyClient::new(&env, &strategy);
+    assert_eq!(strategy_client.strategy_balance(&creator), 1_000);
+
+    // Strategy earns 100; the leaving member's 40% share is 40
+    strategy_client.add_yield(&100);
+    client.break_group_save(&member, &group_id);
+
+    let history = client.get_user_history(&member, &1);
+    assert_eq!(history.get(0).unwrap().amount, 440);
+    assert_eq!(
+        client
+            .get_group_strategy_position(&group_id)
+            .unwrap()
+            .principal_deposited,
+        600
+    );
+    assert_eq!(client.get_strategy_principal(&strategy), 600);
+    assert_eq!(client.get_strategy_yield(&strategy), 60);
+    assert_eq!(strategy_client.strategy_balance(&creator), 600);
+}
+
+#[test]
+fn test_close_group_withdraws_strategy_position() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+
+    let strategy = env.register(MockStrategy, ());
+    client.register_strategy(&admin, &strategy, &1u32);
+    client.set_default_group_strategy(&admin, &Some(strategy.clone()));
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Lapsed Routed Group"),
+        &String::from_str(&env, "Ends short of target"),
+        &String::from_str(&env, "savings"),
+        &10_000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000,
+    );
+    client.join_group_save(&member, &group_id);
+    client.contribute_to_group_save(&creator, &group_id, &600);
+    client.contribute_to_group_save(&member, &group_id, &400);
+
+    let strategy_client = MockStrategyClient::new(&env, &strategy);
+    strategy_client.add_yield(&100);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.close_group(&creator, &group_id);
+
+    assert_eq!(client.get_flexi_balance(&creator), 660);
+    assert_eq!(client.get_flexi_balance(&member), 440);
+    assert_eq!(client.get_strategy_principal(&strategy), 0);
+    assert_eq!(strategy_client.strategy_balance(&creator), 0);
+    assert!(client
+        .get_group_strategy_position(&group_id)
+        .is_none_or(|position| position.principal_deposited == 0));
+}
+
+#[test]
+fn test_group_without_default_strategy_is_not_routed() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+
+    let group_id = client.create_group_save(
+        &creator,
+        &String::from_str(&env, "Plain Group"),
+        &String::from_str(&env, "No strategy"),
+        &String::from_str(&env, "savings"),
+        &10_000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000_000,
+    );
+    client.contribute_to_group_save(&creator, &group_id, &500);
+    assert!(client.get_group_strategy_position(&group_id).is_none());
+
+    client.break_group_save(&creator, &group_id);
+    let history = client.get_user_history(&creator, &1);
+    assert_eq!(history.get(0).unwrap().amount, 500);
+}
+
+#[test]
+fn test_plan_balance_overflow_logs_diagnostic() {
+    use soroban_sdk::testutils::Logs;
+
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+    client.initialize_user(&user);
+    client.create_savings_plan(&user, &crate::PlanType::Flexi, &1);
+
+    let result = client.try_create_savings_plan(&user, &crate::PlanType::Flexi, &i128::MAX);
+    assert_eq!(result, Err(Ok(SavingsError::Overflow)));
+
+    // The log survives the reverted call and names the overflowing operation
+    let logs = env.logs().all();
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("overflow") && log.contains("balance_add")));
+}
+
+#[test]
+fn test_group_contributions_list_every_member() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member1);
+    client.initialize_user(&member2);
+    let group_id = create_typed_group(&env, &client, &creator, 1);
+    client.join_group_save(&member1, &group_id);
+    client.join_group_save(&member2, &group_id);
+
+    client.contribute_to_group_save(&creator, &group_id, &100);
+    client.contribute_to_group_save(&member1, &group_id, &250);
+    client.contribute_to_group_save(&member2, &group_id, &400);
+
+    let contributions = client.get_group_contributions(&group_id);
+    assert_eq!(contributions.len(), 3);
+    assert_eq!(contributions.get(0).unwrap(), (creator, 100));
+    assert_eq!(contributions.get(1).unwrap(), (member1, 250));
+    assert_eq!(contributions.get(2).unwrap(), (member2, 400));
+
+    let total: i128 = contributions.iter().map(|(_, amount)| amount).sum();
+    let group = env.as_contract(&client.address, || {
+        crate::group::get_group_save(&env, group_id).unwrap()
+    });
+    assert_eq!(total, group.current_amount);
+}
+
+#[test]
+fn test_group_contributions_stop_at_target() {
+    let (env, client, _admin) = setup();
+    let creator = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    let group_id = create_typed_group(&env, &client, &creator, 1);
+
+    client.contribute_to_group_save(&creator, &group_id, &9_000);
+    assert_eq!(
+        client.try_contribute_to_group_save(&creator, &group_id, &1_001),
+        Err(Ok(SavingsError::InvalidContribution))
+    );
+
+    // The contribution landing exactly on the target completes the group
+    client.contribute_to_group_save(&creator, &group_id, &1_000);
+    assert_eq!(
+        client.try_contribute_to_group_save(&creator, &group_id, &100),
+        Err(Ok(SavingsError::PlanCompleted))
+    );
+    assert_eq!(
+        client.get_group_contributions(&group_id).get(0).unwrap().1,
+        10_000
+    );
+}
+
+#[test]
+fn test_group_break_fee_falls_back_to_shared_fee() {
+    let (env, client, admin) = setup();
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&creator);
+    client.initialize_user(&member);
+    assert!(client.try_set_fee_recipient(&treasury).is_ok());
+    assert!(client.try_set_early_break_fee_bps(&500).is_ok()); // 5% shared
+    let group_id = create_typed_group(&env, &client, &creator, 1);
+    client.join_group_save(&member, &group_id);
+    client.contribute_to_group_save(&member, &group_id, &1_000);
+
+    // No group-specific fee yet: the shared 5% applies
+    client.break_group_save(&member, &group_id);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 50);
+
+    client.set_group_break_fee_bps(&admin, &1_000); // 10%
+    client.contribute_to_group_save(&creator, &group_id, &1_000);
+    client.break_group_save(&creator, &group_id);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 150);
+}
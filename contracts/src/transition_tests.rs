@@ -2,7 +2,7 @@
 mod transition_tests {
     use crate::governance::ProposalAction;
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
 
     fn setup_contract() -> (Env, NesteraContractClient<'static>, Address) {
@@ -25,6 +25,9 @@ mod transition_tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
         client.initialize_rewards_config(&config);
 
@@ -99,12 +102,45 @@ mod transition_tests {
         let action = ProposalAction::SetFlexiRate(500);
 
         let proposal_id = client
-            .try_create_action_proposal(&creator, &description, &action)
+            .try_create_action_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &description,
+                &action,
+            )
             .unwrap()
             .unwrap();
 
         let proposal = client.get_action_proposal(&proposal_id).unwrap();
         assert_eq!(proposal.action, ProposalAction::SetFlexiRate(500));
+        assert_eq!(proposal.title, String::from_str(&env, "Title"));
+        assert_eq!(proposal.category, String::from_str(&env, "general"));
+    }
+
+    #[test]
+    fn test_create_action_proposal_rejects_empty_metadata() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        let empty = String::from_str(&env, "");
+        let text = String::from_str(&env, "text");
+        let action = ProposalAction::SetFlexiRate(500);
+
+        assert_eq!(
+            client.try_create_action_proposal(&creator, &empty, &text, &text, &action),
+            Err(Ok(SavingsError::InvalidProposal))
+        );
+        assert_eq!(
+            client.try_create_action_proposal(&creator, &text, &empty, &text, &action),
+            Err(Ok(SavingsError::InvalidProposal))
+        );
     }
 
     #[test]
@@ -1,6 +1,8 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
-use crate::storage_types::{DataKey, GoalSave, LockSave, SavingsPlan};
+use crate::config;
+use crate::errors::SavingsError;
+use crate::storage_types::{ConfigKey, DataKey, GoalSave, LockSave, SavingsPlan, TtlConfig};
 
 // TTL Constants (in ledgers)
 // Assuming ~5 seconds per ledger:
@@ -20,17 +22,81 @@ pub const EXTEND_TO: u32 = 3_110_400; // ~180 days (6 months)
 /// Shorter extension for completed/archived plans
 pub const EXTEND_ARCHIVED: u32 = 518_400; // ~30 days
 
+// ========== Configurable Thresholds ==========
+
+/// Returns the TTL thresholds in effect, falling back to the constants above
+/// when no admin override has been stored.
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            low_threshold: LOW_THRESHOLD,
+            extend_to: EXTEND_TO,
+            extend_archived: EXTEND_ARCHIVED,
+        })
+}
+
+/// Overrides the TTL thresholds used by every `extend_*` helper.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `admin` - The contract admin
+/// * `low_threshold` - Remaining TTL (in ledgers) below which entries are extended
+/// * `extend_to` - TTL active entries are extended to
+/// * `extend_archived` - TTL completed/withdrawn entries are extended to
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+/// * `InvalidAmount` - If either extension is below `low_threshold`
+pub fn set_ttl_config(
+    env: &Env,
+    admin: Address,
+    low_threshold: u32,
+    extend_to: u32,
+    extend_archived: u32,
+) -> Result<(), SavingsError> {
+    config::require_admin(env, &admin)?;
+
+    if extend_to < low_threshold || extend_archived < low_threshold {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let ttl_config = TtlConfig {
+        low_threshold,
+        extend_to,
+        extend_archived,
+    };
+    env.storage()
+        .instance()
+        .set(&ConfigKey::TtlConfig, &ttl_config);
+    env.events()
+        .publish((symbol_short!("ttl_cfg"), admin), ttl_config);
+
+    Ok(())
+}
+
+pub(crate) fn low_threshold(env: &Env) -> u32 {
+    get_ttl_config(env).low_threshold
+}
+
+pub(crate) fn extend_to(env: &Env) -> u32 {
+    get_ttl_config(env).extend_to
+}
+
 /// Extends the instance storage TTL
 /// Used for contract-level configuration that should persist long-term
 pub fn extend_instance_ttl(env: &Env) {
+    let cfg = get_ttl_config(env);
     env.storage()
         .instance()
-        .extend_ttl(LOW_THRESHOLD, EXTEND_TO);
+        .extend_ttl(cfg.low_threshold, cfg.extend_to);
 }
 
 /// Extends TTL for user-related storage entries
 /// Includes: User data, FlexiBalance, TotalBalance
 pub fn extend_user_ttl(env: &Env, user: &Address) {
+    let cfg = get_ttl_config(env);
     let user_key = DataKey::User(user.clone());
     let flexi_key = DataKey::FlexiBalance(user.clone());
     let total_key = DataKey::TotalBalance(user.clone());
@@ -39,40 +105,42 @@ pub fn extend_user_ttl(env: &Env, user: &Address) {
     if env.storage().persistent().has(&user_key) {
         env.storage()
             .persistent()
-            .extend_ttl(&user_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(&user_key, cfg.low_threshold, cfg.extend_to);
     }
 
     if env.storage().persistent().has(&flexi_key) {
         env.storage()
             .persistent()
-            .extend_ttl(&flexi_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(&flexi_key, cfg.low_threshold, cfg.extend_to);
     }
 
     if env.storage().persistent().has(&total_key) {
         env.storage()
             .persistent()
-            .extend_ttl(&total_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(&total_key, cfg.low_threshold, cfg.extend_to);
     }
 }
 
 /// Extends TTL for a savings plan
 /// Only extends if the plan is active (not completed/withdrawn)
 pub fn extend_plan_ttl(env: &Env, plan_key: &DataKey) {
+    let cfg = get_ttl_config(env);
     // Check if the plan should be extended
     if should_extend_plan(env, plan_key) {
         env.storage()
             .persistent()
-            .extend_ttl(plan_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(plan_key, cfg.low_threshold, cfg.extend_to);
     } else {
         // For completed/archived plans, use shorter extension
         env.storage()
             .persistent()
-            .extend_ttl(plan_key, LOW_THRESHOLD, EXTEND_ARCHIVED);
+            .extend_ttl(plan_key, cfg.low_threshold, cfg.extend_archived);
     }
 }
 
 /// Extends TTL for a Lock Save plan
 pub fn extend_lock_ttl(env: &Env, lock_id: u64) {
+    let cfg = get_ttl_config(env);
     let lock_key = DataKey::LockSave(lock_id);
 
     if let Some(lock_save) = env
@@ -82,20 +150,23 @@ pub fn extend_lock_ttl(env: &Env, lock_id: u64) {
     {
         if lock_save.is_withdrawn {
             // Already withdrawn - use shorter extension
-            env.storage()
-                .persistent()
-                .extend_ttl(&lock_key, LOW_THRESHOLD, EXTEND_ARCHIVED);
+            env.storage().persistent().extend_ttl(
+                &lock_key,
+                cfg.low_threshold,
+                cfg.extend_archived,
+            );
         } else {
             // Active plan - full extension
             env.storage()
                 .persistent()
-                .extend_ttl(&lock_key, LOW_THRESHOLD, EXTEND_TO);
+                .extend_ttl(&lock_key, cfg.low_threshold, cfg.extend_to);
         }
     }
 }
 
 /// Extends TTL for a Goal Save plan
 pub fn extend_goal_ttl(env: &Env, goal_id: u64) {
+    let cfg = get_ttl_config(env);
     let goal_key = DataKey::GoalSave(goal_id);
 
     if let Some(goal_save) = env
@@ -105,77 +176,132 @@ pub fn extend_goal_ttl(env: &Env, goal_id: u64) {
     {
         if goal_save.is_completed || goal_save.is_withdrawn {
             // Completed/withdrawn - use shorter extension
-            env.storage()
-                .persistent()
-                .extend_ttl(&goal_key, LOW_THRESHOLD, EXTEND_ARCHIVED);
+            env.storage().persistent().extend_ttl(
+                &goal_key,
+                cfg.low_threshold,
+                cfg.extend_archived,
+            );
         } else {
             // Active plan - full extension
             env.storage()
                 .persistent()
-                .extend_ttl(&goal_key, LOW_THRESHOLD, EXTEND_TO);
+                .extend_ttl(&goal_key, cfg.low_threshold, cfg.extend_to);
         }
     }
 }
 
 /// Extends TTL for a Group Save plan
 pub fn extend_group_ttl(env: &Env, group_id: u64) {
+    let cfg = get_ttl_config(env);
     let group_key = DataKey::GroupSave(group_id);
     let members_key = DataKey::GroupMembers(group_id);
 
     if env.storage().persistent().has(&group_key) {
         env.storage()
             .persistent()
-            .extend_ttl(&group_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(&group_key, cfg.low_threshold, cfg.extend_to);
     }
 
     if env.storage().persistent().has(&members_key) {
         env.storage()
             .persistent()
-            .extend_ttl(&members_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(&members_key, cfg.low_threshold, cfg.extend_to);
     }
 }
 
 /// Extends TTL for user's list of plans (Lock/Goal/Group/AutoSave)
 pub fn extend_user_plan_list_ttl(env: &Env, list_key: &DataKey) {
+    let cfg = get_ttl_config(env);
     // Only extend TTL if the key exists
     if env.storage().persistent().has(list_key) {
         env.storage()
             .persistent()
-            .extend_ttl(list_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(list_key, cfg.low_threshold, cfg.extend_to);
     }
 }
 
 /// Extends TTL for an AutoSave schedule
 pub fn extend_autosave_ttl(env: &Env, schedule_id: u64) {
+    let cfg = get_ttl_config(env);
     let schedule_key = DataKey::AutoSave(schedule_id);
     // Only extend TTL if the key exists
     if env.storage().persistent().has(&schedule_key) {
         env.storage()
             .persistent()
-            .extend_ttl(&schedule_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(&schedule_key, cfg.low_threshold, cfg.extend_to);
     }
 }
 
 /// Extends TTL for configuration entries (rates, fees, etc.)
 pub fn extend_config_ttl(env: &Env, config_key: &DataKey) {
+    let cfg = get_ttl_config(env);
     // Only extend TTL if the key exists
     if env.storage().persistent().has(config_key) {
         env.storage()
             .persistent()
-            .extend_ttl(config_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(config_key, cfg.low_threshold, cfg.extend_to);
     }
 }
 
 /// Extends TTL for next ID counters
 pub fn extend_counter_ttl(env: &Env, counter_key: &DataKey) {
+    let cfg = get_ttl_config(env);
     // Only extend TTL if the key exists
     if env.storage().persistent().has(counter_key) {
         env.storage()
             .persistent()
-            .extend_ttl(counter_key, LOW_THRESHOLD, EXTEND_TO);
+            .extend_ttl(counter_key, cfg.low_threshold, cfg.extend_to);
     }
 }
 
+/// Extends the TTL of one of the user's plans without touching its state.
+///
+/// `plan_kind` is `lock`, `goal` or `group`; locks and goals must be owned by
+/// `user`, groups must count `user` as a member. The entry's new expiration
+/// ledger is not observable from inside the contract, so nothing is returned.
+///
+/// # Errors
+/// * `InvalidPlanConfig` - If `plan_kind` is not recognised
+/// * `PlanNotFound` - If the plan does not exist
+/// * `Unauthorized` - If `user` does not own (or belong to) the plan
+pub fn bump_plan_ttl(
+    env: &Env,
+    user: Address,
+    plan_kind: Symbol,
+    plan_id: u64,
+) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    if plan_kind == symbol_short!("lock") {
+        let lock_save =
+            crate::lock::get_lock_save(env, plan_id).ok_or(SavingsError::PlanNotFound)?;
+        if lock_save.owner != user {
+            return Err(SavingsError::Unauthorized);
+        }
+        extend_lock_ttl(env, plan_id);
+    } else if plan_kind == symbol_short!("goal") {
+        let goal_save =
+            crate::goal::get_goal_save(env, plan_id).ok_or(SavingsError::PlanNotFound)?;
+        if goal_save.owner != user {
+            return Err(SavingsError::Unauthorized);
+        }
+        extend_goal_ttl(env, plan_id);
+    } else if plan_kind == symbol_short!("group") {
+        if !crate::group::group_exists(env, plan_id) {
+            return Err(SavingsError::PlanNotFound);
+        }
+        if !crate::group::get_group_members(env, plan_id).contains(&user) {
+            return Err(SavingsError::Unauthorized);
+        }
+        extend_group_ttl(env, plan_id);
+    } else {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    extend_user_ttl(env, &user);
+    Ok(())
+}
+
 // ========== Helper Functions ==========
 
 /// Determines if a plan should receive full TTL extension
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use crate::{NesteraContract, NesteraContractClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+    use crate::ttl;
+    use crate::{DataKey, NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{storage::Persistent as _, Address as _, Ledger},
+        Address, BytesN, Env, Symbol,
+    };
 
     fn setup_test_env() -> (Env, NesteraContractClient<'static>) {
         let env = Env::default();
@@ -63,7 +68,7 @@ mod tests {
         client.initialize_user(&user);
 
         // Create lock save - should extend TTL
-        let lock_id = client.create_lock_save(&user, &5000, &(30 * 24 * 60 * 60));
+        let lock_id = client.create_lock_save(&user, &5000, &(30 * 24 * 60 * 60), &false);
         assert_eq!(lock_id, 1);
 
         // Get user lock saves - should extend TTL
@@ -123,7 +128,7 @@ mod tests {
         assert!(goal.is_completed);
 
         // Withdraw completed goal - should extend TTL
-        let amount = client.withdraw_completed_goal_save(&user, &goal_id);
+        let amount = client.withdraw_completed_goal_save(&user, &goal_id).net;
         assert_eq!(amount, 5000);
 
         // Withdrawn goals should extend TTL with shorter duration
@@ -198,7 +203,7 @@ mod tests {
         client.initialize_user(&user);
 
         // Create a lock save
-        let lock_id = client.create_lock_save(&user, &5000, &(30 * 24 * 60 * 60));
+        let lock_id = client.create_lock_save(&user, &5000, &(30 * 24 * 60 * 60), &false);
 
         // Simulate multiple interactions (each should extend TTL)
         for _ in 0..10 {
@@ -243,4 +248,117 @@ mod tests {
         client.initialize_user(&member);
         client.join_group_save(&member, &group_id);
     }
+
+    fn setup_admin_env() -> (Env, NesteraContractClient<'static>, Address) {
+        let (env, client) = setup_test_env();
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize(&admin, &BytesN::from_array(&env, &[1u8; 32]));
+        (env, client, admin)
+    }
+
+    fn persistent_ttl(env: &Env, client: &NesteraContractClient, key: &DataKey) -> u32 {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+    }
+
+    #[test]
+    fn test_ttl_config_defaults_to_constants() {
+        let (_env, client, _admin) = setup_admin_env();
+
+        let config = client.get_ttl_config();
+        assert_eq!(config.low_threshold, ttl::LOW_THRESHOLD);
+        assert_eq!(config.extend_to, ttl::EXTEND_TO);
+        assert_eq!(config.extend_archived, ttl::EXTEND_ARCHIVED);
+    }
+
+    #[test]
+    fn test_custom_ttl_config_used_by_extensions() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        client.set_ttl_config(&admin, &100_000, &200_000, &150_000);
+        assert_eq!(client.get_ttl_config().extend_to, 200_000);
+
+        client.initialize_user(&user);
+        assert_eq!(
+            persistent_ttl(&env, &client, &DataKey::User(user.clone())),
+            200_000
+        );
+
+        // Withdrawn locks get the archived extension
+        let lock_id = client.create_lock_save(&user, &1_000, &1, &false);
+        assert_eq!(
+            persistent_ttl(&env, &client, &DataKey::LockSave(lock_id)),
+            200_000
+        );
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        client.withdraw_lock_save(&user, &lock_id);
+        // Already above the threshold, so the archived extension does not shrink it
+        assert_eq!(
+            persistent_ttl(&env, &client, &DataKey::LockSave(lock_id)),
+            200_000
+        );
+    }
+
+    #[test]
+    fn test_set_ttl_config_validates_inputs() {
+        let (env, client, admin) = setup_admin_env();
+        let non_admin = Address::generate(&env);
+
+        assert_eq!(
+            client.try_set_ttl_config(&admin, &100_000, &99_999, &100_000),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_set_ttl_config(&admin, &100_000, &100_000, &99_999),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_set_ttl_config(&non_admin, &100_000, &200_000, &200_000),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(client.get_ttl_config().extend_to, ttl::EXTEND_TO);
+    }
+
+    #[test]
+    fn test_bump_plan_ttl_extends_idle_lock() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &(180 * 24 * 60 * 60), &false);
+
+        // Let the entry drift below the extension threshold
+        env.ledger()
+            .with_mut(|li| li.sequence_number += ttl::EXTEND_TO - ttl::LOW_THRESHOLD + 1);
+        let key = DataKey::LockSave(lock_id);
+        assert!(persistent_ttl(&env, &client, &key) < ttl::LOW_THRESHOLD);
+
+        client.bump_plan_ttl(&user, &symbol_short!("lock"), &lock_id);
+        assert_eq!(persistent_ttl(&env, &client, &key), ttl::EXTEND_TO);
+    }
+
+    #[test]
+    fn test_bump_plan_ttl_rejects_non_owner_and_unknown_plans() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.initialize_user(&user);
+        client.initialize_user(&other);
+
+        let lock_id = client.create_lock_save(&user, &1_000, &100, &false);
+
+        assert_eq!(
+            client.try_bump_plan_ttl(&other, &symbol_short!("lock"), &lock_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_bump_plan_ttl(&user, &symbol_short!("goal"), &99),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+        assert_eq!(
+            client.try_bump_plan_ttl(&user, &symbol_short!("flexi"), &lock_id),
+            Err(Ok(SavingsError::InvalidPlanConfig))
+        );
+    }
 }
@@ -1,8 +1,8 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{symbol_short, Address, Env};
 
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
-use crate::storage_types::{DataKey, User};
+use crate::storage_types::{ConfigKey, DataKey, User};
 use crate::ttl;
 
 /// Check if a user exists in storage
@@ -68,6 +68,14 @@ pub fn initialize_user(env: &Env, user: Address) -> Result<(), SavingsError> {
         return Err(SavingsError::UserAlreadyExists);
     }
 
+    create_user_record(env, user);
+
+    Ok(())
+}
+
+/// Stores a fresh `User` record and its rewards entry. Callers are
+/// responsible for authorization and for checking the user does not exist.
+pub(crate) fn create_user_record(env: &Env, user: Address) {
     // Create new user with default values
     let new_user = User::new();
 
@@ -77,9 +85,92 @@ pub fn initialize_user(env: &Env, user: Address) -> Result<(), SavingsError> {
 
     // Extend TTL for new user
     ttl::extend_user_ttl(env, &user);
+    crate::stats::record_new_user(env);
 
     // Initialize user rewards
     let _ = crate::rewards::storage::initialize_user_rewards(env, user);
+}
+
+/// Ensures `user` has a record before a deposit, registering them inline
+/// when auto-init is enabled.
+///
+/// # Errors
+/// * `UserNotFound` - If the user does not exist and auto-init is off
+pub(crate) fn ensure_user(env: &Env, user: &Address) -> Result<(), SavingsError> {
+    if user_exists(env, user) {
+        return Ok(());
+    }
+    if !get_auto_init_users(env) {
+        return Err(SavingsError::UserNotFound);
+    }
 
+    create_user_record(env, user.clone());
+    Ok(())
+}
+
+/// Enables or disables registering unknown users on their first deposit.
+///
+/// # Errors
+/// * `Unauthorized` - If caller is not the admin
+pub fn set_auto_init_users(env: &Env, admin: Address, enabled: bool) -> Result<(), SavingsError> {
+    crate::config::require_admin(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::AutoInitUsers, &enabled);
+    env.events()
+        .publish((symbol_short!("auto_init"), admin), enabled);
+
+    Ok(())
+}
+
+/// Returns whether first deposits register unknown users (off by default).
+pub fn get_auto_init_users(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::AutoInitUsers)
+        .unwrap_or(false)
+}
+
+/// Returns the user's open plans: savings plans, Lock Saves, Goal Saves and
+/// group memberships that have not been withdrawn, broken or left.
+pub(crate) fn count_open_plans(env: &Env, user: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OpenPlanCount(user.clone()))
+        .unwrap_or(0)
+}
+
+/// Counts a newly opened plan towards the user's `MaxPlansPerUser` limit.
+///
+/// # Errors
+/// * `Overflow` - If the count would overflow
+pub(crate) fn record_plan_opened(env: &Env, user: &Address) -> Result<(), SavingsError> {
+    let open = count_open_plans(env, user)
+        .checked_add(1)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::OpenPlanCount(user.clone()), &open);
+    Ok(())
+}
+
+/// Releases a plan slot once a plan is withdrawn, broken, merged away or left.
+pub(crate) fn record_plan_closed(env: &Env, user: &Address) {
+    let open = count_open_plans(env, user).saturating_sub(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::OpenPlanCount(user.clone()), &open);
+}
+
+/// Ensures the user can open one more plan under `MaxPlansPerUser`.
+///
+/// # Errors
+/// * `MaxPlansExceeded` - If the user already holds the maximum number of open plans
+pub(crate) fn ensure_plan_capacity(env: &Env, user: &Address) -> Result<(), SavingsError> {
+    let max_plans = crate::config::get_max_plans_per_user(env);
+    if max_plans > 0 && count_open_plans(env, user) >= max_plans {
+        return Err(SavingsError::MaxPlansExceeded);
+    }
     Ok(())
 }
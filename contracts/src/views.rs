@@ -1,6 +1,7 @@
 use crate::errors::SavingsError;
 use crate::storage_types::{
-    DataKey, GoalSaveView, GroupSaveView, LockSaveView, PlanType, SavingsPlan, User,
+    DataKey, GoalSaveView, GroupSave, GroupSaveView, InitStatus, LockSaveView, PlanType,
+    SavingsPlan, User, UserPortfolio,
 };
 use crate::ttl;
 use soroban_sdk::{Address, Env, Vec};
@@ -9,7 +10,7 @@ use soroban_sdk::{Address, Env, Vec};
 // Helper Functions to Convert SavingsPlan to Specific Types
 // ===========================================================================
 
-fn to_lock_save(plan: &SavingsPlan) -> Option<LockSaveView> {
+fn to_lock_save(plan: &SavingsPlan, now: u64) -> Option<LockSaveView> {
     match plan.plan_type {
         PlanType::Lock(locked_until) => Some(LockSaveView {
             plan_id: plan.plan_id,
@@ -18,6 +19,12 @@ fn to_lock_save(plan: &SavingsPlan) -> Option<LockSaveView> {
             locked_until,
             interest_rate: plan.interest_rate,
             is_withdrawn: plan.is_withdrawn,
+            time_remaining: locked_until.saturating_sub(now),
+            projected_yield: crate::rates::calculate_plan_interest(
+                plan.balance,
+                plan.interest_rate,
+                locked_until.saturating_sub(plan.start_time),
+            ),
         }),
         _ => None,
     }
@@ -34,14 +41,34 @@ fn to_goal_save(plan: &SavingsPlan) -> Option<GoalSaveView> {
             is_completed: plan.is_completed,
             contribution_type: *contribution_type,
             goal_name: goal_name.clone(),
+            progress_bps: progress_bps(plan.balance, *target_amount),
+            remaining: target_amount.saturating_sub(plan.balance).max(0),
         }),
         _ => None,
     }
 }
 
-fn to_group_save(plan: &SavingsPlan) -> Option<GroupSaveView> {
+/// `current * 10_000 / target`, capped at 10_000 (100%).
+fn progress_bps(current: i128, target: i128) -> u32 {
+    if target <= 0 || current >= target {
+        return 10_000;
+    }
+    if current <= 0 {
+        return 0;
+    }
+    (current.saturating_mul(10_000) / target) as u32
+}
+
+fn to_group_save(env: &Env, plan: &SavingsPlan) -> Option<GroupSaveView> {
     match plan.plan_type {
         PlanType::Group(group_id, is_public, contribution_type, target_amount) => {
+            let group: Option<GroupSave> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::GroupSave(group_id));
+            let (pooled, member_count) = group
+                .map(|group| (group.current_amount, group.member_count))
+                .unwrap_or((plan.balance, 0));
             Some(GroupSaveView {
                 plan_id: plan.plan_id,
                 balance: plan.balance,
@@ -52,12 +79,30 @@ fn to_group_save(plan: &SavingsPlan) -> Option<GroupSaveView> {
                 is_public,
                 contribution_type,
                 group_id,
+                progress_bps: progress_bps(pooled, target_amount),
+                member_count,
             })
         }
         _ => None,
     }
 }
 
+fn group_to_view(env: &Env, group: &GroupSave) -> Result<GroupSaveView, SavingsError> {
+    Ok(GroupSaveView {
+        plan_id: group.id,
+        balance: group.current_amount,
+        target_amount: group.target_amount,
+        start_time: group.start_time,
+        interest_rate: crate::rates::resolve_group_rate(env)?,
+        is_completed: group.is_completed,
+        is_public: group.is_public,
+        contribution_type: group.contribution_type,
+        group_id: group.id,
+        progress_bps: progress_bps(group.current_amount, group.target_amount),
+        member_count: group.member_count,
+    })
+}
+
 // ===========================================================================
 // Lock Save Views
 // ===========================================================================
@@ -81,7 +126,7 @@ pub fn get_user_ongoing_lock_saves(
             // Extend TTL on read
             ttl::extend_plan_ttl(env, &key);
 
-            if let Some(lock_save) = to_lock_save(&plan) {
+            if let Some(lock_save) = to_lock_save(&plan, env.ledger().timestamp()) {
                 // Ongoing means not withdrawn (and potentially check if locked_until > now,
                 // but "ongoing" usually implies active/fresh. Let's assume active = not withdrawn)
                 if !lock_save.is_withdrawn {
@@ -113,7 +158,7 @@ pub fn get_user_matured_lock_saves(
             // Extend TTL on read
             ttl::extend_plan_ttl(env, &key);
 
-            if let Some(lock_save) = to_lock_save(&plan) {
+            if let Some(lock_save) = to_lock_save(&plan, env.ledger().timestamp()) {
                 // Matured means lock time has passed
                 if current_time >= lock_save.locked_until && !lock_save.is_withdrawn {
                     matured_plans.push_back(lock_save);
@@ -132,7 +177,29 @@ pub fn get_lock_save(env: &Env, user: Address, lock_id: u64) -> Result<LockSaveV
         .get::<DataKey, SavingsPlan>(&key)
         .ok_or(SavingsError::PlanNotFound)?;
 
-    to_lock_save(&plan).ok_or(SavingsError::PlanNotFound) // Or some mismatched type error? PlanNotFound seems safe enough
+    to_lock_save(&plan, env.ledger().timestamp()).ok_or(SavingsError::PlanNotFound)
+    // Or some mismatched type error? PlanNotFound seems safe enough
+}
+
+/// Returns a Lock Save with its time to maturity and projected yield.
+///
+/// # Errors
+/// * `PlanNotFound` - If the lock does not exist
+pub fn get_lock_view(env: &Env, lock_id: u64) -> Result<LockSaveView, SavingsError> {
+    let lock_save = crate::lock::get_lock_save(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+
+    Ok(LockSaveView {
+        plan_id: lock_save.id,
+        balance: lock_save.amount,
+        start_time: lock_save.start_time,
+        locked_until: lock_save.maturity_time,
+        interest_rate: lock_save.interest_rate,
+        is_withdrawn: lock_save.is_withdrawn,
+        time_remaining: lock_save
+            .maturity_time
+            .saturating_sub(env.ledger().timestamp()),
+        projected_yield: crate::lock::projected_lock_yield(&lock_save),
+    })
 }
 
 // ===========================================================================
@@ -211,6 +278,30 @@ pub fn get_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<GoalSaveV
     to_goal_save(&plan).ok_or(SavingsError::PlanNotFound)
 }
 
+/// Returns a Goal Save with its funding progress and remaining amount.
+///
+/// # Errors
+/// * `PlanNotFound` - If the goal does not exist
+pub fn get_goal_view(env: &Env, goal_id: u64) -> Result<GoalSaveView, SavingsError> {
+    let goal_save = crate::goal::get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+
+    Ok(GoalSaveView {
+        plan_id: goal_save.id,
+        balance: goal_save.current_amount,
+        target_amount: goal_save.target_amount,
+        start_time: goal_save.start_time,
+        interest_rate: goal_save.interest_rate,
+        is_completed: goal_save.is_completed,
+        contribution_type: 0,
+        goal_name: goal_save.goal_name,
+        progress_bps: progress_bps(goal_save.current_amount, goal_save.target_amount),
+        remaining: goal_save
+            .target_amount
+            .saturating_sub(goal_save.current_amount)
+            .max(0),
+    })
+}
+
 // ===========================================================================
 // Group Save Views
 // ===========================================================================
@@ -234,7 +325,7 @@ pub fn get_user_live_group_saves(
             // Extend TTL on read
             ttl::extend_plan_ttl(env, &key);
 
-            if let Some(group_save) = to_group_save(&plan) {
+            if let Some(group_save) = to_group_save(env, &plan) {
                 if !group_save.is_completed {
                     live_plans.push_back(group_save);
                 }
@@ -263,7 +354,7 @@ pub fn get_user_completed_group_saves(
             // Extend TTL on read
             ttl::extend_plan_ttl(env, &key);
 
-            if let Some(group_save) = to_group_save(&plan) {
+            if let Some(group_save) = to_group_save(env, &plan) {
                 if group_save.is_completed {
                     completed_plans.push_back(group_save);
                 }
@@ -288,7 +379,39 @@ pub fn get_group_save(
     // Extend TTL on read
     ttl::extend_plan_ttl(env, &key);
 
-    to_group_save(&plan).ok_or(SavingsError::PlanNotFound)
+    to_group_save(env, &plan).ok_or(SavingsError::PlanNotFound)
+}
+
+/// Returns up to `limit` public groups starting at `offset` in the public
+/// group index (creation order).
+pub fn list_public_groups(
+    env: &Env,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<GroupSaveView>, SavingsError> {
+    let index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PublicGroupIndex)
+        .unwrap_or(Vec::new(env));
+    let mut groups = Vec::new(env);
+    if offset >= index.len() || limit == 0 {
+        return Ok(groups);
+    }
+    let end = offset.saturating_add(limit).min(index.len());
+
+    for i in offset..end {
+        let group_id = index.get(i).ok_or(SavingsError::InternalError)?;
+        if let Some(group) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, GroupSave>(&DataKey::GroupSave(group_id))
+        {
+            ttl::extend_group_ttl(env, group_id);
+            groups.push_back(group_to_view(env, &group)?);
+        }
+    }
+    Ok(groups)
 }
 
 // ===========================================================================
@@ -347,3 +470,440 @@ pub fn get_group_member_contribution(
 
     Err(SavingsError::PlanNotFound)
 }
+
+// ===========================================================================
+// Savings Plan Pagination
+// ===========================================================================
+
+/// Returns the number of generic savings plans created by a user.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The user address
+///
+/// # Returns
+/// The user's `savings_count`, or 0 if the user has not been initialized
+pub fn get_user_plan_count(env: &Env, user: &Address) -> u64 {
+    crate::users::get_user(env, user)
+        .map(|user_data| user_data.savings_count as u64)
+        .unwrap_or(0)
+}
+
+/// Returns a window of a user's savings plans ordered by plan ID.
+///
+/// Plan IDs run from 1 to `savings_count`; the window starts at the
+/// `offset`-th plan and contains at most `limit` plans. Windows that start
+/// past the end yield an empty vector.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The user address
+/// * `offset` - Number of plans to skip
+/// * `limit` - Maximum number of plans to return
+///
+/// # Returns
+/// The plans in the requested window
+pub fn get_user_savings_plans_paged(
+    env: &Env,
+    user: Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<SavingsPlan> {
+    let mut plans = Vec::new(env);
+    let count = get_user_plan_count(env, &user);
+    let start = offset as u64;
+    if start >= count || limit == 0 {
+        return plans;
+    }
+    let end = start.saturating_add(limit as u64).min(count);
+
+    for plan_id in (start + 1)..=end {
+        let key = DataKey::SavingsPlan(user.clone(), plan_id);
+        if let Some(plan) = env.storage().persistent().get::<DataKey, SavingsPlan>(&key) {
+            // Extend TTL on read
+            ttl::extend_plan_ttl(env, &key);
+            plans.push_back(plan);
+        }
+    }
+    plans
+}
+
+// ===========================================================================
+// Portfolio Views
+// ===========================================================================
+
+/// Aggregates a user's holdings across Flexi, Lock, Goal and Group saves.
+///
+/// Only Lock and Goal saves that have not been withdrawn are counted. Group
+/// contributions are summed over every group ID in the user's group list.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The user address
+///
+/// # Returns
+/// `Ok(UserPortfolio)` with the per-product totals
+///
+/// # Errors
+/// * `UserNotFound` - If the user has not been initialized
+pub fn get_user_portfolio(env: &Env, user: Address) -> Result<UserPortfolio, SavingsError> {
+    let user_data: User = crate::users::get_user(env, &user)?;
+
+    let flexi_balance: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FlexiBalance(user.clone()))
+        .unwrap_or(0);
+
+    let mut lock_total: i128 = 0;
+    for lock_id in crate::lock::get_user_lock_saves(env, &user).iter() {
+        if let Some(lock_save) = crate::lock::get_lock_save(env, lock_id) {
+            if !lock_save.is_withdrawn {
+                lock_total = lock_total
+                    .checked_add(lock_save.amount)
+                    .ok_or(SavingsError::Overflow)?;
+            }
+        }
+    }
+
+    let mut goal_total: i128 = 0;
+    for goal_id in crate::goal::get_user_goal_saves(env, &user).iter() {
+        if let Some(goal_save) = crate::goal::get_goal_save(env, goal_id) {
+            if !goal_save.is_withdrawn {
+                goal_total = goal_total
+                    .checked_add(goal_save.current_amount)
+                    .ok_or(SavingsError::Overflow)?;
+            }
+        }
+    }
+
+    let mut group_total: i128 = 0;
+    for group_id in crate::group::get_user_groups(env, &user).iter() {
+        group_total = group_total
+            .checked_add(crate::group::get_member_contribution(env, group_id, &user))
+            .ok_or(SavingsError::Overflow)?;
+    }
+
+    let reward_points = crate::rewards::storage::get_user_rewards(env, user).total_points;
+
+    Ok(UserPortfolio {
+        flexi_balance,
+        lock_total,
+        goal_total,
+        group_total,
+        reward_points,
+        total_balance: user_data.total_balance,
+    })
+}
+
+/// Reports which setup steps have been completed, in a single call.
+///
+/// Integrators can use this instead of probing each config getter.
+pub fn get_init_status(env: &Env) -> InitStatus {
+    InitStatus {
+        initialized: env.storage().instance().has(&DataKey::Initialized),
+        config_initialized: env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigInitialized)
+            .unwrap_or(false),
+        rewards_config_initialized: env
+            .storage()
+            .instance()
+            .has(&crate::rewards::storage_types::RewardsDataKey::Config),
+        voting_config_initialized: crate::governance::get_voting_config(env).is_ok(),
+        governance_active: crate::governance::is_governance_active(env),
+        paused: crate::config::is_paused(env),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewards::storage_types::RewardsConfig;
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env, String, Symbol,
+    };
+
+    fn setup() -> (Env, NesteraContractClient<'static>) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        let config = RewardsConfig {
+            points_per_token: 10,
+            streak_bonus_bps: 0,
+            long_lock_bonus_bps: 0,
+            goal_completion_bonus: 0,
+            enabled: true,
+            min_deposit_for_rewards: 0,
+            action_cooldown_seconds: 0,
+            max_daily_points: 1_000_000,
+            max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
+        };
+        client.initialize_rewards_config(&config);
+
+        (env, client)
+    }
+
+    #[test]
+    fn test_portfolio_aggregates_every_product() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        client.deposit_flexi(&user, &1_000);
+        client.create_lock_save(&user, &2_000, &86_400, &false);
+        client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &3_000);
+        let group_id = client.create_group_save(
+            &user,
+            &String::from_str(&env, "Trip"),
+            &String::from_str(&env, "Group trip"),
+            &String::from_str(&env, "travel"),
+            &10_000,
+            &0,
+            &100,
+            &true,
+            &1,
+            &1_000,
+        );
+        client.contribute_to_group_save(&user, &group_id, &400);
+
+        let portfolio = client.get_user_portfolio(&user);
+        assert_eq!(portfolio.flexi_balance, 1_000);
+        assert_eq!(portfolio.lock_total, 2_000);
+        assert_eq!(portfolio.goal_total, 3_000);
+        assert_eq!(portfolio.group_total, 400);
+        assert!(portfolio.reward_points > 0);
+        assert_eq!(
+            portfolio.reward_points,
+            client.get_user_rewards(&user).total_points
+        );
+        assert_eq!(
+            portfolio.total_balance,
+            client.get_user(&user).total_balance
+        );
+    }
+
+    #[test]
+    fn test_portfolio_unknown_user_fails() {
+        let (env, client) = setup();
+        let stranger = Address::generate(&env);
+
+        assert_eq!(
+            client.try_get_user_portfolio(&stranger),
+            Err(Ok(SavingsError::UserNotFound))
+        );
+    }
+
+    #[test]
+    fn test_savings_plan_pages_stitch_back_together() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        for deposit in 1..=5i128 {
+            client.create_savings_plan(&user, &PlanType::Flexi, &(deposit * 100));
+        }
+        assert_eq!(client.get_user_plan_count(&user), 5);
+
+        let first = client.get_user_savings_plans_paged(&user, &0, &2);
+        let second = client.get_user_savings_plans_paged(&user, &2, &2);
+        let last = client.get_user_savings_plans_paged(&user, &4, &2);
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 2);
+        assert_eq!(last.len(), 1);
+
+        let mut plan_id = 1u64;
+        for page in [first, second, last] {
+            for plan in page.iter() {
+                assert_eq!(plan.plan_id, plan_id);
+                assert_eq!(plan.balance, plan_id as i128 * 100);
+                plan_id += 1;
+            }
+        }
+        assert_eq!(plan_id, 6);
+    }
+
+    #[test]
+    fn test_savings_plan_page_past_end_is_empty() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        client.create_savings_plan(&user, &PlanType::Flexi, &100);
+
+        assert_eq!(client.get_user_savings_plans_paged(&user, &1, &2).len(), 0);
+        assert_eq!(client.get_user_savings_plans_paged(&user, &0, &0).len(), 0);
+        assert_eq!(
+            client
+                .get_user_savings_plans_paged(&user, &0, &u32::MAX)
+                .len(),
+            1
+        );
+
+        let stranger = Address::generate(&env);
+        assert_eq!(client.get_user_plan_count(&stranger), 0);
+        assert_eq!(
+            client
+                .get_user_savings_plans_paged(&stranger, &0, &10)
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_goal_view_reports_progress_and_remaining() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &5_000);
+        let view = client.get_goal_view(&goal_id);
+        assert_eq!(view.progress_bps, 5_000);
+        assert_eq!(view.remaining, 5_000);
+        assert_eq!(view.balance, 5_000);
+
+        client.deposit_to_goal_save(&user, &goal_id, &7_000);
+        let view = client.get_goal_view(&goal_id);
+        assert_eq!(view.progress_bps, 10_000);
+        assert_eq!(view.remaining, 0);
+        assert!(view.is_completed);
+
+        assert_eq!(
+            client.try_get_goal_view(&999),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
+
+    #[test]
+    fn test_lock_view_reports_time_remaining_and_projected_yield() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        // One 365.25-day year at 5%; three years compounded yearly
+        let year = 31_557_600u64;
+        let simple_id = client.create_lock_save(&user, &10_000, &year, &false);
+        let compound_id = client.create_lock_save(&user, &10_000, &(3 * year), &true);
+
+        let view = client.get_lock_view(&simple_id);
+        assert_eq!(view.time_remaining, year);
+        assert_eq!(view.projected_yield, 500);
+        assert_eq!(client.get_lock_view(&compound_id).projected_yield, 1_576);
+
+        env.ledger().with_mut(|li| li.timestamp += year / 2);
+        assert_eq!(client.get_lock_view(&simple_id).time_remaining, year / 2);
+
+        env.ledger().with_mut(|li| li.timestamp += year);
+        let view = client.get_lock_view(&simple_id);
+        assert_eq!(view.time_remaining, 0);
+    }
+
+    fn create_group(
+        client: &NesteraContractClient<'_>,
+        env: &Env,
+        creator: &Address,
+        public: bool,
+    ) -> u64 {
+        client.create_group_save(
+            creator,
+            &String::from_str(env, "Club"),
+            &String::from_str(env, "Savings club"),
+            &String::from_str(env, "savings"),
+            &1_000,
+            &0,
+            &50,
+            &public,
+            &1,
+            &1_000_000,
+        )
+    }
+
+    #[test]
+    fn test_public_group_pages_stitch_back_together() {
+        let (env, client) = setup();
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+
+        let mut public_ids = soroban_sdk::Vec::new(&env);
+        for i in 0..5 {
+            let public = i % 2 == 0;
+            let group_id = create_group(&client, &env, &creator, public);
+            if public {
+                public_ids.push_back(group_id);
+            }
+        }
+        client.contribute_to_group_save(&creator, &public_ids.get(0).unwrap(), &250);
+
+        let mut stitched = soroban_sdk::Vec::new(&env);
+        for offset in [0u32, 2] {
+            for view in client.list_public_groups(&offset, &2).iter() {
+                assert!(view.is_public);
+                stitched.push_back(view.group_id);
+            }
+        }
+        assert_eq!(stitched, public_ids);
+
+        let first = client.list_public_groups(&0, &1).get(0).unwrap();
+        assert_eq!(first.progress_bps, 2_500);
+        assert_eq!(first.member_count, 1);
+        assert!(client.list_public_groups(&3, &2).is_empty());
+    }
+
+    #[test]
+    fn test_init_status_tracks_each_setup_step() {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        let status = client.get_init_status();
+        assert!(!status.initialized);
+        assert!(!status.config_initialized);
+        assert!(!status.rewards_config_initialized);
+        assert!(!status.voting_config_initialized);
+        assert!(!status.governance_active);
+        assert!(!status.paused);
+
+        client.initialize(&admin, &BytesN::from_array(&env, &[1u8; 32]));
+        assert!(client.get_init_status().initialized);
+
+        client.initialize_config(&admin, &Address::generate(&env), &100u32);
+        assert!(client.get_init_status().config_initialized);
+
+        let config = RewardsConfig {
+            points_per_token: 10,
+            streak_bonus_bps: 0,
+            long_lock_bonus_bps: 0,
+            goal_completion_bonus: 0,
+            enabled: true,
+            min_deposit_for_rewards: 0,
+            action_cooldown_seconds: 0,
+            max_daily_points: 1_000_000,
+            max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
+        };
+        client.initialize_rewards_config(&config);
+        assert!(client.get_init_status().rewards_config_initialized);
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        assert!(client.get_init_status().voting_config_initialized);
+
+        client.activate_governance(&admin);
+        assert!(client.get_init_status().governance_active);
+
+        client.pause(&admin);
+        let status = client.get_init_status();
+        assert!(status.paused);
+        assert!(status.initialized && status.config_initialized && status.governance_active);
+    }
+}
@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod voting_tests {
 
+    use crate::governance::ProposalAction;
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
         Address, BytesN, Env, String,
@@ -28,6 +29,9 @@ mod voting_tests {
             action_cooldown_seconds: 0,
             max_daily_points: 1_000_000,
             max_streak_multiplier: 10_000,
+            streak_window_secs: 0,
+            streak_threshold: 0,
+            maturity_bonus_bps: 0,
         };
         client.initialize_rewards_config(&config);
 
@@ -41,9 +45,16 @@ mod voting_tests {
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
         let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
         let description = String::from_str(&env, "Test proposal");
         let proposal_id = client
-            .try_create_proposal(&creator, &description)
+            .try_create_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &description,
+            )
             .unwrap()
             .unwrap();
 
@@ -150,6 +161,95 @@ mod voting_tests {
         assert_eq!(proposal.against_votes, 0);
     }
 
+    #[test]
+    fn test_vote_weight_fixed_after_mid_vote_deposit() {
+        let (env, client, _admin, _creator, proposal_id) = setup_with_proposal();
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize_user(&voter);
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
+
+        client.vote(&proposal_id, &1, &voter);
+        assert_eq!(client.get_vote_weight(&proposal_id, &voter), 1000);
+
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &5000);
+        assert_eq!(client.get_voting_power(&voter), 6000);
+
+        assert!(client.try_vote(&proposal_id, &1, &voter).is_err());
+        assert_eq!(client.get_vote_weight(&proposal_id, &voter), 1000);
+        assert_eq!(client.get_proposal(&proposal_id).unwrap().for_votes, 1000);
+    }
+
+    #[test]
+    fn test_vote_weight_uses_power_at_proposal_creation() {
+        let (env, client, _admin, _creator, proposal_id) = setup_with_proposal();
+        env.mock_all_auths();
+
+        let holder = Address::generate(&env);
+        client.initialize_user(&holder);
+        let _ = client.create_savings_plan(&holder, &PlanType::Flexi, &1000);
+        let late = Address::generate(&env);
+        client.initialize_user(&late);
+
+        // Deposits made after the proposal was created carry no weight on it
+        env.ledger().with_mut(|li| li.timestamp += 60);
+        let _ = client.create_savings_plan(&holder, &PlanType::Flexi, &5000);
+        let _ = client.create_savings_plan(&late, &PlanType::Flexi, &5000);
+        assert_eq!(client.get_voting_power(&holder), 6000);
+
+        client.vote(&proposal_id, &1, &holder);
+        assert_eq!(client.get_vote_weight(&proposal_id, &holder), 1000);
+        assert_eq!(
+            client.try_vote(&proposal_id, &1, &late),
+            Err(Ok(SavingsError::InsufficientBalance))
+        );
+        assert_eq!(client.get_proposal(&proposal_id).unwrap().for_votes, 1000);
+    }
+
+    #[test]
+    fn test_batch_vote_skips_already_voted_proposal() {
+        let (env, client, _admin, creator, first) = setup_with_proposal();
+        let voter = Address::generate(&env);
+        env.mock_all_auths();
+
+        let mut ids = soroban_sdk::Vec::new(&env);
+        ids.push_back(first);
+        for _ in 0..2 {
+            ids.push_back(client.create_proposal(
+                &creator,
+                &String::from_str(&env, "Title"),
+                &String::from_str(&env, "general"),
+                &String::from_str(&env, "Another proposal"),
+            ));
+        }
+
+        client.initialize_user(&voter);
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
+        client.vote(&ids.get(1).unwrap(), &2, &voter);
+
+        let votes = soroban_sdk::vec![
+            &env,
+            (ids.get(0).unwrap(), 1u32),
+            (ids.get(1).unwrap(), 1u32),
+            (ids.get(2).unwrap(), 1u32),
+        ];
+        let results = client.batch_vote(&votes, &voter);
+        assert_eq!(results, soroban_sdk::vec![&env, true, false, true]);
+
+        assert_eq!(
+            client.get_proposal(&ids.get(0).unwrap()).unwrap().for_votes,
+            1000
+        );
+        let skipped = client.get_proposal(&ids.get(1).unwrap()).unwrap();
+        assert_eq!(skipped.for_votes, 0);
+        assert_eq!(skipped.against_votes, 1000);
+        assert_eq!(
+            client.get_proposal(&ids.get(2).unwrap()).unwrap().for_votes,
+            1000
+        );
+    }
+
     #[test]
     fn test_has_voted() {
         let (env, client, _admin, _creator, proposal_id) = setup_with_proposal();
@@ -244,4 +344,125 @@ mod voting_tests {
         let proposal = client.get_proposal(&proposal_id).unwrap();
         assert_eq!(proposal.for_votes, 8000);
     }
+
+    fn funded_voter(env: &Env, client: &NesteraContractClient<'static>, deposit: i128) -> Address {
+        let voter = Address::generate(env);
+        client.initialize_user(&voter);
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &deposit);
+        voter
+    }
+
+    #[test]
+    fn test_proposal_status_through_execution() {
+        let (env, client, _admin, _creator, proposal_id) = setup_with_proposal();
+        env.mock_all_auths();
+        assert_eq!(client.get_proposal_status(&proposal_id), 0); // active
+
+        let against = funded_voter(&env, &client, 2000);
+        client.vote(&proposal_id, &2, &against);
+        assert_eq!(client.get_proposal_status(&proposal_id), 2); // failing
+
+        let supporter = funded_voter(&env, &client, 3000);
+        client.vote(&proposal_id, &1, &supporter);
+        assert_eq!(client.get_proposal_status(&proposal_id), 1); // passing
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        assert_eq!(client.get_proposal_status(&proposal_id), 1); // passed, awaiting queue
+
+        client.queue_proposal(&proposal_id);
+        assert_eq!(client.get_proposal_status(&proposal_id), 3); // queued
+
+        env.ledger().with_mut(|li| li.timestamp += 86400 + 1);
+        client.execute_proposal(&proposal_id);
+        assert_eq!(client.get_proposal_status(&proposal_id), 4); // executed
+    }
+
+    #[test]
+    fn test_proposal_status_defeated_after_voting_ends() {
+        let (env, client, _admin, _creator, proposal_id) = setup_with_proposal();
+        env.mock_all_auths();
+
+        let supporter = funded_voter(&env, &client, 1000);
+        let against = funded_voter(&env, &client, 1000);
+        client.vote(&proposal_id, &1, &supporter);
+        client.vote(&proposal_id, &2, &against);
+        assert_eq!(client.get_proposal_status(&proposal_id), 2); // tied = failing
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        assert_eq!(client.get_proposal_status(&proposal_id), 5); // defeated
+    }
+
+    #[test]
+    fn test_action_proposal_status() {
+        let (env, client, _admin, creator, _proposal_id) = setup_with_proposal();
+        env.mock_all_auths();
+
+        let action_id = client.create_action_proposal(
+            &creator,
+            &String::from_str(&env, "Rate"),
+            &String::from_str(&env, "rates"),
+            &String::from_str(&env, "Set flexi rate"),
+            &ProposalAction::SetFlexiRate(600),
+        );
+        assert_eq!(client.get_proposal_status(&action_id), 0);
+
+        let supporter = funded_voter(&env, &client, 1000);
+        client.vote(&action_id, &1, &supporter);
+        assert_eq!(client.get_proposal_status(&action_id), 1);
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        client.queue_proposal(&action_id);
+        assert_eq!(client.get_proposal_status(&action_id), 3);
+
+        env.ledger().with_mut(|li| li.timestamp += 86400 + 1);
+        client.execute_proposal(&action_id);
+        assert_eq!(client.get_proposal_status(&action_id), 4);
+    }
+
+    fn new_proposal(env: &Env, client: &NesteraContractClient<'static>, creator: &Address) -> u64 {
+        client.create_proposal(
+            creator,
+            &String::from_str(env, "Title"),
+            &String::from_str(env, "general"),
+            &String::from_str(env, "Another proposal"),
+        )
+    }
+
+    #[test]
+    fn test_list_proposals_by_status_filters_and_pages() {
+        let (env, client, _admin, creator, queued) = setup_with_proposal();
+        env.mock_all_auths();
+        let passed = new_proposal(&env, &client, &creator);
+        let defeated_a = new_proposal(&env, &client, &creator);
+        let defeated_b = new_proposal(&env, &client, &creator);
+
+        let supporter = funded_voter(&env, &client, 1000);
+        client.vote(&queued, &1, &supporter);
+        client.vote(&passed, &1, &supporter);
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        client.queue_proposal(&queued);
+        let active = new_proposal(&env, &client, &creator);
+
+        let ids = |status: u32| client.list_proposals_by_status(&status, &0, &10);
+        assert_eq!(ids(0), soroban_sdk::vec![&env, active]);
+        assert_eq!(ids(1), soroban_sdk::vec![&env, passed]);
+        assert_eq!(ids(3), soroban_sdk::vec![&env, queued]);
+        assert_eq!(ids(4).len(), 0);
+        assert_eq!(ids(5), soroban_sdk::vec![&env, defeated_a, defeated_b]);
+
+        assert_eq!(
+            client.list_proposals_by_status(&5, &1, &1),
+            soroban_sdk::vec![&env, defeated_b]
+        );
+    }
+
+    #[test]
+    fn test_proposal_status_unknown_proposal() {
+        let (_env, client, _admin, _creator, _proposal_id) = setup_with_proposal();
+        assert_eq!(
+            client.try_get_proposal_status(&999),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
 }
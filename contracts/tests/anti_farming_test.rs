@@ -1,5 +1,8 @@
 #![cfg(test)]
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env,
+};
 use Nestera::{NesteraContract, NesteraContractClient};
 
 fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Address) {
@@ -153,3 +156,108 @@ fn test_overflow_protection_basic() {
         "Should track lifetime deposits"
     );
 }
+
+/// Configures rewards with explicit anti-farming parameters.
+fn setup_custom_rewards_config(
+    client: &NesteraContractClient,
+    admin: &Address,
+    streak_bonus_bps: u32,
+    min_deposit_for_rewards: i128,
+    action_cooldown_seconds: u64,
+    max_daily_points: u128,
+    max_streak_multiplier: u32,
+) {
+    client.init_rewards_config(
+        admin,
+        &10, // points_per_token
+        &streak_bonus_bps,
+        &0, // long_lock_bonus_bps
+        &0, // goal_completion_bonus
+        &true,
+        &min_deposit_for_rewards,
+        &action_cooldown_seconds,
+        &max_daily_points,
+        &max_streak_multiplier,
+    );
+}
+
+#[test]
+fn test_first_deposit_rewarded_right_after_registration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+    setup_rewards_config(&env, &client, &admin);
+
+    // Registering the user stamps last_action_timestamp; the first deposit
+    // in the same ledger must still be rewarded despite the 60s cooldown.
+    let user = Address::generate(&env);
+    client.init_user(&user);
+    client.deposit_flexi(&user, &100);
+
+    assert_eq!(client.get_user_rewards(&user).total_points, 1_000);
+}
+
+#[test]
+fn test_cooldown_blocks_second_deposit() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+
+    client.deposit_flexi(&user, &100);
+    let after_first = client.get_user_rewards(&user).total_points;
+    assert_eq!(after_first, 1_000);
+
+    // Within the 60s cooldown: deposit succeeds but earns nothing
+    env.ledger().with_mut(|li| li.timestamp += 30);
+    client.deposit_flexi(&user, &100);
+    assert_eq!(client.get_user_rewards(&user).total_points, after_first);
+
+    // After the cooldown: rewards resume
+    env.ledger().with_mut(|li| li.timestamp += 31);
+    client.deposit_flexi(&user, &100);
+    assert_eq!(client.get_user_rewards(&user).total_points, 2_000);
+}
+
+#[test]
+fn test_daily_cap_saturates() {
+    let (env, client, admin, user) = create_test_env();
+    setup_custom_rewards_config(&client, &admin, 0, 0, 0, 1_500, 10_000);
+
+    client.deposit_flexi(&user, &100); // 1,000 points
+    client.deposit_flexi(&user, &100); // capped at remaining 500
+    client.deposit_flexi(&user, &100); // cap reached, nothing awarded
+
+    let rewards = client.get_user_rewards(&user);
+    assert_eq!(rewards.total_points, 1_500);
+    assert_eq!(rewards.daily_points_earned, 1_500);
+
+    // Next day the bucket resets
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.deposit_flexi(&user, &100);
+
+    let rewards = client.get_user_rewards(&user);
+    assert_eq!(rewards.total_points, 2_500);
+    assert_eq!(rewards.daily_points_earned, 1_000);
+}
+
+#[test]
+fn test_streak_bonus_clamped_by_max_streak_multiplier() {
+    let (env, client, admin, user) = create_test_env();
+    // 50% streak bonus configured, but clamped to 10%
+    setup_custom_rewards_config(&client, &admin, 5_000, 0, 0, 1_000_000, 1_000);
+
+    client.deposit_flexi(&user, &100);
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.deposit_flexi(&user, &100);
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.deposit_flexi(&user, &100);
+
+    let rewards = client.get_user_rewards(&user);
+    assert_eq!(rewards.current_streak, 3);
+    // base 3 * 1,000 + third deposit bonus 1,000 * 10% = 100
+    assert_eq!(rewards.total_points, 3_100);
+}
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod autosave_tests {
-    use soroban_sdk::{testutils::Address as _, Address, Env};
-    use Nestera::{NesteraContract, NesteraContractClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env, Symbol,
+    };
+    use Nestera::{AutoSaveTarget, NesteraContract, NesteraContractClient, SavingsError};
 
     fn setup_test_contract() -> (Env, NesteraContractClient<'static>, Address) {
         let env = Env::default();
@@ -102,11 +105,31 @@ mod autosave_tests {
 
         let schedule_id = client.create_autosave(&user, &amount, &interval, &start_time);
 
-        // Try to execute before due time
-        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            client.execute_autosave(&schedule_id);
-        }));
-        assert!(result.is_err()); // Should panic with InvalidTimestamp
+        // Executing before the due time is a no-op
+        assert!(!client.execute_autosave(&schedule_id));
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_execute_autosave_retry_within_interval_is_noop() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp();
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &start_time);
+
+        assert!(client.execute_autosave(&schedule_id));
+        assert!(!client.execute_autosave(&schedule_id));
+
+        env.ledger()
+            .with_mut(|li| li.timestamp = start_time + 86400);
+        assert!(client.execute_autosave(&schedule_id));
+        assert!(!client.execute_autosave(&schedule_id));
+
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+        assert_eq!(
+            client.get_autosave(&schedule_id).unwrap().executions_done,
+            2
+        );
     }
 
     #[test]
@@ -350,4 +373,215 @@ mod autosave_tests {
         assert_eq!(client.get_flexi_balance(&user1), 500);
         assert_eq!(client.get_flexi_balance(&user2), 800);
     }
+
+    // ========== Batch Size Limit Tests ==========
+
+    fn setup_with_admin() -> (Env, NesteraContractClient<'static>, Address, Address) {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+        client.initialize(&admin, &admin_pk);
+        (env, client, admin, user)
+    }
+
+    #[test]
+    fn test_batch_size_defaults_when_unset() {
+        let (_env, client, _user) = setup_test_contract();
+        assert_eq!(client.get_autosave_max_batch_size(), 50);
+    }
+
+    #[test]
+    fn test_batch_over_default_limit_rejected() {
+        let (env, client, _user) = setup_test_contract();
+
+        let limit = client.get_autosave_max_batch_size() as u64;
+        let mut schedule_ids = soroban_sdk::Vec::new(&env);
+        for id in 0..(limit + 1) {
+            schedule_ids.push_back(id);
+        }
+
+        let result = client.try_execute_due_autosaves(&schedule_ids);
+        assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_batch_over_configured_limit_rejected_and_nothing_executed() {
+        let (env, client, admin, user) = setup_with_admin();
+        client.set_autosave_max_batch_size(&admin, &2);
+        assert_eq!(client.get_autosave_max_batch_size(), 2);
+
+        let start_time = env.ledger().timestamp();
+        let id1 = client.create_autosave(&user, &100, &86400, &start_time);
+        let id2 = client.create_autosave(&user, &200, &86400, &start_time);
+        let id3 = client.create_autosave(&user, &300, &86400, &start_time);
+
+        let schedule_ids = soroban_sdk::vec![&env, id1, id2, id3];
+        let result = client.try_execute_due_autosaves(&schedule_ids);
+        assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+
+        // The whole batch is rejected, so no deposits happened
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_batch_at_configured_limit_executes() {
+        let (env, client, admin, user) = setup_with_admin();
+        client.set_autosave_max_batch_size(&admin, &2);
+
+        let start_time = env.ledger().timestamp();
+        let id1 = client.create_autosave(&user, &100, &86400, &start_time);
+        let id2 = client.create_autosave(&user, &200, &86400, &start_time);
+
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, id1, id2]);
+        assert_eq!(results.len(), 2);
+        assert_eq!(client.get_flexi_balance(&user), 300);
+    }
+
+    #[test]
+    fn test_set_batch_size_zero_rejected() {
+        let (_env, client, admin, _user) = setup_with_admin();
+        let result = client.try_set_autosave_max_batch_size(&admin, &0);
+        assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_set_batch_size_non_admin_rejected() {
+        let (_env, client, _admin, user) = setup_with_admin();
+        let result = client.try_set_autosave_max_batch_size(&user, &10);
+        assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_preview_autosave_execution_no_fee() {
+        let (env, client, user) = setup_test_contract();
+
+        let schedule_id = client.create_autosave(&user, &1_000, &86400, &env.ledger().timestamp());
+
+        let (gross, net) = client.preview_autosave_execution(&schedule_id);
+        assert_eq!(gross, 1_000);
+        assert_eq!(net, 1_000);
+    }
+
+    #[test]
+    fn test_preview_autosave_execution_with_protocol_fee() {
+        let (env, client, _admin, user) = setup_with_admin();
+        client.set_fee_recipient(&Address::generate(&env));
+        client.set_protocol_fee_bps(&250); // 2.5%
+
+        let schedule_id = client.create_autosave(&user, &1_000, &86400, &env.ledger().timestamp());
+
+        let (gross, net) = client.preview_autosave_execution(&schedule_id);
+        assert_eq!(gross, 1_000);
+        assert_eq!(net, 975);
+
+        // The preview matches what actually lands on execution
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_flexi_balance(&user), net);
+    }
+
+    #[test]
+    fn test_preview_autosave_execution_not_found() {
+        let (_env, client, _user) = setup_test_contract();
+
+        let result = client.try_preview_autosave_execution(&99);
+        assert_eq!(result, Err(Ok(SavingsError::PlanNotFound)));
+    }
+
+    #[test]
+    fn test_goal_targeted_autosave_contributes_each_interval() {
+        let (env, client, user) = setup_test_contract();
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "house"), &10_000, &500);
+        let start = env.ledger().timestamp();
+        let schedule_id = client.create_targeted_autosave(
+            &user,
+            &300,
+            &86400,
+            &start,
+            &AutoSaveTarget::Goal(goal_id),
+        );
+        assert_eq!(
+            client.get_autosave(&schedule_id).unwrap().target,
+            AutoSaveTarget::Goal(goal_id)
+        );
+
+        client.execute_autosave(&schedule_id);
+        env.ledger().with_mut(|li| li.timestamp = start + 86400);
+        client.execute_autosave(&schedule_id);
+
+        assert_eq!(client.get_goal_save_detail(&goal_id).current_amount, 1_100);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_targeted_autosave_rejects_missing_or_foreign_goal() {
+        let (env, client, user) = setup_test_contract();
+        let other = Address::generate(&env);
+        client.initialize_user(&other);
+        let foreign_goal = client.create_goal_save(&other, &Symbol::new(&env, "car"), &5_000, &0);
+        let start = env.ledger().timestamp();
+
+        let missing = client.try_create_targeted_autosave(
+            &user,
+            &100,
+            &86400,
+            &start,
+            &AutoSaveTarget::Goal(99),
+        );
+        assert_eq!(missing, Err(Ok(SavingsError::PlanNotFound)));
+
+        let foreign = client.try_create_targeted_autosave(
+            &user,
+            &100,
+            &86400,
+            &start,
+            &AutoSaveTarget::Goal(foreign_goal),
+        );
+        assert_eq!(foreign, Err(Ok(SavingsError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_lock_topup_autosave_grows_principal() {
+        let (env, client, user) = setup_test_contract();
+
+        let lock_id = client.create_lock_save(&user, &1_000, &(30 * 86400), &false);
+        let start = env.ledger().timestamp();
+        let schedule_id = client.create_targeted_autosave(
+            &user,
+            &250,
+            &86400,
+            &start,
+            &AutoSaveTarget::LockTopUp(lock_id),
+        );
+
+        let (gross, net) = client.preview_autosave_execution(&schedule_id);
+        assert_eq!((gross, net), (250, 250));
+
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_user_portfolio(&user).lock_total, 1_250);
+    }
+
+    #[test]
+    fn test_lock_topup_only_accrues_from_when_it_is_added() {
+        let (env, client, user) = setup_test_contract();
+
+        let lock_id = client.create_lock_save(&user, &1_000, &(30 * 86400), &false);
+        let start = env.ledger().timestamp();
+        env.ledger()
+            .with_mut(|li| li.timestamp = start + 10 * 86400);
+        let schedule_id = client.create_targeted_autosave(
+            &user,
+            &3_000,
+            &86400,
+            &env.ledger().timestamp(),
+            &AutoSaveTarget::LockTopUp(lock_id),
+        );
+        client.execute_autosave(&schedule_id);
+
+        // 1_000 from day 0 and 3_000 from day 10 average to a day 7.5 start
+        let view = client.get_lock_view(&lock_id);
+        assert_eq!(view.balance, 4_000);
+        assert_eq!(view.start_time, start + 10 * 86400 * 3 / 4);
+        assert_eq!(view.locked_until, start + 30 * 86400);
+    }
 }
@@ -177,7 +177,7 @@ fn test_goal_plan_full_flow() {
     assert!(goal.is_completed);
 
     // Withdraw completed goal
-    let withdrawn = client.withdraw_completed_goal_save(&user1, &goal_id);
+    let withdrawn = client.withdraw_completed_goal_save(&user1, &goal_id).net;
     assert_eq!(withdrawn, 5000);
 
     let goal = client.get_goal_save_detail(&goal_id);
@@ -205,7 +205,7 @@ fn test_goal_early_withdrawal_with_penalty() {
     let goal_id = client.create_goal_save(&user1, &goal_name, &target_amount, &initial_deposit);
 
     // Break goal before completion
-    let returned = client.break_goal_save(&user1, &goal_id);
+    let returned = client.break_goal_save(&user1, &goal_id).net;
 
     // Should receive amount minus penalty (5% of 3000 = 150)
     assert!(returned > 0);
@@ -230,7 +230,7 @@ fn test_lock_save_full_flow() {
     let duration_days = 30u64;
 
     // Create Lock Save plan
-    let lock_id = client.create_lock_save(&user1, &lock_amount, &duration_days);
+    let lock_id = client.create_lock_save(&user1, &lock_amount, &duration_days, &false);
 
     // Verify lock not matured
     assert!(!client.check_matured_lock(&lock_id));
@@ -258,7 +258,7 @@ fn test_lock_save_early_withdrawal_fails() {
     client.initialize_user(&user1);
     client.deposit_flexi(&user1, &5000);
 
-    let lock_id = client.create_lock_save(&user1, &3000, &60);
+    let lock_id = client.create_lock_save(&user1, &3000, &60, &false);
 
     // Try to withdraw before maturity - should panic
     client.withdraw_lock_save(&user1, &lock_id);
@@ -272,9 +272,9 @@ fn test_multiple_lock_saves() {
     client.deposit_flexi(&user1, &20000);
 
     // Create multiple locks
-    client.create_lock_save(&user1, &5000, &30);
-    client.create_lock_save(&user1, &3000, &60);
-    client.create_lock_save(&user1, &2000, &90);
+    client.create_lock_save(&user1, &5000, &30, &false);
+    client.create_lock_save(&user1, &3000, &60, &false);
+    client.create_lock_save(&user1, &2000, &90, &false);
 
     // Verify all locks exist
     let user_locks = client.get_user_lock_saves(&user1);
@@ -397,8 +397,7 @@ fn test_autosave_full_flow() {
 }
 
 #[test]
-#[should_panic]
-fn test_autosave_early_execution_fails() {
+fn test_autosave_early_execution_is_noop() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
     client.initialize_user(&user1);
@@ -407,8 +406,9 @@ fn test_autosave_early_execution_fails() {
     let start_time = env.ledger().timestamp() + 1000;
     let schedule_id = client.create_autosave(&user1, &500, &3600, &start_time);
 
-    // Try to execute before due time - should panic
-    client.execute_autosave(&schedule_id);
+    // Executing before the due time deposits nothing
+    assert!(!client.execute_autosave(&schedule_id));
+    assert_eq!(client.get_flexi_balance(&user1), 10000);
 }
 
 #[test]
@@ -628,7 +628,7 @@ fn test_multi_user_multi_plan_scenario() {
     client.deposit_flexi(&user3, &10000);
 
     // User1 creates multiple plans
-    client.create_lock_save(&user1, &5000, &30);
+    client.create_lock_save(&user1, &5000, &30, &false);
     client.create_goal_save(&user1, &Symbol::new(&env, "vacation"), &8000, &3000);
 
     // User2 creates autosave
@@ -688,7 +688,7 @@ fn test_complete_user_journey() {
     );
 
     // Step 3: Create a lock (this doesn't deduct from flexi balance either)
-    let _lock_id = client.create_lock_save(&user1, &10000, &90);
+    let _lock_id = client.create_lock_save(&user1, &10000, &90, &false);
     // Lock creation also manages funds internally
     let balance = client.get_flexi_balance(&user1);
     assert!(
@@ -721,6 +721,6 @@ fn test_complete_user_journey() {
     let goal = client.get_goal_save_detail(&goal_id);
     assert!(goal.is_completed);
 
-    let withdrawn = client.withdraw_completed_goal_save(&user1, &goal_id);
+    let withdrawn = client.withdraw_completed_goal_save(&user1, &goal_id).net;
     assert_eq!(withdrawn, 20000);
 }
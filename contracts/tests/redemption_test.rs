@@ -257,3 +257,38 @@ fn test_redemption_overflow_protection() {
     let rewards_after = client.get_user_rewards(&user);
     assert_eq!(rewards_after.total_points, rewards.total_points);
 }
+
+#[test]
+fn test_redeem_for_rate_boost_applies_to_new_locks() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    add_points_directly(&env, &client, &user, 50_000);
+
+    let base_lock = client.create_lock_save(&user, &1_000, &86_400, &false);
+    let base_rate = client.get_lock_view(&base_lock).interest_rate;
+    let points_before = client.get_user_rewards(&user).total_points;
+
+    // 30_000 points over 30 days at 10 points per bps-day buys 100 bps
+    let boost = client.redeem_for_rate_boost(&user, &30_000, &30);
+    assert_eq!(boost, 100);
+    assert_eq!(
+        client.get_user_rewards(&user).total_points,
+        points_before - 30_000
+    );
+
+    let boosted_lock = client.create_lock_save(&user, &1_000, &86_400, &false);
+    assert_eq!(
+        client.get_lock_view(&boosted_lock).interest_rate,
+        base_rate + 100
+    );
+}
+
+#[test]
+fn test_redeem_for_rate_boost_insufficient_points() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    add_points_directly(&env, &client, &user, 1_000);
+
+    let result = client.try_redeem_for_rate_boost(&user, &3_000, &30);
+    assert_eq!(result, Err(Ok(Nestera::SavingsError::InsufficientBalance)));
+}